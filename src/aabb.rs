@@ -0,0 +1,216 @@
+use ray::Ray;
+use vec3::Vec3;
+
+/// An axis-aligned bounding box in 3D space, represented by its minimum and maximum corners.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Aabb {
+    /// The corner of the box with the smallest coordinates.
+    pub min: Vec3<f32>,
+    /// The corner of the box with the largest coordinates.
+    pub max: Vec3<f32>,
+}
+
+impl Aabb {
+    /// Constructs a new `Aabb` from a minimum and a maximum corner.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gamemath::{Aabb, Vec3};
+    ///
+    /// let b = Aabb::new(Vec3::new(-1.0, -2.0, -3.0), Vec3::new(1.0, 2.0, 3.0));
+    ///
+    /// assert_eq!(b.min, Vec3::new(-1.0, -2.0, -3.0));
+    /// assert_eq!(b.max, Vec3::new(1.0, 2.0, 3.0));
+    /// ```
+    pub fn new(min: Vec3<f32>, max: Vec3<f32>) -> Aabb {
+        Aabb { min, max }
+    }
+
+    /// Constructs the smallest `Aabb` containing all points of a slice.
+    ///
+    /// An empty slice results in an inverted box, with its minimum corner at positive infinity
+    /// and its maximum corner at negative infinity, which contains no points but can be grown
+    /// using `expand_to_include`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gamemath::{Aabb, Vec3};
+    ///
+    /// let b = Aabb::from_points(&[Vec3::new(1.0, -2.0, 0.0),
+    ///                             Vec3::new(-1.0, 5.0, 2.0),
+    ///                             Vec3::new(0.0, 0.0, -4.0)]);
+    ///
+    /// assert_eq!(b, Aabb::new(Vec3::new(-1.0, -2.0, -4.0), Vec3::new(1.0, 5.0, 2.0)));
+    /// assert!(!Aabb::from_points(&[]).contains(Vec3::new(0.0, 0.0, 0.0)));
+    /// ```
+    pub fn from_points(points: &[Vec3<f32>]) -> Aabb {
+        let mut result = Aabb {
+            min: Vec3::from(f32::INFINITY),
+            max: Vec3::from(f32::NEG_INFINITY),
+        };
+
+        for point in points {
+            result.expand_to_include(*point);
+        }
+
+        result
+    }
+
+    /// Returns `true` if a point lies inside the calling `Aabb`, or on its boundary.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gamemath::{Aabb, Vec3};
+    ///
+    /// let b = Aabb::new(Vec3::new(0.0, 0.0, 0.0), Vec3::new(1.0, 1.0, 1.0));
+    ///
+    /// assert!(b.contains(Vec3::new(0.5, 0.5, 0.5)));
+    /// assert!(b.contains(Vec3::new(1.0, 0.0, 1.0)));
+    /// assert!(!b.contains(Vec3::new(0.5, 1.5, 0.5)));
+    /// ```
+    pub fn contains(&self, point: Vec3<f32>) -> bool {
+        point.x >= self.min.x
+            && point.x <= self.max.x
+            && point.y >= self.min.y
+            && point.y <= self.max.y
+            && point.z >= self.min.z
+            && point.z <= self.max.z
+    }
+
+    /// Returns `true` if the calling `Aabb` and another `Aabb` overlap, including when they only
+    /// touch.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gamemath::{Aabb, Vec3};
+    ///
+    /// let a = Aabb::new(Vec3::new(0.0, 0.0, 0.0), Vec3::new(2.0, 2.0, 2.0));
+    /// let b = Aabb::new(Vec3::new(1.0, 1.0, 1.0), Vec3::new(3.0, 3.0, 3.0));
+    /// let c = Aabb::new(Vec3::new(2.5, 0.0, 0.0), Vec3::new(3.0, 1.0, 1.0));
+    ///
+    /// assert!(a.intersects(&b));
+    /// assert!(b.intersects(&a));
+    /// assert!(!a.intersects(&c));
+    /// ```
+    pub fn intersects(&self, other: &Aabb) -> bool {
+        self.min.x <= other.max.x
+            && self.max.x >= other.min.x
+            && self.min.y <= other.max.y
+            && self.max.y >= other.min.y
+            && self.min.z <= other.max.z
+            && self.max.z >= other.min.z
+    }
+
+    /// Calculates and returns the center point of the calling `Aabb`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gamemath::{Aabb, Vec3};
+    ///
+    /// let b = Aabb::new(Vec3::new(0.0, -2.0, 2.0), Vec3::new(2.0, 2.0, 6.0));
+    ///
+    /// assert_eq!(b.center(), Vec3::new(1.0, 0.0, 4.0));
+    /// ```
+    pub fn center(&self) -> Vec3<f32> {
+        (self.min + self.max) * 0.5
+    }
+
+    /// Calculates and returns the extents of the calling `Aabb`, i.e. half of its size along
+    /// each axis.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gamemath::{Aabb, Vec3};
+    ///
+    /// let b = Aabb::new(Vec3::new(0.0, -2.0, 2.0), Vec3::new(2.0, 2.0, 6.0));
+    ///
+    /// assert_eq!(b.extents(), Vec3::new(1.0, 2.0, 2.0));
+    /// ```
+    pub fn extents(&self) -> Vec3<f32> {
+        (self.max - self.min) * 0.5
+    }
+
+    /// Grows the calling `Aabb` just enough to contain a point.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gamemath::{Aabb, Vec3};
+    ///
+    /// let mut b = Aabb::new(Vec3::new(0.0, 0.0, 0.0), Vec3::new(1.0, 1.0, 1.0));
+    ///
+    /// b.expand_to_include(Vec3::new(2.0, -1.0, 0.5));
+    ///
+    /// assert_eq!(b, Aabb::new(Vec3::new(0.0, -1.0, 0.0), Vec3::new(2.0, 1.0, 1.0)));
+    /// assert!(b.contains(Vec3::new(2.0, -1.0, 0.5)));
+    /// ```
+    pub fn expand_to_include(&mut self, point: Vec3<f32>) {
+        self.min.x = self.min.x.min(point.x);
+        self.min.y = self.min.y.min(point.y);
+        self.min.z = self.min.z.min(point.z);
+        self.max.x = self.max.x.max(point.x);
+        self.max.y = self.max.y.max(point.y);
+        self.max.z = self.max.z.max(point.z);
+    }
+
+    /// Calculates the distances along a `Ray` at which it enters and exits the calling `Aabb`,
+    /// using the slab method with the reciprocal of the ray direction. Returns `None` if the ray
+    /// misses the box, or if the box lies entirely behind the origin of the ray. A ray originating
+    /// inside the box enters it at a distance of 0.0.
+    ///
+    /// Rays parallel to an axis are supported, and only hit the box if their origin lies between
+    /// the two planes bounding the box along that axis.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gamemath::{Aabb, Ray, Vec3};
+    ///
+    /// let b = Aabb::new(Vec3::new(-1.0, -1.0, -1.0), Vec3::new(1.0, 1.0, 1.0));
+    ///
+    /// let through = Ray::new(Vec3::new(-5.0, 0.0, 0.0), Vec3::new(1.0, 0.0, 0.0));
+    /// let upwards = Ray::new(Vec3::new(0.0, -3.0, 0.5), Vec3::new(0.0, 1.0, 0.0));
+    /// let miss = Ray::new(Vec3::new(-5.0, 2.0, 0.0), Vec3::new(1.0, 0.0, 0.0));
+    /// let away = Ray::new(Vec3::new(-5.0, 0.0, 0.0), Vec3::new(-1.0, 0.0, 0.0));
+    /// let inside = Ray::new(Vec3::new(0.5, 0.0, 0.0), Vec3::new(0.0, 0.0, 2.0));
+    ///
+    /// assert_eq!(b.intersect_ray(&through), Some((4.0, 6.0)));
+    /// assert_eq!(b.intersect_ray(&upwards), Some((2.0, 4.0)));
+    /// assert_eq!(b.intersect_ray(&miss), None);
+    /// assert_eq!(b.intersect_ray(&away), None);
+    /// assert_eq!(b.intersect_ray(&inside), Some((0.0, 1.0)));
+    /// ```
+    pub fn intersect_ray(&self, ray: &Ray) -> Option<(f32, f32)> {
+        let inverse_direction = ray.direction.recip();
+        let mut entry = 0.0_f32;
+        let mut exit = f32::INFINITY;
+
+        for axis in 0..3 {
+            let origin = ray.origin[axis];
+
+            if ray.direction[axis] == 0.0 {
+                if origin < self.min[axis] || origin > self.max[axis] {
+                    return None;
+                }
+            } else {
+                let t1 = (self.min[axis] - origin) * inverse_direction[axis];
+                let t2 = (self.max[axis] - origin) * inverse_direction[axis];
+
+                entry = entry.max(t1.min(t2));
+                exit = exit.min(t1.max(t2));
+            }
+        }
+
+        if entry <= exit {
+            Some((entry, exit))
+        } else {
+            None
+        }
+    }
+}
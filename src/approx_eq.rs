@@ -0,0 +1,161 @@
+use mat2::Mat2;
+use mat2d::Mat2d;
+use mat3::Mat3;
+use mat3d::Mat3d;
+use mat4::Mat4;
+use mat4d::Mat4d;
+use vec2::Vec2;
+use vec3::Vec3;
+use vec4::Vec4;
+
+/// A wrapper comparing the wrapped value with a tolerance through `PartialEq`, implemented for
+/// the floating-point vectors and matrices. Two wrapped values are equal when every pair of
+/// corresponding components differs by at most the larger of their two epsilons, which makes it
+/// possible to compare computed results in `assert_eq!` without exact floating-point equality.
+///
+/// Note that this equality is not transitive, as two values can each be within the epsilon of a
+/// third value without being within it of each other.
+///
+/// # Examples
+///
+/// ```
+/// use gamemath::{Approx, Mat4, Vec3};
+///
+/// let a = Mat4::identity().rotated(0.1, Vec3::new(0.0, 0.0, 1.0));
+/// let b = Mat4::identity().rotated(0.3, Vec3::new(0.0, 0.0, 1.0));
+/// let c = a.rotated(0.2, Vec3::new(0.0, 0.0, 1.0));
+///
+/// assert_eq!(Approx(b, 1e-5), Approx(c, 1e-5));
+/// assert_ne!(Approx(a, 1e-5), Approx(c, 1e-5));
+///
+/// let v = Approx(Vec3::new(1.0_f32, 2.0, 3.0), 0.01);
+///
+/// assert_eq!(v, Approx(Vec3::new(1.005, 2.0, 2.995), 0.01));
+/// assert_ne!(v, Approx(Vec3::new(1.02, 2.0, 3.0), 0.01));
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct Approx<T>(pub T, pub f32);
+
+impl PartialEq for Approx<Vec2<f32>> {
+    fn eq(&self, other: &Approx<Vec2<f32>>) -> bool {
+        let epsilon = self.1.max(other.1);
+
+        (self.0.x - other.0.x).abs() <= epsilon && (self.0.y - other.0.y).abs() <= epsilon
+    }
+}
+
+impl PartialEq for Approx<Vec2<f64>> {
+    fn eq(&self, other: &Approx<Vec2<f64>>) -> bool {
+        let epsilon = self.1.max(other.1) as f64;
+
+        (self.0.x - other.0.x).abs() <= epsilon && (self.0.y - other.0.y).abs() <= epsilon
+    }
+}
+
+impl PartialEq for Approx<Vec3<f32>> {
+    fn eq(&self, other: &Approx<Vec3<f32>>) -> bool {
+        let epsilon = self.1.max(other.1);
+
+        (self.0.x - other.0.x).abs() <= epsilon
+            && (self.0.y - other.0.y).abs() <= epsilon
+            && (self.0.z - other.0.z).abs() <= epsilon
+    }
+}
+
+impl PartialEq for Approx<Vec3<f64>> {
+    fn eq(&self, other: &Approx<Vec3<f64>>) -> bool {
+        let epsilon = self.1.max(other.1) as f64;
+
+        (self.0.x - other.0.x).abs() <= epsilon
+            && (self.0.y - other.0.y).abs() <= epsilon
+            && (self.0.z - other.0.z).abs() <= epsilon
+    }
+}
+
+impl PartialEq for Approx<Vec4<f32>> {
+    fn eq(&self, other: &Approx<Vec4<f32>>) -> bool {
+        let epsilon = self.1.max(other.1);
+
+        (self.0.x - other.0.x).abs() <= epsilon
+            && (self.0.y - other.0.y).abs() <= epsilon
+            && (self.0.z - other.0.z).abs() <= epsilon
+            && (self.0.w - other.0.w).abs() <= epsilon
+    }
+}
+
+impl PartialEq for Approx<Vec4<f64>> {
+    fn eq(&self, other: &Approx<Vec4<f64>>) -> bool {
+        let epsilon = self.1.max(other.1) as f64;
+
+        (self.0.x - other.0.x).abs() <= epsilon
+            && (self.0.y - other.0.y).abs() <= epsilon
+            && (self.0.z - other.0.z).abs() <= epsilon
+            && (self.0.w - other.0.w).abs() <= epsilon
+    }
+}
+
+impl PartialEq for Approx<Mat2> {
+    fn eq(&self, other: &Approx<Mat2>) -> bool {
+        let epsilon = self.1.max(other.1);
+
+        self.0
+            .iter()
+            .zip(other.0.iter())
+            .all(|(a, b)| (a - b).abs() <= epsilon)
+    }
+}
+
+impl PartialEq for Approx<Mat2d> {
+    fn eq(&self, other: &Approx<Mat2d>) -> bool {
+        let epsilon = self.1.max(other.1) as f64;
+
+        self.0
+            .iter()
+            .zip(other.0.iter())
+            .all(|(a, b)| (a - b).abs() <= epsilon)
+    }
+}
+
+impl PartialEq for Approx<Mat3> {
+    fn eq(&self, other: &Approx<Mat3>) -> bool {
+        let epsilon = self.1.max(other.1);
+
+        self.0
+            .iter()
+            .zip(other.0.iter())
+            .all(|(a, b)| (a - b).abs() <= epsilon)
+    }
+}
+
+impl PartialEq for Approx<Mat3d> {
+    fn eq(&self, other: &Approx<Mat3d>) -> bool {
+        let epsilon = self.1.max(other.1) as f64;
+
+        self.0
+            .iter()
+            .zip(other.0.iter())
+            .all(|(a, b)| (a - b).abs() <= epsilon)
+    }
+}
+
+impl PartialEq for Approx<Mat4> {
+    fn eq(&self, other: &Approx<Mat4>) -> bool {
+        let epsilon = self.1.max(other.1);
+
+        self.0
+            .iter()
+            .zip(other.0.iter())
+            .all(|(a, b)| (a - b).abs() <= epsilon)
+    }
+}
+
+impl PartialEq for Approx<Mat4d> {
+    fn eq(&self, other: &Approx<Mat4d>) -> bool {
+        let epsilon = self.1.max(other.1) as f64;
+
+        self.0
+            .iter()
+            .zip(other.0.iter())
+            .all(|(a, b)| (a - b).abs() <= epsilon)
+    }
+}
@@ -0,0 +1,482 @@
+//! Implementations of the `approx` crate's comparison traits, enabled through the `approx`
+//! feature.
+
+use approx::{AbsDiffEq, RelativeEq, UlpsEq};
+use mat2::Mat2;
+use mat2d::Mat2d;
+use mat3::Mat3;
+use mat3d::Mat3d;
+use mat4::Mat4;
+use mat4d::Mat4d;
+use quat::Quat;
+use quatd::Quatd;
+use vec2::Vec2;
+use vec3::Vec3;
+use vec4::Vec4;
+
+impl<T: AbsDiffEq> AbsDiffEq for Vec2<T>
+where
+    T::Epsilon: Copy,
+{
+    type Epsilon = T::Epsilon;
+
+    fn default_epsilon() -> T::Epsilon {
+        T::default_epsilon()
+    }
+
+    fn abs_diff_eq(&self, other: &Vec2<T>, epsilon: T::Epsilon) -> bool {
+        self.x.abs_diff_eq(&other.x, epsilon) && self.y.abs_diff_eq(&other.y, epsilon)
+    }
+}
+
+impl<T: RelativeEq> RelativeEq for Vec2<T>
+where
+    T::Epsilon: Copy,
+{
+    fn default_max_relative() -> T::Epsilon {
+        T::default_max_relative()
+    }
+
+    fn relative_eq(&self, other: &Vec2<T>, epsilon: T::Epsilon, max_relative: T::Epsilon) -> bool {
+        self.x.relative_eq(&other.x, epsilon, max_relative)
+            && self.y.relative_eq(&other.y, epsilon, max_relative)
+    }
+}
+
+impl<T: UlpsEq> UlpsEq for Vec2<T>
+where
+    T::Epsilon: Copy,
+{
+    fn default_max_ulps() -> u32 {
+        T::default_max_ulps()
+    }
+
+    fn ulps_eq(&self, other: &Vec2<T>, epsilon: T::Epsilon, max_ulps: u32) -> bool {
+        self.x.ulps_eq(&other.x, epsilon, max_ulps) && self.y.ulps_eq(&other.y, epsilon, max_ulps)
+    }
+}
+
+impl<T: AbsDiffEq> AbsDiffEq for Vec3<T>
+where
+    T::Epsilon: Copy,
+{
+    type Epsilon = T::Epsilon;
+
+    fn default_epsilon() -> T::Epsilon {
+        T::default_epsilon()
+    }
+
+    fn abs_diff_eq(&self, other: &Vec3<T>, epsilon: T::Epsilon) -> bool {
+        self.x.abs_diff_eq(&other.x, epsilon)
+            && self.y.abs_diff_eq(&other.y, epsilon)
+            && self.z.abs_diff_eq(&other.z, epsilon)
+    }
+}
+
+impl<T: RelativeEq> RelativeEq for Vec3<T>
+where
+    T::Epsilon: Copy,
+{
+    fn default_max_relative() -> T::Epsilon {
+        T::default_max_relative()
+    }
+
+    fn relative_eq(&self, other: &Vec3<T>, epsilon: T::Epsilon, max_relative: T::Epsilon) -> bool {
+        self.x.relative_eq(&other.x, epsilon, max_relative)
+            && self.y.relative_eq(&other.y, epsilon, max_relative)
+            && self.z.relative_eq(&other.z, epsilon, max_relative)
+    }
+}
+
+impl<T: UlpsEq> UlpsEq for Vec3<T>
+where
+    T::Epsilon: Copy,
+{
+    fn default_max_ulps() -> u32 {
+        T::default_max_ulps()
+    }
+
+    fn ulps_eq(&self, other: &Vec3<T>, epsilon: T::Epsilon, max_ulps: u32) -> bool {
+        self.x.ulps_eq(&other.x, epsilon, max_ulps)
+            && self.y.ulps_eq(&other.y, epsilon, max_ulps)
+            && self.z.ulps_eq(&other.z, epsilon, max_ulps)
+    }
+}
+
+impl<T: AbsDiffEq> AbsDiffEq for Vec4<T>
+where
+    T::Epsilon: Copy,
+{
+    type Epsilon = T::Epsilon;
+
+    fn default_epsilon() -> T::Epsilon {
+        T::default_epsilon()
+    }
+
+    fn abs_diff_eq(&self, other: &Vec4<T>, epsilon: T::Epsilon) -> bool {
+        self.x.abs_diff_eq(&other.x, epsilon)
+            && self.y.abs_diff_eq(&other.y, epsilon)
+            && self.z.abs_diff_eq(&other.z, epsilon)
+            && self.w.abs_diff_eq(&other.w, epsilon)
+    }
+}
+
+impl<T: RelativeEq> RelativeEq for Vec4<T>
+where
+    T::Epsilon: Copy,
+{
+    fn default_max_relative() -> T::Epsilon {
+        T::default_max_relative()
+    }
+
+    fn relative_eq(&self, other: &Vec4<T>, epsilon: T::Epsilon, max_relative: T::Epsilon) -> bool {
+        self.x.relative_eq(&other.x, epsilon, max_relative)
+            && self.y.relative_eq(&other.y, epsilon, max_relative)
+            && self.z.relative_eq(&other.z, epsilon, max_relative)
+            && self.w.relative_eq(&other.w, epsilon, max_relative)
+    }
+}
+
+impl<T: UlpsEq> UlpsEq for Vec4<T>
+where
+    T::Epsilon: Copy,
+{
+    fn default_max_ulps() -> u32 {
+        T::default_max_ulps()
+    }
+
+    fn ulps_eq(&self, other: &Vec4<T>, epsilon: T::Epsilon, max_ulps: u32) -> bool {
+        self.x.ulps_eq(&other.x, epsilon, max_ulps)
+            && self.y.ulps_eq(&other.y, epsilon, max_ulps)
+            && self.z.ulps_eq(&other.z, epsilon, max_ulps)
+            && self.w.ulps_eq(&other.w, epsilon, max_ulps)
+    }
+}
+
+impl AbsDiffEq for Mat2 {
+    type Epsilon = f32;
+
+    fn default_epsilon() -> f32 {
+        f32::default_epsilon()
+    }
+
+    fn abs_diff_eq(&self, other: &Mat2, epsilon: f32) -> bool {
+        self.rows
+            .iter()
+            .zip(other.rows.iter())
+            .all(|(a, b)| a.abs_diff_eq(b, epsilon))
+    }
+}
+
+impl RelativeEq for Mat2 {
+    fn default_max_relative() -> f32 {
+        f32::default_max_relative()
+    }
+
+    fn relative_eq(&self, other: &Mat2, epsilon: f32, max_relative: f32) -> bool {
+        self.rows
+            .iter()
+            .zip(other.rows.iter())
+            .all(|(a, b)| a.relative_eq(b, epsilon, max_relative))
+    }
+}
+
+impl UlpsEq for Mat2 {
+    fn default_max_ulps() -> u32 {
+        f32::default_max_ulps()
+    }
+
+    fn ulps_eq(&self, other: &Mat2, epsilon: f32, max_ulps: u32) -> bool {
+        self.rows
+            .iter()
+            .zip(other.rows.iter())
+            .all(|(a, b)| a.ulps_eq(b, epsilon, max_ulps))
+    }
+}
+
+impl AbsDiffEq for Mat2d {
+    type Epsilon = f64;
+
+    fn default_epsilon() -> f64 {
+        f64::default_epsilon()
+    }
+
+    fn abs_diff_eq(&self, other: &Mat2d, epsilon: f64) -> bool {
+        self.rows
+            .iter()
+            .zip(other.rows.iter())
+            .all(|(a, b)| a.abs_diff_eq(b, epsilon))
+    }
+}
+
+impl RelativeEq for Mat2d {
+    fn default_max_relative() -> f64 {
+        f64::default_max_relative()
+    }
+
+    fn relative_eq(&self, other: &Mat2d, epsilon: f64, max_relative: f64) -> bool {
+        self.rows
+            .iter()
+            .zip(other.rows.iter())
+            .all(|(a, b)| a.relative_eq(b, epsilon, max_relative))
+    }
+}
+
+impl UlpsEq for Mat2d {
+    fn default_max_ulps() -> u32 {
+        f64::default_max_ulps()
+    }
+
+    fn ulps_eq(&self, other: &Mat2d, epsilon: f64, max_ulps: u32) -> bool {
+        self.rows
+            .iter()
+            .zip(other.rows.iter())
+            .all(|(a, b)| a.ulps_eq(b, epsilon, max_ulps))
+    }
+}
+
+impl AbsDiffEq for Mat3 {
+    type Epsilon = f32;
+
+    fn default_epsilon() -> f32 {
+        f32::default_epsilon()
+    }
+
+    fn abs_diff_eq(&self, other: &Mat3, epsilon: f32) -> bool {
+        self.rows
+            .iter()
+            .zip(other.rows.iter())
+            .all(|(a, b)| a.abs_diff_eq(b, epsilon))
+    }
+}
+
+impl RelativeEq for Mat3 {
+    fn default_max_relative() -> f32 {
+        f32::default_max_relative()
+    }
+
+    fn relative_eq(&self, other: &Mat3, epsilon: f32, max_relative: f32) -> bool {
+        self.rows
+            .iter()
+            .zip(other.rows.iter())
+            .all(|(a, b)| a.relative_eq(b, epsilon, max_relative))
+    }
+}
+
+impl UlpsEq for Mat3 {
+    fn default_max_ulps() -> u32 {
+        f32::default_max_ulps()
+    }
+
+    fn ulps_eq(&self, other: &Mat3, epsilon: f32, max_ulps: u32) -> bool {
+        self.rows
+            .iter()
+            .zip(other.rows.iter())
+            .all(|(a, b)| a.ulps_eq(b, epsilon, max_ulps))
+    }
+}
+
+impl AbsDiffEq for Mat3d {
+    type Epsilon = f64;
+
+    fn default_epsilon() -> f64 {
+        f64::default_epsilon()
+    }
+
+    fn abs_diff_eq(&self, other: &Mat3d, epsilon: f64) -> bool {
+        self.rows
+            .iter()
+            .zip(other.rows.iter())
+            .all(|(a, b)| a.abs_diff_eq(b, epsilon))
+    }
+}
+
+impl RelativeEq for Mat3d {
+    fn default_max_relative() -> f64 {
+        f64::default_max_relative()
+    }
+
+    fn relative_eq(&self, other: &Mat3d, epsilon: f64, max_relative: f64) -> bool {
+        self.rows
+            .iter()
+            .zip(other.rows.iter())
+            .all(|(a, b)| a.relative_eq(b, epsilon, max_relative))
+    }
+}
+
+impl UlpsEq for Mat3d {
+    fn default_max_ulps() -> u32 {
+        f64::default_max_ulps()
+    }
+
+    fn ulps_eq(&self, other: &Mat3d, epsilon: f64, max_ulps: u32) -> bool {
+        self.rows
+            .iter()
+            .zip(other.rows.iter())
+            .all(|(a, b)| a.ulps_eq(b, epsilon, max_ulps))
+    }
+}
+
+impl AbsDiffEq for Mat4 {
+    type Epsilon = f32;
+
+    fn default_epsilon() -> f32 {
+        f32::default_epsilon()
+    }
+
+    fn abs_diff_eq(&self, other: &Mat4, epsilon: f32) -> bool {
+        self.rows
+            .iter()
+            .zip(other.rows.iter())
+            .all(|(a, b)| a.abs_diff_eq(b, epsilon))
+    }
+}
+
+impl RelativeEq for Mat4 {
+    fn default_max_relative() -> f32 {
+        f32::default_max_relative()
+    }
+
+    fn relative_eq(&self, other: &Mat4, epsilon: f32, max_relative: f32) -> bool {
+        self.rows
+            .iter()
+            .zip(other.rows.iter())
+            .all(|(a, b)| a.relative_eq(b, epsilon, max_relative))
+    }
+}
+
+impl UlpsEq for Mat4 {
+    fn default_max_ulps() -> u32 {
+        f32::default_max_ulps()
+    }
+
+    fn ulps_eq(&self, other: &Mat4, epsilon: f32, max_ulps: u32) -> bool {
+        self.rows
+            .iter()
+            .zip(other.rows.iter())
+            .all(|(a, b)| a.ulps_eq(b, epsilon, max_ulps))
+    }
+}
+
+impl AbsDiffEq for Mat4d {
+    type Epsilon = f64;
+
+    fn default_epsilon() -> f64 {
+        f64::default_epsilon()
+    }
+
+    fn abs_diff_eq(&self, other: &Mat4d, epsilon: f64) -> bool {
+        self.rows
+            .iter()
+            .zip(other.rows.iter())
+            .all(|(a, b)| a.abs_diff_eq(b, epsilon))
+    }
+}
+
+impl RelativeEq for Mat4d {
+    fn default_max_relative() -> f64 {
+        f64::default_max_relative()
+    }
+
+    fn relative_eq(&self, other: &Mat4d, epsilon: f64, max_relative: f64) -> bool {
+        self.rows
+            .iter()
+            .zip(other.rows.iter())
+            .all(|(a, b)| a.relative_eq(b, epsilon, max_relative))
+    }
+}
+
+impl UlpsEq for Mat4d {
+    fn default_max_ulps() -> u32 {
+        f64::default_max_ulps()
+    }
+
+    fn ulps_eq(&self, other: &Mat4d, epsilon: f64, max_ulps: u32) -> bool {
+        self.rows
+            .iter()
+            .zip(other.rows.iter())
+            .all(|(a, b)| a.ulps_eq(b, epsilon, max_ulps))
+    }
+}
+
+impl AbsDiffEq for Quat {
+    type Epsilon = f32;
+
+    fn default_epsilon() -> f32 {
+        f32::default_epsilon()
+    }
+
+    fn abs_diff_eq(&self, other: &Quat, epsilon: f32) -> bool {
+        self.x.abs_diff_eq(&other.x, epsilon)
+            && self.y.abs_diff_eq(&other.y, epsilon)
+            && self.z.abs_diff_eq(&other.z, epsilon)
+            && self.w.abs_diff_eq(&other.w, epsilon)
+    }
+}
+
+impl RelativeEq for Quat {
+    fn default_max_relative() -> f32 {
+        f32::default_max_relative()
+    }
+
+    fn relative_eq(&self, other: &Quat, epsilon: f32, max_relative: f32) -> bool {
+        self.x.relative_eq(&other.x, epsilon, max_relative)
+            && self.y.relative_eq(&other.y, epsilon, max_relative)
+            && self.z.relative_eq(&other.z, epsilon, max_relative)
+            && self.w.relative_eq(&other.w, epsilon, max_relative)
+    }
+}
+
+impl UlpsEq for Quat {
+    fn default_max_ulps() -> u32 {
+        f32::default_max_ulps()
+    }
+
+    fn ulps_eq(&self, other: &Quat, epsilon: f32, max_ulps: u32) -> bool {
+        self.x.ulps_eq(&other.x, epsilon, max_ulps)
+            && self.y.ulps_eq(&other.y, epsilon, max_ulps)
+            && self.z.ulps_eq(&other.z, epsilon, max_ulps)
+            && self.w.ulps_eq(&other.w, epsilon, max_ulps)
+    }
+}
+
+impl AbsDiffEq for Quatd {
+    type Epsilon = f64;
+
+    fn default_epsilon() -> f64 {
+        f64::default_epsilon()
+    }
+
+    fn abs_diff_eq(&self, other: &Quatd, epsilon: f64) -> bool {
+        self.x.abs_diff_eq(&other.x, epsilon)
+            && self.y.abs_diff_eq(&other.y, epsilon)
+            && self.z.abs_diff_eq(&other.z, epsilon)
+            && self.w.abs_diff_eq(&other.w, epsilon)
+    }
+}
+
+impl RelativeEq for Quatd {
+    fn default_max_relative() -> f64 {
+        f64::default_max_relative()
+    }
+
+    fn relative_eq(&self, other: &Quatd, epsilon: f64, max_relative: f64) -> bool {
+        self.x.relative_eq(&other.x, epsilon, max_relative)
+            && self.y.relative_eq(&other.y, epsilon, max_relative)
+            && self.z.relative_eq(&other.z, epsilon, max_relative)
+            && self.w.relative_eq(&other.w, epsilon, max_relative)
+    }
+}
+
+impl UlpsEq for Quatd {
+    fn default_max_ulps() -> u32 {
+        f64::default_max_ulps()
+    }
+
+    fn ulps_eq(&self, other: &Quatd, epsilon: f64, max_ulps: u32) -> bool {
+        self.x.ulps_eq(&other.x, epsilon, max_ulps)
+            && self.y.ulps_eq(&other.y, epsilon, max_ulps)
+            && self.z.ulps_eq(&other.z, epsilon, max_ulps)
+            && self.w.ulps_eq(&other.w, epsilon, max_ulps)
+    }
+}
@@ -0,0 +1,24 @@
+/// An axis in 3D space, used for selecting a component of a `Vec3` at runtime.
+///
+/// # Examples
+///
+/// ```
+/// use gamemath::{Axis, Vec3};
+///
+/// let mut v = Vec3::new(1, 2, 3);
+///
+/// assert_eq!(v[Axis::Y], 2);
+///
+/// v[Axis::Z] = 5;
+///
+/// assert_eq!(v, Vec3::new(1, 2, 5));
+/// ```
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum Axis {
+    /// The X/first axis.
+    X,
+    /// The Y/second axis.
+    Y,
+    /// The Z/third axis.
+    Z,
+}
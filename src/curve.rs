@@ -1,4 +1,8 @@
-use std::ops::{Index, IndexMut};
+#[cfg(not(feature = "std"))]
+use alloc::vec::Vec;
+use core::ops::{Index, IndexMut};
+use core::slice::Iter;
+use interpolation::smoothstep;
 
 /// A heap allocated structure for representing a value curve.
 pub struct Curve<T>(Vec<T>);
@@ -21,6 +25,7 @@ where
     /// assert_eq!(c[2], 5.0);
     /// assert_eq!(c[3], 0.0);
     /// ```
+    #[allow(clippy::init_numbered_fields)]
     pub fn new(values: &[T]) -> Curve<T> {
         Curve { 0: values.into() }
     }
@@ -57,6 +62,190 @@ where
             }
         }
     }
+
+    /// Linearly interpolates between the values of the curve by a factor, treating the curve as a
+    /// closed loop. The factor wraps around outside of the range `[0, 1)`, and the last value of
+    /// the curve is interpolated back towards the first, which suits looping animations.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gamemath::Curve;
+    ///
+    /// let c: Curve<f32> = Curve::new(&[0.0, 10.0]);
+    ///
+    /// assert_eq!(c.lerp_wrapped(0.25), 5.0);
+    /// assert_eq!(c.lerp_wrapped(0.5), 10.0);
+    /// assert_eq!(c.lerp_wrapped(1.5), c.lerp_wrapped(0.5));
+    /// assert_eq!(c.lerp_wrapped(-0.25), 5.0);
+    /// assert_eq!(c.lerp_wrapped(1.0), 0.0);
+    /// ```
+    pub fn lerp_wrapped(&self, factor: f32) -> T {
+        let len = self.0.len();
+
+        match len {
+            0 => T::default(),
+            1 => self.0[0],
+            _ => {
+                let mut factor_wrapped = factor % 1.0;
+
+                if factor_wrapped < 0.0 {
+                    factor_wrapped += 1.0;
+                }
+
+                let factor_scaled = factor_wrapped * len as f32;
+                let index = (factor_scaled as usize).min(len - 1);
+                let start = self.0[index];
+                let end = self.0[(index + 1) % len];
+                let new_factor = factor_scaled - index as f32;
+
+                ((1.0 - new_factor) * start.into() + new_factor * end.into()).into()
+            }
+        }
+    }
+
+    /// Samples the curve at a number of evenly spaced factors between 0.0 and 1.0, inclusive,
+    /// using `lerp`. A count of one samples the curve at factor 0.0 only.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gamemath::Curve;
+    ///
+    /// let c: Curve<f32> = Curve::new(&[0.0, 10.0]);
+    ///
+    /// assert_eq!(c.sample_uniform(3), vec![0.0, 5.0, 10.0]);
+    /// assert_eq!(c.sample_uniform(1), vec![0.0]);
+    /// assert!(c.sample_uniform(0).is_empty());
+    /// ```
+    pub fn sample_uniform(&self, count: usize) -> Vec<T> {
+        let last = count.max(2) - 1;
+
+        (0..count)
+            .map(|i| self.lerp(i as f32 / last as f32))
+            .collect()
+    }
+
+    /// Returns the number of values in the curve.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gamemath::Curve;
+    ///
+    /// let c: Curve<f32> = Curve::new(&[0.0, 10.0, 5.0, 0.0]);
+    ///
+    /// assert_eq!(c.len(), 4);
+    /// ```
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    /// Returns `true` if the curve contains no values.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gamemath::Curve;
+    ///
+    /// let c: Curve<f32> = Curve::new(&[]);
+    ///
+    /// assert!(c.is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Appends a value to the end of the curve.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gamemath::Curve;
+    ///
+    /// let mut c: Curve<f32> = Curve::new(&[]);
+    ///
+    /// c.push(0.0);
+    /// c.push(10.0);
+    /// c.push(5.0);
+    ///
+    /// assert_eq!(c.len(), 3);
+    /// assert_eq!(c.iter().cloned().collect::<Vec<f32>>(), vec![0.0, 10.0, 5.0]);
+    /// ```
+    pub fn push(&mut self, value: T) {
+        self.0.push(value);
+    }
+
+    /// Returns an iterator over the values of the curve, in order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gamemath::Curve;
+    ///
+    /// let c: Curve<f32> = Curve::new(&[0.0, 10.0, 5.0]);
+    /// let mut i = c.iter();
+    ///
+    /// assert_eq!(i.next(), Some(&0.0));
+    /// assert_eq!(i.next(), Some(&10.0));
+    /// assert_eq!(i.next(), Some(&5.0));
+    /// assert_eq!(i.next(), None);
+    /// ```
+    pub fn iter(&self) -> Iter<'_, T> {
+        self.0.iter()
+    }
+
+    /// Interpolates between the values of the curve like `lerp`, but eases the factor in and out
+    /// using `smoothstep` before sampling.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gamemath::Curve;
+    ///
+    /// let c: Curve<f32> = Curve::new(&[0.0, 10.0]);
+    ///
+    /// assert_eq!(c.smoothstep(0.0), 0.0);
+    /// assert_eq!(c.smoothstep(0.25), 1.5625);
+    /// assert_eq!(c.smoothstep(0.5), 5.0);
+    /// assert_eq!(c.smoothstep(1.0), 10.0);
+    /// ```
+    pub fn smoothstep(&self, factor: f32) -> T {
+        self.lerp(smoothstep(0.0, 1.0, factor))
+    }
+
+    /// Calculates the rate of change of the curve at a factor, as sampled by `lerp`. This is the
+    /// slope of the segment the factor falls in, scaled by the number of segments, since the
+    /// whole curve spans a factor range of 1.0. Curves with fewer than two values are constant.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gamemath::Curve;
+    ///
+    /// let c: Curve<f32> = Curve::new(&[0.0, 10.0, 5.0]);
+    ///
+    /// assert_eq!(c.derivative(0.0), 20.0);
+    /// assert_eq!(c.derivative(0.1), 20.0);
+    /// assert_eq!(c.derivative(0.4), 20.0);
+    /// assert_eq!(c.derivative(0.6), -10.0);
+    /// assert_eq!(c.derivative(0.9), -10.0);
+    /// assert_eq!(c.derivative(1.0), -10.0);
+    /// ```
+    pub fn derivative(&self, factor: f32) -> T {
+        let len = self.0.len();
+
+        if len < 2 {
+            return T::from(0.0);
+        }
+
+        let segments = len - 1;
+        let index = ((factor * segments as f32) as usize).min(segments - 1);
+        let start: f32 = self.0[index].into();
+        let end: f32 = self.0[index + 1].into();
+
+        ((end - start) * segments as f32).into()
+    }
 }
 
 impl<T> Index<usize> for Curve<T> {
@@ -0,0 +1,261 @@
+use quat::Quat;
+use quatd::Quatd;
+use vec2::Vec2;
+use vec3::Vec3;
+use vec4::Vec4;
+
+/// Performs a smooth Hermite interpolation between 0.0 and 1.0 as `x` moves from `edge0` to
+/// `edge1`. The remapped value is clamped into the range `[0, 1]`, and the resulting curve has a
+/// slope of zero at both edges, which makes it useful for ease-in-out animation.
+///
+/// # Examples
+///
+/// ```
+/// use gamemath::smoothstep;
+///
+/// assert_eq!(smoothstep(0.0, 10.0, -5.0), 0.0);
+/// assert_eq!(smoothstep(0.0, 10.0, 5.0), 0.5);
+/// assert_eq!(smoothstep(0.0, 10.0, 15.0), 1.0);
+///
+/// let h = 0.001;
+///
+/// assert!((smoothstep(0.0, 1.0, h) - smoothstep(0.0, 1.0, 0.0)) / h < 0.01);
+/// assert!((smoothstep(0.0, 1.0, 1.0) - smoothstep(0.0, 1.0, 1.0 - h)) / h < 0.01);
+/// ```
+pub fn smoothstep(edge0: f32, edge1: f32, x: f32) -> f32 {
+    let t = ((x - edge0) / (edge1 - edge0)).clamp(0.0, 1.0);
+
+    t * t * (3.0 - 2.0 * t)
+}
+
+/// Performs Ken Perlin's quintic variant of `smoothstep`, which additionally has a second
+/// derivative of zero at both edges.
+///
+/// # Examples
+///
+/// ```
+/// use gamemath::smootherstep;
+///
+/// assert_eq!(smootherstep(0.0, 10.0, -5.0), 0.0);
+/// assert_eq!(smootherstep(0.0, 10.0, 5.0), 0.5);
+/// assert_eq!(smootherstep(0.0, 10.0, 15.0), 1.0);
+///
+/// let h = 0.001;
+///
+/// assert!((smootherstep(0.0, 1.0, h) - smootherstep(0.0, 1.0, 0.0)) / h < 0.001);
+/// assert!((smootherstep(0.0, 1.0, 1.0) - smootherstep(0.0, 1.0, 1.0 - h)) / h < 0.001);
+/// ```
+pub fn smootherstep(edge0: f32, edge1: f32, x: f32) -> f32 {
+    let t = ((x - edge0) / (edge1 - edge0)).clamp(0.0, 1.0);
+
+    t * t * t * (t * (t * 6.0 - 15.0) + 10.0)
+}
+
+/// Evaluates a cubic Bezier curve defined by the control points `p0`, `p1`, `p2` and `p3` at
+/// `t`, through repeated linear interpolation. The curve starts at `p0` for a `t` of 0.0 and ends
+/// at `p3` for a `t` of 1.0, while `p1` and `p2` shape it without being passed through.
+///
+/// # Examples
+///
+/// ```
+/// use gamemath::{bezier3, Vec3};
+///
+/// let p0 = Vec3::new(0.0, 0.0, 0.0);
+/// let p1 = Vec3::new(1.0, 2.0, 0.0);
+/// let p2 = Vec3::new(3.0, 2.0, 0.0);
+/// let p3 = Vec3::new(4.0, 0.0, 1.0);
+///
+/// assert_eq!(bezier3(p0, p1, p2, p3, 0.0), p0);
+/// assert_eq!(bezier3(p0, p1, p2, p3, 1.0), p3);
+/// assert_eq!(bezier3(p0, p1, p2, p3, 0.5), Vec3::new(2.0, 1.5, 0.125));
+/// ```
+pub fn bezier3(p0: Vec3<f32>, p1: Vec3<f32>, p2: Vec3<f32>, p3: Vec3<f32>, t: f32) -> Vec3<f32> {
+    let a = p0.lerp(p1, t);
+    let b = p1.lerp(p2, t);
+    let c = p2.lerp(p3, t);
+
+    a.lerp(b, t).lerp(b.lerp(c, t), t)
+}
+
+/// Evaluates a cubic Hermite curve at `t`, starting at `p0` with the tangent `m0` for a `t` of
+/// 0.0 and ending at `p1` with the tangent `m1` for a `t` of 1.0.
+///
+/// # Examples
+///
+/// ```
+/// use gamemath::{hermite, Vec3};
+///
+/// let p0 = Vec3::new(0.0, 0.0, 0.0);
+/// let m0 = Vec3::new(1.0, 0.0, 0.0);
+/// let p1 = Vec3::new(2.0, 1.0, 0.0);
+/// let m1 = Vec3::new(0.0, 3.0, 0.0);
+///
+/// assert_eq!(hermite(p0, m0, p1, m1, 0.0), p0);
+/// assert_eq!(hermite(p0, m0, p1, m1, 1.0), p1);
+///
+/// let h = 0.001;
+/// let start_tangent = (hermite(p0, m0, p1, m1, h) - p0) / h;
+/// let end_tangent = (p1 - hermite(p0, m0, p1, m1, 1.0 - h)) / h;
+///
+/// assert!((start_tangent - m0).length() < 0.01);
+/// assert!((end_tangent - m1).length() < 0.01);
+/// ```
+pub fn hermite(p0: Vec3<f32>, m0: Vec3<f32>, p1: Vec3<f32>, m1: Vec3<f32>, t: f32) -> Vec3<f32> {
+    let t2 = t * t;
+    let t3 = t2 * t;
+
+    p0 * (2.0 * t3 - 3.0 * t2 + 1.0)
+        + m0 * (t3 - 2.0 * t2 + t)
+        + p1 * (3.0 * t2 - 2.0 * t3)
+        + m1 * (t3 - t2)
+}
+
+/// A value that can be interpolated towards another value of the same type by a factor,
+/// implemented for `f32`, `f64`, the floating-point vectors and the quaternions. This allows
+/// generic code, such as animation tracks, to interpolate any keyframe type through `lerp`.
+///
+/// The quaternions are interpolated with a normalized linear interpolation along the shortest
+/// path, which is cheaper than `slerp` but does not keep a constant angular velocity.
+///
+/// # Examples
+///
+/// ```
+/// use gamemath::{Lerp, Quat, Vec3};
+///
+/// assert_eq!(Lerp::lerp(2.0_f32, 4.0, 0.5), 3.0);
+/// assert_eq!(Lerp::lerp(2.0_f64, 4.0, 0.25), 2.5);
+///
+/// let v = Lerp::lerp(Vec3::new(0.0_f32, 0.0, 0.0), Vec3::new(2.0, 4.0, 6.0), 0.5);
+///
+/// assert_eq!(v, Vec3::new(1.0, 2.0, 3.0));
+///
+/// let q = Lerp::lerp(Quat::identity(), Quat::from((0.0, 0.0, 1.0, 0.0)), 0.5);
+///
+/// assert!((q.z - 0.5_f32.sqrt()).abs() < 0.000001);
+/// assert!((q.w - 0.5_f32.sqrt()).abs() < 0.000001);
+///
+/// // The factor has the precision of the interpolated type, so method calls on the `f64` types
+/// // take an `f64` factor like their inherent `lerp` does.
+/// let a = Vec3::new(0.0_f64, 0.0, 0.0);
+///
+/// assert_eq!(a.lerp(Vec3::new(2.0, 4.0, 6.0), 0.5_f64), Vec3::new(1.0, 2.0, 3.0));
+/// ```
+pub trait Lerp {
+    /// The type of the interpolation factor, `f32` for the single precision types and `f64` for
+    /// the double precision ones.
+    type Factor;
+
+    /// Interpolates between the calling value and a target value by a factor, returning the
+    /// calling value for a factor of 0.0 and the target for a factor of 1.0.
+    fn lerp(self, target: Self, factor: Self::Factor) -> Self;
+}
+
+impl Lerp for f32 {
+    type Factor = f32;
+
+    fn lerp(self, target: f32, factor: f32) -> f32 {
+        self + (target - self) * factor
+    }
+}
+
+impl Lerp for f64 {
+    type Factor = f64;
+
+    fn lerp(self, target: f64, factor: f64) -> f64 {
+        self + (target - self) * factor
+    }
+}
+
+impl Lerp for Vec2<f32> {
+    type Factor = f32;
+
+    fn lerp(self, target: Vec2<f32>, factor: f32) -> Vec2<f32> {
+        Vec2::<f32>::lerp(&self, target, factor)
+    }
+}
+
+impl Lerp for Vec2<f64> {
+    type Factor = f64;
+
+    fn lerp(self, target: Vec2<f64>, factor: f64) -> Vec2<f64> {
+        Vec2::<f64>::lerp(&self, target, factor)
+    }
+}
+
+impl Lerp for Vec3<f32> {
+    type Factor = f32;
+
+    fn lerp(self, target: Vec3<f32>, factor: f32) -> Vec3<f32> {
+        Vec3::<f32>::lerp(&self, target, factor)
+    }
+}
+
+impl Lerp for Vec3<f64> {
+    type Factor = f64;
+
+    fn lerp(self, target: Vec3<f64>, factor: f64) -> Vec3<f64> {
+        Vec3::<f64>::lerp(&self, target, factor)
+    }
+}
+
+impl Lerp for Vec4<f32> {
+    type Factor = f32;
+
+    fn lerp(self, target: Vec4<f32>, factor: f32) -> Vec4<f32> {
+        Vec4::<f32>::lerp(&self, target, factor)
+    }
+}
+
+impl Lerp for Vec4<f64> {
+    type Factor = f64;
+
+    fn lerp(self, target: Vec4<f64>, factor: f64) -> Vec4<f64> {
+        Vec4::<f64>::lerp(&self, target, factor)
+    }
+}
+
+impl Lerp for Quat {
+    type Factor = f32;
+
+    fn lerp(self, target: Quat, factor: f32) -> Quat {
+        let dot = self.x * target.x + self.y * target.y + self.z * target.z + self.w * target.w;
+        let target = if dot < 0.0 { -target } else { target };
+
+        (self + (target - self) * factor).normalized()
+    }
+}
+
+impl Lerp for Quatd {
+    type Factor = f64;
+
+    fn lerp(self, target: Quatd, factor: f64) -> Quatd {
+        let dot = self.x * target.x + self.y * target.y + self.z * target.z + self.w * target.w;
+        let target = if dot < 0.0 { -target } else { target };
+
+        (self + (target - self) * factor).normalized()
+    }
+}
+
+/// Interpolates between two values of any type implementing `Lerp` by a factor of the precision
+/// of that type.
+///
+/// # Examples
+///
+/// ```
+/// use gamemath::{lerp, Quat, Vec3};
+///
+/// assert_eq!(lerp(10.0_f32, 20.0, 0.1), 11.0);
+///
+/// let v = lerp(Vec3::new(1.0_f32, 1.0, 1.0), Vec3::new(3.0, 1.0, -1.0), 0.5);
+///
+/// assert_eq!(v, Vec3::new(2.0, 1.0, 0.0));
+/// assert_eq!(lerp(Quat::identity(), Quat::identity(), 0.3), Quat::identity());
+///
+/// // Opposite signs describe the same rotation, so the shortest path stays at the identity.
+/// let flipped = Quat::from((0.0, 0.0, 0.0, -1.0));
+///
+/// assert_eq!(lerp(Quat::identity(), flipped, 0.5), Quat::identity());
+/// ```
+pub fn lerp<T: Lerp>(a: T, b: T, t: T::Factor) -> T {
+    a.lerp(b, t)
+}
@@ -0,0 +1,137 @@
+#[cfg(not(feature = "std"))]
+use alloc::vec::Vec;
+use core::cmp::Ordering;
+
+/// A heap allocated structure for representing a value curve with keys at arbitrary times.
+pub struct Keyframes<T>(Vec<(f32, T)>);
+
+impl<T> Keyframes<T>
+where
+    T: Default + Clone + Copy + From<f32> + Into<f32>,
+{
+    /// Constructs a `Keyframes` object from a slice of `(time, value)` pairs. The keys are
+    /// sorted by time using `f32::total_cmp`, so they can be given in any order, and a NaN time
+    /// is sorted after every other key instead of panicking.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gamemath::Keyframes;
+    ///
+    /// let k: Keyframes<f32> = Keyframes::new(&[(2.0, 5.0), (0.0, 0.0), (0.5, 10.0)]);
+    ///
+    /// assert_eq!(k.len(), 3);
+    /// assert_eq!(k.evaluate(0.5), 10.0);
+    ///
+    /// let k: Keyframes<f32> = Keyframes::new(&[(f32::NAN, 1.0), (1.0, 2.0), (0.0, 0.0)]);
+    ///
+    /// assert_eq!(k.len(), 3);
+    /// assert_eq!(k.evaluate(0.5), 1.0);
+    /// ```
+    pub fn new(keys: &[(f32, T)]) -> Keyframes<T> {
+        let mut keys: Vec<(f32, T)> = keys.into();
+
+        keys.sort_by(|a, b| a.0.total_cmp(&b.0));
+
+        Keyframes(keys)
+    }
+
+    /// Evaluates the keyframes at a point in time, linearly interpolating between the two keys
+    /// surrounding it. Times before the first key or after the last key are clamped to the value
+    /// of that key.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gamemath::Keyframes;
+    ///
+    /// let k: Keyframes<f32> = Keyframes::new(&[(0.0, 0.0), (0.5, 10.0), (2.0, 4.0)]);
+    ///
+    /// assert_eq!(k.evaluate(0.25), 5.0);
+    /// assert_eq!(k.evaluate(1.25), 7.0);
+    /// assert_eq!(k.evaluate(-1.0), 0.0);
+    /// assert_eq!(k.evaluate(3.0), 4.0);
+    /// ```
+    pub fn evaluate(&self, time: f32) -> T {
+        let keys = &self.0;
+
+        match keys.len() {
+            0 => T::default(),
+            1 => keys[0].1,
+            len => {
+                let index = keys.partition_point(|key| key.0 <= time);
+
+                if index == 0 {
+                    keys[0].1
+                } else if index == len {
+                    keys[len - 1].1
+                } else {
+                    let (start_time, start) = keys[index - 1];
+                    let (end_time, end) = keys[index];
+                    let factor = (time - start_time) / (end_time - start_time);
+
+                    ((1.0 - factor) * start.into() + factor * end.into()).into()
+                }
+            }
+        }
+    }
+
+    /// Inserts a key at a point in time, keeping the keys sorted by time in the same order as
+    /// `new`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gamemath::Keyframes;
+    ///
+    /// let mut k: Keyframes<f32> = Keyframes::new(&[(0.0, 0.0), (2.0, 4.0)]);
+    ///
+    /// k.insert(1.0, 10.0);
+    ///
+    /// assert_eq!(k.len(), 3);
+    /// assert_eq!(k.evaluate(0.5), 5.0);
+    ///
+    /// k.insert(f32::NAN, 1.0);
+    /// k.insert(3.0, 6.0);
+    ///
+    /// assert_eq!(k.len(), 5);
+    /// assert_eq!(k.evaluate(2.5), 5.0);
+    /// ```
+    pub fn insert(&mut self, time: f32, value: T) {
+        let index = self
+            .0
+            .partition_point(|key| key.0.total_cmp(&time) != Ordering::Greater);
+
+        self.0.insert(index, (time, value));
+    }
+
+    /// Returns the number of keys.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gamemath::Keyframes;
+    ///
+    /// let k: Keyframes<f32> = Keyframes::new(&[(0.0, 0.0), (0.5, 10.0)]);
+    ///
+    /// assert_eq!(k.len(), 2);
+    /// ```
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    /// Returns `true` if there are no keys.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gamemath::Keyframes;
+    ///
+    /// let k: Keyframes<f32> = Keyframes::new(&[]);
+    ///
+    /// assert!(k.is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+}
@@ -1,20 +1,98 @@
 //! A simple math library containing the most common data structures used for 2D/3D rendering and
 //! general game development.
 //!
+//! The crate depends on the standard library by default through the `std` feature. It can be
+//! built for `no_std` targets by disabling the default features and enabling the `libm` feature
+//! instead, which provides the floating-point functions otherwise taken from `std`. `Curve` and
+//! `Keyframes` still require an allocator through the `alloc` crate.
+//!
+//! The matrix and quaternion types use `f32` components, with `Mat2d`, `Mat3d`, `Mat4d` and
+//! `Quatd` mirroring them over `f64` for computations that need double precision.
+//!
+//! Enabling the `simd` feature makes `Mat4` multiplication use SSE instructions on x86_64
+//! targets, while other targets keep using the scalar implementation.
+//!
+//! Enabling the `approx` feature implements the `AbsDiffEq`, `RelativeEq` and `UlpsEq` traits of
+//! the `approx` crate for the vector, matrix and quaternion types, while enabling the `rand`
+//! feature allows sampling random vectors through the `rand` crate's `Standard` distribution, as
+//! well as random directions and rotations through `UnitSphere` and `UnitQuaternion`. Enabling
+//! the `mint` feature adds conversions to and from the types of the `mint` crate.
+//!
+#![cfg_attr(not(feature = "std"), no_std)]
+
+#[cfg(all(not(feature = "std"), not(feature = "libm")))]
+compile_error!("gamemath requires either the `std` or the `libm` feature to be enabled");
+
+#[cfg(feature = "std")]
+extern crate core;
+
+extern crate alloc;
+
+#[cfg(feature = "approx")]
+extern crate approx;
+#[cfg(not(feature = "std"))]
+extern crate libm;
+#[cfg(feature = "mint")]
+extern crate mint;
+#[cfg(feature = "rand")]
+extern crate rand;
+
+mod aabb;
+mod approx_eq;
+#[cfg(feature = "approx")]
+mod approx_impls;
+mod axis;
 mod curve;
+mod interpolation;
+mod keyframes;
 mod mat2;
+mod mat2d;
 mod mat3;
+mod mat3d;
 mod mat4;
+mod mat4d;
+#[cfg(all(not(feature = "std"), not(test)))]
+mod math;
+#[cfg(feature = "mint")]
+mod mint_impls;
+mod normalize;
+mod plane;
+mod projection_error;
 mod quat;
+mod quatd;
+#[cfg(feature = "rand")]
+mod rand_impls;
+mod ray;
+#[cfg(all(feature = "simd", target_arch = "x86_64"))]
+mod simd;
+mod transform;
+mod triangle;
 mod vec2;
 mod vec3;
 mod vec4;
 
+pub use self::aabb::Aabb;
+pub use self::approx_eq::Approx;
+pub use self::axis::Axis;
 pub use self::curve::Curve;
+pub use self::interpolation::{bezier3, hermite, lerp, smootherstep, smoothstep, Lerp};
+pub use self::keyframes::Keyframes;
 pub use self::mat2::Mat2;
+pub use self::mat2d::Mat2d;
 pub use self::mat3::Mat3;
+pub use self::mat3d::Mat3d;
 pub use self::mat4::Mat4;
+pub use self::mat4d::Mat4d;
+pub use self::normalize::Normalize;
+pub use self::plane::Plane;
+pub use self::projection_error::ProjectionError;
 pub use self::quat::Quat;
+pub use self::quatd::Quatd;
+#[cfg(feature = "rand")]
+pub use self::rand_impls::{UnitQuaternion, UnitSphere};
+pub use self::ray::Ray;
+pub use self::transform::Transform;
+pub use self::triangle::{barycentric, point_in_triangle};
 pub use self::vec2::Vec2;
 pub use self::vec3::Vec3;
 pub use self::vec4::Vec4;
@@ -1,4 +1,6 @@
-use std;
+use core;
+#[cfg(all(not(feature = "std"), not(test)))]
+use math::Float;
 use vec2::Vec2;
 
 type Row = (f32, f32);
@@ -68,6 +70,67 @@ impl Mat2 {
         *self = self.transposed();
     }
 
+    /// Calculates and returns the determinant of the calling `Mat2` object.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gamemath::Mat2;
+    ///
+    /// let m: Mat2 = ((1.0, 2.0),
+    ///                (3.0, 4.0)).into();
+    ///
+    /// assert_eq!(m.determinant(), -2.0);
+    /// ```
+    pub fn determinant(&self) -> f32 {
+        self[0][0] * self[1][1] - self[0][1] * self[1][0]
+    }
+
+    /// Calculates and returns the inverted matrix of the calling `Mat2` object, or `None` if the
+    /// matrix is singular. A matrix counts as singular when its determinant is zero, not finite, or
+    /// negligible next to the product of its row lengths, so uniformly scaled matrices invert
+    /// no matter how small the scale is.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gamemath::{Approx, Mat2};
+    ///
+    /// let m: Mat2 = ((1.0, 2.0),
+    ///                (3.0, 4.0)).into();
+    /// let singular: Mat2 = ((1.0, 2.0),
+    ///                       (2.0, 4.0)).into();
+    ///
+    /// assert_eq!(m.try_inverse().map(|inverse| m * inverse), Some(Mat2::identity()));
+    /// assert_eq!(singular.try_inverse(), None);
+    ///
+    /// let small = Mat2::from(1e-4_f32);
+    /// let small_inverse = small.try_inverse().unwrap();
+    ///
+    /// assert_eq!(Approx(small * small_inverse, 1e-5), Approx(Mat2::identity(), 1e-5));
+    /// ```
+    pub fn try_inverse(&self) -> Option<Mat2> {
+        let determinant = self.determinant();
+
+        if self.is_singular(determinant) {
+            return None;
+        }
+
+        Some(
+            (
+                (self[1][1] / determinant, -self[0][1] / determinant),
+                (-self[1][0] / determinant, self[0][0] / determinant),
+            )
+                .into(),
+        )
+    }
+
+    fn is_singular(&self, determinant: f32) -> bool {
+        let row_lengths = self[0].length() * self[1].length();
+
+        !determinant.is_finite() || determinant.abs() <= f32::EPSILON * row_lengths
+    }
+
     /// Constructs a 2x2 rotation matrix from a radians value.
     ///
     /// # Examples
@@ -122,6 +185,23 @@ impl Mat2 {
         *self = self.rotated(radians);
     }
 
+    /// Constructs a 2x2 scaling matrix from a `Vec2<f32>`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gamemath::{Mat2, Vec2};
+    ///
+    /// let m = Mat2::scaling(Vec2::new(3.0, 6.0));
+    ///
+    /// assert_eq!(m, Mat2::identity().scaled(Vec2::new(3.0, 6.0)));
+    /// assert_eq!(m, ((3.0, 0.0),
+    ///                (0.0, 6.0)).into());
+    /// ```
+    pub fn scaling(factor: Vec2<f32>) -> Mat2 {
+        ((factor.x, 0.0), (0.0, factor.y)).into()
+    }
+
     /// Calculates and returns a `Mat2` object representing the calling `Mat2` object scaled
     /// by a `Vec2<f32>`.
     ///
@@ -161,6 +241,104 @@ impl Mat2 {
     pub fn scale(&mut self, factor: Vec2<f32>) {
         *self = self.scaled(factor);
     }
+
+    /// Returns a copy of the row at an index of the calling `Mat2` object, which is the same
+    /// `Vec2<f32>` that indexing the matrix with a single index returns.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gamemath::{Mat2, Vec2};
+    ///
+    /// let m: Mat2 = ((0.0, 1.0),
+    ///                (2.0, 3.0)).into();
+    ///
+    /// assert_eq!(m.row(1), Vec2::new(2.0, 3.0));
+    /// ```
+    pub fn row(&self, index: usize) -> Vec2<f32> {
+        self[index]
+    }
+
+    /// Assembles and returns the column at an index of the calling `Mat2` object.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gamemath::{Mat2, Vec2};
+    ///
+    /// let m: Mat2 = ((0.0, 1.0),
+    ///                (2.0, 3.0)).into();
+    ///
+    /// assert_eq!(m.col(0), Vec2::new(0.0, 2.0));
+    /// ```
+    pub fn col(&self, index: usize) -> Vec2<f32> {
+        (self[0][index], self[1][index]).into()
+    }
+
+    /// Returns a reference to the row at an index of the calling `Mat2` object, or `None` if the
+    /// index is out of range, instead of panicking like indexing does.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gamemath::Mat2;
+    ///
+    /// let m = Mat2::identity();
+    ///
+    /// assert_eq!(m.get(0), Some(&m[0]));
+    /// assert_eq!(m.get(2), None);
+    /// ```
+    pub fn get(&self, index: usize) -> Option<&Vec2<f32>> {
+        self.rows.get(index)
+    }
+
+    /// Returns an iterator over references to the elements of the calling `Mat2` object, going
+    /// through them row by row.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gamemath::Mat2;
+    ///
+    /// let elements = [1.0, 2.0, 3.0, 4.0];
+    /// let m = Mat2::from(elements);
+    ///
+    /// assert_eq!(m.iter().count(), 4);
+    /// assert_eq!(m.iter().cloned().collect::<Vec<f32>>(), elements.to_vec());
+    /// ```
+    pub fn iter(&self) -> impl Iterator<Item = &f32> {
+        self.rows
+            .iter()
+            .flat_map(|row| core::iter::once(&row.x).chain(core::iter::once(&row.y)))
+    }
+
+    /// Returns an iterator over mutable references to the elements of the calling `Mat2`
+    /// object, going through them row by row.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gamemath::Mat2;
+    ///
+    /// let mut m = Mat2::identity();
+    ///
+    /// for element in m.iter_mut() {
+    ///     *element += 1.0;
+    /// }
+    ///
+    /// assert_eq!(m[0][0], 2.0);
+    /// assert_eq!(m[1][0], 1.0);
+    /// ```
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = &mut f32> {
+        self.rows.iter_mut().flat_map(|row| {
+            let Vec2 {
+                ref mut x,
+                ref mut y,
+            } = *row;
+
+            core::iter::once(x).chain(core::iter::once(y))
+        })
+    }
 }
 
 impl Default for Mat2 {
@@ -218,6 +396,31 @@ impl From<[f32; 4]> for Mat2 {
     }
 }
 
+/// Converts a slice of elements in row-major order into a `Mat2`, failing if the length of the
+/// slice is not exactly 4.
+///
+/// # Examples
+///
+/// ```
+/// use gamemath::Mat2;
+/// use std::convert::TryFrom;
+///
+/// let elements: Vec<f32> = (0..5).map(|i| i as f32).collect();
+/// let m = Mat2::try_from(&elements[..4]).unwrap();
+///
+/// assert_eq!(m[0][1], 1.0);
+/// assert_eq!(m[1][0], 2.0);
+/// assert!(Mat2::try_from(&elements[..3]).is_err());
+/// assert!(Mat2::try_from(&elements[..]).is_err());
+/// ```
+impl<'a> core::convert::TryFrom<&'a [f32]> for Mat2 {
+    type Error = core::array::TryFromSliceError;
+
+    fn try_from(slice: &'a [f32]) -> Result<Mat2, core::array::TryFromSliceError> {
+        <[f32; 4] as core::convert::TryFrom<&[f32]>>::try_from(slice).map(Mat2::from)
+    }
+}
+
 impl From<[Vec2<f32>; 2]> for Mat2 {
     fn from(slice: [Vec2<f32>; 2]) -> Mat2 {
         Mat2 {
@@ -226,15 +429,26 @@ impl From<[Vec2<f32>; 2]> for Mat2 {
     }
 }
 
-impl From<(Vec2<f32>, Vec2<f32>, Vec2<f32>)> for Mat2 {
-    fn from(tuple: (Vec2<f32>, Vec2<f32>, Vec2<f32>)) -> Mat2 {
+/// Constructs a `Mat2` from a tuple of two `Vec2<f32>` used as its rows.
+///
+/// # Examples
+///
+/// ```
+/// use gamemath::{Mat2, Vec2};
+///
+/// let m: Mat2 = (Vec2::new(1.0, 2.0), Vec2::new(3.0, 4.0)).into();
+///
+/// assert_eq!(m, ((1.0, 2.0), (3.0, 4.0)).into());
+/// ```
+impl From<(Vec2<f32>, Vec2<f32>)> for Mat2 {
+    fn from(tuple: (Vec2<f32>, Vec2<f32>)) -> Mat2 {
         Mat2 {
             rows: [tuple.0, tuple.1],
         }
     }
 }
 
-impl std::ops::Index<usize> for Mat2 {
+impl core::ops::Index<usize> for Mat2 {
     type Output = Vec2<f32>;
 
     fn index(&self, index: usize) -> &Vec2<f32> {
@@ -246,7 +460,7 @@ impl std::ops::Index<usize> for Mat2 {
     }
 }
 
-impl std::ops::IndexMut<usize> for Mat2 {
+impl core::ops::IndexMut<usize> for Mat2 {
     fn index_mut(&mut self, index: usize) -> &mut Vec2<f32> {
         match index {
             0 => &mut self.rows[0],
@@ -256,7 +470,7 @@ impl std::ops::IndexMut<usize> for Mat2 {
     }
 }
 
-impl std::ops::Index<(usize, usize)> for Mat2 {
+impl core::ops::Index<(usize, usize)> for Mat2 {
     type Output = f32;
 
     fn index(&self, index: (usize, usize)) -> &f32 {
@@ -264,13 +478,13 @@ impl std::ops::Index<(usize, usize)> for Mat2 {
     }
 }
 
-impl std::ops::IndexMut<(usize, usize)> for Mat2 {
+impl core::ops::IndexMut<(usize, usize)> for Mat2 {
     fn index_mut(&mut self, index: (usize, usize)) -> &mut f32 {
         &mut self.rows[index.0][index.1]
     }
 }
 
-impl std::ops::Add for Mat2 {
+impl core::ops::Add for Mat2 {
     type Output = Mat2;
 
     fn add(self, right: Mat2) -> Mat2 {
@@ -280,13 +494,13 @@ impl std::ops::Add for Mat2 {
     }
 }
 
-impl std::ops::AddAssign for Mat2 {
+impl core::ops::AddAssign for Mat2 {
     fn add_assign(&mut self, right: Mat2) {
         *self = *self + right;
     }
 }
 
-impl std::ops::Sub for Mat2 {
+impl core::ops::Sub for Mat2 {
     type Output = Mat2;
 
     fn sub(self, right: Mat2) -> Mat2 {
@@ -296,13 +510,59 @@ impl std::ops::Sub for Mat2 {
     }
 }
 
-impl std::ops::SubAssign for Mat2 {
+impl core::ops::SubAssign for Mat2 {
     fn sub_assign(&mut self, right: Mat2) {
         *self = *self - right;
     }
 }
 
-impl std::ops::Mul<Vec2<f32>> for Mat2 {
+/// Negates every element of a `Mat2`.
+///
+/// # Examples
+///
+/// ```
+/// use gamemath::Mat2;
+///
+/// let m = -Mat2::identity();
+///
+/// assert_eq!(m[0][0], -1.0);
+/// assert_eq!(m[1][1], -1.0);
+/// assert_eq!(m + Mat2::identity(), Mat2::from(0.0));
+/// ```
+impl core::ops::Neg for Mat2 {
+    type Output = Mat2;
+
+    fn neg(self) -> Mat2 {
+        Mat2 {
+            rows: [-self[0], -self[1]],
+        }
+    }
+}
+
+/// Divides every element of a `Mat2` by a scalar value. Dividing by zero is not guarded against,
+/// and follows the IEEE 754 rules, producing infinite or NaN elements.
+///
+/// # Examples
+///
+/// ```
+/// use gamemath::Mat2;
+///
+/// let m = Mat2::from([2.0; 4]) / 2.0;
+///
+/// assert_eq!(m, Mat2::from([1.0; 4]));
+/// assert_eq!(Mat2::identity() / 4.0, Mat2::from(0.25));
+/// ```
+impl core::ops::Div<f32> for Mat2 {
+    type Output = Mat2;
+
+    fn div(self, right: f32) -> Mat2 {
+        Mat2 {
+            rows: [self[0] / right, self[1] / right],
+        }
+    }
+}
+
+impl core::ops::Mul<Vec2<f32>> for Mat2 {
     type Output = Vec2<f32>;
 
     fn mul(self, vec: Vec2<f32>) -> Vec2<f32> {
@@ -310,7 +570,7 @@ impl std::ops::Mul<Vec2<f32>> for Mat2 {
     }
 }
 
-impl std::ops::Mul<Mat2> for Mat2 {
+impl core::ops::Mul<Mat2> for Mat2 {
     type Output = Mat2;
 
     fn mul(self, right: Mat2) -> Mat2 {
@@ -330,7 +590,7 @@ impl std::ops::Mul<Mat2> for Mat2 {
     }
 }
 
-impl std::ops::MulAssign<Mat2> for Mat2 {
+impl core::ops::MulAssign<Mat2> for Mat2 {
     fn mul_assign(&mut self, right: Mat2) {
         *self = *self * right;
     }
@@ -0,0 +1,597 @@
+use core;
+#[cfg(all(not(feature = "std"), not(test)))]
+use math::Float;
+use vec2::Vec2;
+
+type Row = (f64, f64);
+type InlineMat2 = (f64, f64, f64, f64);
+
+/// A 2x2-component Euclidean matrix of `f64` components, mirroring `Mat2` for computations that
+/// need double precision.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Mat2d {
+    /// The two rows of the matrix, represented by an array of two `Vec2<f64>` objects.
+    pub rows: [Vec2<f64>; 2],
+}
+
+impl Mat2d {
+    /// Constructs a 2x2 identity matrix.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gamemath::{Mat2d, Vec2};
+    ///
+    /// let m = Mat2d::identity();
+    ///
+    /// assert_eq!(m[0], Vec2::new(1.0, 0.0));
+    /// assert_eq!(m[1], Vec2::new(0.0, 1.0));
+    /// ```
+    pub fn identity() -> Mat2d {
+        Self::default()
+    }
+
+    /// Extracts and returns a transposed representation of the calling `Mat2d` object.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gamemath::Mat2d;
+    ///
+    /// let m: Mat2d = ((0.0, 1.0),
+    ///                 (2.0, 3.0)).into();
+    ///
+    /// assert_eq!(m.transposed(), ((0.0, 2.0),
+    ///                             (1.0, 3.0)).into());
+    /// ```
+    pub fn transposed(&self) -> Mat2d {
+        (
+            (self[0][0], self[1][0]),
+            (self[0][1], self[1][1]),
+        ).into()
+    }
+
+    /// Performs a transpose operation on the calling `Mat2d` object.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gamemath::Mat2d;
+    ///
+    /// let mut m: Mat2d = ((0.0, 1.0),
+    ///                     (3.0, 4.0)).into();
+    ///
+    /// m.transpose();
+    ///
+    /// assert_eq!(m, ((0.0, 3.0),
+    ///                (1.0, 4.0)).into());
+    /// ```
+    pub fn transpose(&mut self) {
+        *self = self.transposed();
+    }
+
+    /// Calculates and returns the determinant of the calling `Mat2d` object.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gamemath::Mat2d;
+    ///
+    /// let m: Mat2d = ((1.0, 2.0),
+    ///                (3.0, 4.0)).into();
+    ///
+    /// assert_eq!(m.determinant(), -2.0);
+    /// ```
+    pub fn determinant(&self) -> f64 {
+        self[0][0] * self[1][1] - self[0][1] * self[1][0]
+    }
+
+    /// Calculates and returns the inverted matrix of the calling `Mat2d` object, or `None` if the
+    /// matrix is singular. A matrix counts as singular when its determinant is zero, not finite, or
+    /// negligible next to the product of its row lengths, so uniformly scaled matrices invert
+    /// no matter how small the scale is.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gamemath::{Approx, Mat2d};
+    ///
+    /// let m: Mat2d = ((1.0, 2.0),
+    ///                (3.0, 4.0)).into();
+    /// let singular: Mat2d = ((1.0, 2.0),
+    ///                       (2.0, 4.0)).into();
+    ///
+    /// assert_eq!(m.try_inverse().map(|inverse| m * inverse), Some(Mat2d::identity()));
+    /// assert_eq!(singular.try_inverse(), None);
+    ///
+    /// let small = Mat2d::from(1e-4_f64);
+    /// let small_inverse = small.try_inverse().unwrap();
+    ///
+    /// assert_eq!(Approx(small * small_inverse, 1e-5), Approx(Mat2d::identity(), 1e-5));
+    /// ```
+    pub fn try_inverse(&self) -> Option<Mat2d> {
+        let determinant = self.determinant();
+
+        if self.is_singular(determinant) {
+            return None;
+        }
+
+        Some(
+            (
+                (self[1][1] / determinant, -self[0][1] / determinant),
+                (-self[1][0] / determinant, self[0][0] / determinant),
+            )
+                .into(),
+        )
+    }
+
+    fn is_singular(&self, determinant: f64) -> bool {
+        let row_lengths = self[0].length() * self[1].length();
+
+        !determinant.is_finite() || determinant.abs() <= f64::EPSILON * row_lengths
+    }
+
+    /// Constructs a 2x2 rotation matrix from a radians value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gamemath::{Mat2d, Vec2};
+    ///
+    /// let m = Mat2d::rotation(1.0);
+    ///
+    /// assert_eq!(m[0], Vec2::new(0.5403023058681398,  -0.8414709848078965,));
+    /// assert_eq!(m[1], Vec2::new(0.8414709848078965,  0.5403023058681398,));
+    /// ```
+    pub fn rotation(radians: f64) -> Mat2d {
+        let sin = radians.sin();
+        let cos = radians.cos();
+
+        ((cos, -sin), (sin, cos)).into()
+    }
+
+    /// Calculates and returns a `Mat2d` object representing the calling `Mat2d` object rotated
+    /// by a radians value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gamemath::Mat2d;
+    ///
+    /// let m = Mat2d::identity().rotated(1.0);
+    ///
+    /// assert_eq!(m, ((0.5403023058681398,  -0.8414709848078965,),
+    ///                (0.8414709848078965,  0.5403023058681398,)).into());
+    /// ```
+    pub fn rotated(&self, radians: f64) -> Mat2d {
+        *self * Mat2d::rotation(radians)
+    }
+
+    /// Rotates the calling `Mat2d` object by a radians value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gamemath::Mat2d;
+    ///
+    /// let mut m = Mat2d::identity();
+    ///
+    /// m.rotate(1.0);
+    ///
+    /// assert_eq!(m, ((0.5403023058681398,  -0.8414709848078965,),
+    ///                (0.8414709848078965,  0.5403023058681398,)).into());
+    /// ```
+    pub fn rotate(&mut self, radians: f64) {
+        *self = self.rotated(radians);
+    }
+
+    /// Constructs a 2x2 scaling matrix from a `Vec2<f64>`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gamemath::{Mat2d, Vec2};
+    ///
+    /// let m = Mat2d::scaling(Vec2::new(3.0, 6.0));
+    ///
+    /// assert_eq!(m, Mat2d::identity().scaled(Vec2::new(3.0, 6.0)));
+    /// assert_eq!(m, ((3.0, 0.0),
+    ///                (0.0, 6.0)).into());
+    /// ```
+    pub fn scaling(factor: Vec2<f64>) -> Mat2d {
+        ((factor.x, 0.0), (0.0, factor.y)).into()
+    }
+
+    /// Calculates and returns a `Mat2d` object representing the calling `Mat2d` object scaled
+    /// by a `Vec2<f64>`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gamemath::{Mat2d, Vec2};
+    ///
+    /// let m = Mat2d::identity();
+    ///
+    /// assert_eq!(m.scaled(Vec2::new(3.0, 6.0)), ((3.0, 0.0),
+    ///                                            (0.0, 6.0)).into());
+    /// ```
+    pub fn scaled(&self, factor: Vec2<f64>) -> Mat2d {
+        let mut matrix = *self;
+
+        matrix[0] *= factor.x;
+        matrix[1] *= factor.y;
+
+        matrix
+    }
+
+    /// Performs the scale operation on the calling `Mat2d` object, scaling it by a `Vec2<f64>`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gamemath::{Mat2d, Vec2};
+    ///
+    /// let mut m = Mat2d::identity();
+    ///
+    /// m.scale(Vec2::new(5.0, 2.0));
+    ///
+    /// assert_eq!(m, ((5.0, 0.0),
+    ///                (0.0, 2.0)).into());
+    /// ```
+    pub fn scale(&mut self, factor: Vec2<f64>) {
+        *self = self.scaled(factor);
+    }
+
+    /// Returns a copy of the row at an index of the calling `Mat2d` object, which is the same
+    /// `Vec2<f64>` that indexing the matrix with a single index returns.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gamemath::{Mat2d, Vec2};
+    ///
+    /// let m: Mat2d = ((0.0, 1.0),
+    ///                 (2.0, 3.0)).into();
+    ///
+    /// assert_eq!(m.row(1), Vec2::new(2.0, 3.0));
+    /// ```
+    pub fn row(&self, index: usize) -> Vec2<f64> {
+        self[index]
+    }
+
+    /// Assembles and returns the column at an index of the calling `Mat2d` object.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gamemath::{Mat2d, Vec2};
+    ///
+    /// let m: Mat2d = ((0.0, 1.0),
+    ///                 (2.0, 3.0)).into();
+    ///
+    /// assert_eq!(m.col(0), Vec2::new(0.0, 2.0));
+    /// ```
+    pub fn col(&self, index: usize) -> Vec2<f64> {
+        (self[0][index], self[1][index]).into()
+    }
+
+    /// Returns a reference to the row at an index of the calling `Mat2d` object, or `None` if
+    /// the index is out of range, instead of panicking like indexing does.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gamemath::Mat2d;
+    ///
+    /// let m = Mat2d::identity();
+    ///
+    /// assert_eq!(m.get(0), Some(&m[0]));
+    /// assert_eq!(m.get(2), None);
+    /// ```
+    pub fn get(&self, index: usize) -> Option<&Vec2<f64>> {
+        self.rows.get(index)
+    }
+
+    /// Returns an iterator over references to the elements of the calling `Mat2d` object, going
+    /// through them row by row.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gamemath::Mat2d;
+    ///
+    /// let elements = [1.0, 2.0, 3.0, 4.0];
+    /// let m = Mat2d::from(elements);
+    ///
+    /// assert_eq!(m.iter().count(), 4);
+    /// assert_eq!(m.iter().cloned().collect::<Vec<f64>>(), elements.to_vec());
+    /// ```
+    pub fn iter(&self) -> impl Iterator<Item = &f64> {
+        self.rows
+            .iter()
+            .flat_map(|row| core::iter::once(&row.x).chain(core::iter::once(&row.y)))
+    }
+
+    /// Returns an iterator over mutable references to the elements of the calling `Mat2d`
+    /// object, going through them row by row.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gamemath::Mat2d;
+    ///
+    /// let mut m = Mat2d::identity();
+    ///
+    /// for element in m.iter_mut() {
+    ///     *element += 1.0;
+    /// }
+    ///
+    /// assert_eq!(m[0][0], 2.0);
+    /// assert_eq!(m[1][0], 1.0);
+    /// ```
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = &mut f64> {
+        self.rows.iter_mut().flat_map(|row| {
+            let Vec2 {
+                ref mut x,
+                ref mut y,
+            } = *row;
+
+            core::iter::once(x).chain(core::iter::once(y))
+        })
+    }
+}
+
+impl Default for Mat2d {
+    fn default() -> Mat2d {
+        ((1.0, 0.0), (0.0, 1.0)).into()
+    }
+}
+
+impl From<f64> for Mat2d {
+    fn from(value: f64) -> Mat2d {
+        Mat2d {
+            rows: [
+                (value, 0.0).into(),
+                (0.0, value).into(),
+            ],
+        }
+    }
+}
+
+impl From<(Row, Row)> for Mat2d {
+    fn from(tuple: (Row, Row)) -> Mat2d {
+        Mat2d {
+            rows: [tuple.0.into(), tuple.1.into()],
+        }
+    }
+}
+
+impl From<InlineMat2> for Mat2d {
+    fn from(tuple: InlineMat2) -> Mat2d {
+        Mat2d {
+            rows: [
+                (tuple.0, tuple.1).into(),
+                (tuple.2, tuple.3).into(),
+            ],
+        }
+    }
+}
+
+impl From<[[f64; 2]; 2]> for Mat2d {
+    fn from(slice: [[f64; 2]; 2]) -> Mat2d {
+        Mat2d {
+            rows: [slice[0].into(), slice[1].into()],
+        }
+    }
+}
+
+impl From<[f64; 4]> for Mat2d {
+    fn from(slice: [f64; 4]) -> Mat2d {
+        Mat2d {
+            rows: [
+                (slice[0], slice[1]).into(),
+                (slice[2], slice[3]).into(),
+            ],
+        }
+    }
+}
+
+/// Converts a slice of elements in row-major order into a `Mat2d`, failing if the length of the
+/// slice is not exactly 4.
+///
+/// # Examples
+///
+/// ```
+/// use gamemath::Mat2d;
+/// use std::convert::TryFrom;
+///
+/// let elements: Vec<f64> = (0..5).map(|i| i as f64).collect();
+/// let m = Mat2d::try_from(&elements[..4]).unwrap();
+///
+/// assert_eq!(m[0][1], 1.0);
+/// assert_eq!(m[1][0], 2.0);
+/// assert!(Mat2d::try_from(&elements[..3]).is_err());
+/// assert!(Mat2d::try_from(&elements[..]).is_err());
+/// ```
+impl<'a> core::convert::TryFrom<&'a [f64]> for Mat2d {
+    type Error = core::array::TryFromSliceError;
+
+    fn try_from(slice: &'a [f64]) -> Result<Mat2d, core::array::TryFromSliceError> {
+        <[f64; 4] as core::convert::TryFrom<&[f64]>>::try_from(slice).map(Mat2d::from)
+    }
+}
+
+impl From<[Vec2<f64>; 2]> for Mat2d {
+    fn from(slice: [Vec2<f64>; 2]) -> Mat2d {
+        Mat2d {
+            rows: [slice[0], slice[1]],
+        }
+    }
+}
+
+/// Constructs a `Mat2d` from a tuple of two `Vec2<f64>` used as its rows.
+///
+/// # Examples
+///
+/// ```
+/// use gamemath::{Mat2d, Vec2};
+///
+/// let m: Mat2d = (Vec2::new(1.0, 2.0), Vec2::new(3.0, 4.0)).into();
+///
+/// assert_eq!(m, ((1.0, 2.0), (3.0, 4.0)).into());
+/// ```
+impl From<(Vec2<f64>, Vec2<f64>)> for Mat2d {
+    fn from(tuple: (Vec2<f64>, Vec2<f64>)) -> Mat2d {
+        Mat2d {
+            rows: [tuple.0, tuple.1],
+        }
+    }
+}
+
+impl core::ops::Index<usize> for Mat2d {
+    type Output = Vec2<f64>;
+
+    fn index(&self, index: usize) -> &Vec2<f64> {
+        match index {
+            0 => &self.rows[0],
+            1 => &self.rows[1],
+            _ => panic!("Mat2d index out of range!"),
+        }
+    }
+}
+
+impl core::ops::IndexMut<usize> for Mat2d {
+    fn index_mut(&mut self, index: usize) -> &mut Vec2<f64> {
+        match index {
+            0 => &mut self.rows[0],
+            1 => &mut self.rows[1],
+            _ => panic!("Mat2d index out of range!"),
+        }
+    }
+}
+
+impl core::ops::Index<(usize, usize)> for Mat2d {
+    type Output = f64;
+
+    fn index(&self, index: (usize, usize)) -> &f64 {
+        &self.rows[index.0][index.1]
+    }
+}
+
+impl core::ops::IndexMut<(usize, usize)> for Mat2d {
+    fn index_mut(&mut self, index: (usize, usize)) -> &mut f64 {
+        &mut self.rows[index.0][index.1]
+    }
+}
+
+impl core::ops::Add for Mat2d {
+    type Output = Mat2d;
+
+    fn add(self, right: Mat2d) -> Mat2d {
+        Mat2d {
+            rows: [self[0] + right[0], self[1] + right[1]],
+        }
+    }
+}
+
+impl core::ops::AddAssign for Mat2d {
+    fn add_assign(&mut self, right: Mat2d) {
+        *self = *self + right;
+    }
+}
+
+impl core::ops::Sub for Mat2d {
+    type Output = Mat2d;
+
+    fn sub(self, right: Mat2d) -> Mat2d {
+        Mat2d {
+            rows: [self[0] - right[0], self[1] - right[1]],
+        }
+    }
+}
+
+impl core::ops::SubAssign for Mat2d {
+    fn sub_assign(&mut self, right: Mat2d) {
+        *self = *self - right;
+    }
+}
+
+/// Negates every element of a `Mat2d`.
+///
+/// # Examples
+///
+/// ```
+/// use gamemath::Mat2d;
+///
+/// let m = -Mat2d::identity();
+///
+/// assert_eq!(m[0][0], -1.0);
+/// assert_eq!(m[1][1], -1.0);
+/// assert_eq!(m + Mat2d::identity(), Mat2d::from(0.0));
+/// ```
+impl core::ops::Neg for Mat2d {
+    type Output = Mat2d;
+
+    fn neg(self) -> Mat2d {
+        Mat2d {
+            rows: [-self[0], -self[1]],
+        }
+    }
+}
+
+/// Divides every element of a `Mat2d` by a scalar value. Dividing by zero is not guarded against,
+/// and follows the IEEE 754 rules, producing infinite or NaN elements.
+///
+/// # Examples
+///
+/// ```
+/// use gamemath::Mat2d;
+///
+/// let m = Mat2d::from([2.0; 4]) / 2.0;
+///
+/// assert_eq!(m, Mat2d::from([1.0; 4]));
+/// assert_eq!(Mat2d::identity() / 4.0, Mat2d::from(0.25));
+/// ```
+impl core::ops::Div<f64> for Mat2d {
+    type Output = Mat2d;
+
+    fn div(self, right: f64) -> Mat2d {
+        Mat2d {
+            rows: [self[0] / right, self[1] / right],
+        }
+    }
+}
+
+impl core::ops::Mul<Vec2<f64>> for Mat2d {
+    type Output = Vec2<f64>;
+
+    fn mul(self, vec: Vec2<f64>) -> Vec2<f64> {
+        (self[0].dot(vec), self[1].dot(vec)).into()
+    }
+}
+
+impl core::ops::Mul<Mat2d> for Mat2d {
+    type Output = Mat2d;
+
+    fn mul(self, right: Mat2d) -> Mat2d {
+        let mut result: Mat2d = 0.0.into();
+
+        result[0][0] =
+            self[0][0] * right[0][0] + self[1][0] * right[0][1];
+        result[0][1] =
+            self[0][1] * right[0][0] + self[1][1] * right[0][1];
+        
+        result[1][0] =
+            self[0][0] * right[1][0] + self[1][0] * right[1][1];
+        result[1][1] =
+            self[0][1] * right[1][0] + self[1][1] * right[1][1];
+
+        result
+    }
+}
+
+impl core::ops::MulAssign<Mat2d> for Mat2d {
+    fn mul_assign(&mut self, right: Mat2d) {
+        *self = *self * right;
+    }
+}
@@ -1,10 +1,9 @@
-use std;
+use core;
+#[cfg(all(not(feature = "std"), not(test)))]
+use math::Float;
 use vec2::Vec2;
 use vec3::Vec3;
 
-// TODO: Consider making Mat3 of a generic type instead of forcing f32.
-//       But would any type other than f64 ever be useful?
-
 type Row = (f32, f32, f32);
 type InlineMat3 = (f32, f32, f32, f32, f32, f32, f32, f32, f32);
 
@@ -79,6 +78,108 @@ impl Mat3 {
         *self = self.transposed();
     }
 
+    /// Calculates and returns the determinant of the calling `Mat3` object.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gamemath::Mat3;
+    ///
+    /// let m: Mat3 = ((1.0, 2.0, 3.0),
+    ///                (0.0, 1.0, 4.0),
+    ///                (5.0, 6.0, 0.0)).into();
+    ///
+    /// assert_eq!(m.determinant(), 1.0);
+    /// ```
+    pub fn determinant(&self) -> f32 {
+        self[0].dot(self[1].cross(self[2]))
+    }
+
+    /// Calculates and returns the inverted matrix of the calling `Mat3` object, or `None` if the
+    /// matrix is singular. A matrix counts as singular when its determinant is zero, not finite, or
+    /// negligible next to the product of its row lengths, so uniformly scaled matrices invert
+    /// no matter how small the scale is.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gamemath::{Approx, Mat3};
+    ///
+    /// let m: Mat3 = ((1.0, 2.0, 3.0),
+    ///                (0.0, 1.0, 4.0),
+    ///                (5.0, 6.0, 0.0)).into();
+    /// let singular: Mat3 = ((1.0, 2.0, 3.0),
+    ///                       (4.0, 5.0, 6.0),
+    ///                       (7.0, 8.0, 9.0)).into();
+    ///
+    /// assert_eq!(m.try_inverse().map(|inverse| m * inverse), Some(Mat3::identity()));
+    /// assert_eq!(singular.try_inverse(), None);
+    ///
+    /// let small = Mat3::from(0.001_f32);
+    /// let small_inverse = small.try_inverse().unwrap();
+    ///
+    /// assert_eq!(Approx(small * small_inverse, 1e-5), Approx(Mat3::identity(), 1e-5));
+    /// ```
+    pub fn try_inverse(&self) -> Option<Mat3> {
+        let determinant = self.determinant();
+
+        if self.is_singular(determinant) {
+            return None;
+        }
+
+        Some(Mat3::from_columns(
+            self[1].cross(self[2]) / determinant,
+            self[2].cross(self[0]) / determinant,
+            self[0].cross(self[1]) / determinant,
+        ))
+    }
+
+    fn is_singular(&self, determinant: f32) -> bool {
+        let row_lengths = self[0].length() * self[1].length() * self[2].length();
+
+        !determinant.is_finite() || determinant.abs() <= f32::EPSILON * row_lengths
+    }
+
+    /// Solves the linear system `self * x = b` for `x`, or returns `None` if the calling `Mat3`
+    /// object is singular by the same test as `try_inverse`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gamemath::{Mat3, Vec3};
+    ///
+    /// let m: Mat3 = ((2.0, 1.0, -1.0),
+    ///                (-3.0, -1.0, 2.0),
+    ///                (-2.0, 1.0, 2.0)).into();
+    /// let b = Vec3::new(8.0, -11.0, -3.0);
+    /// let x = m.solve(b).unwrap();
+    ///
+    /// assert!((x - Vec3::new(2.0, 3.0, -1.0)).length() < 0.00001);
+    /// assert!((m * x - b).length() < 0.00001);
+    /// assert!((x - m.try_inverse().unwrap() * b).length() < 0.00001);
+    ///
+    /// let singular: Mat3 = ((1.0, 2.0, 3.0),
+    ///                       (4.0, 5.0, 6.0),
+    ///                       (7.0, 8.0, 9.0)).into();
+    ///
+    /// assert_eq!(singular.solve(b), None);
+    /// assert!(Mat3::from(0.001).solve(b).is_some());
+    /// ```
+    pub fn solve(&self, b: Vec3<f32>) -> Option<Vec3<f32>> {
+        let determinant = self.determinant();
+
+        if self.is_singular(determinant) {
+            return None;
+        }
+
+        Some(
+            (self[1].cross(self[2]) * b.x
+                + self[2].cross(self[0]) * b.y
+                + self[0].cross(self[1]) * b.z)
+                / determinant,
+        )
+    }
+
     /// Constructs a 3x3 rotation matrix from a radians value.
     ///
     /// # Examples
@@ -99,6 +200,53 @@ impl Mat3 {
         ((cos, -sin, 0.0), (sin, cos, 0.0), (0.0, 0.0, 1.0)).into()
     }
 
+    /// Constructs a 3x3 matrix representing a 3D rotation from a radians value and an axis
+    /// `Vec3<f32>`. The elements are laid out like the upper-left 3x3 part of `Mat4::rotation`,
+    /// which is also the layout expected by `Quat::from_mat3`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gamemath::{Mat3, Mat4, Quat, Vec3};
+    ///
+    /// let axis = Vec3::new(1.0, 2.0, 3.0);
+    /// let m = Mat3::rotation_3d(1.0, axis);
+    /// let m4 = Mat4::rotation(1.0, axis);
+    ///
+    /// for i in 0..3 {
+    ///     for j in 0..3 {
+    ///         assert_eq!(m[i][j], m4[i][j]);
+    ///     }
+    /// }
+    ///
+    /// assert!(Quat::from_mat3(m).approx_eq(Quat::rotation(1.0, axis), 0.000001));
+    /// ```
+    pub fn rotation_3d(radians: f32, axis: Vec3<f32>) -> Mat3 {
+        let sin = radians.sin();
+        let cos = radians.cos();
+        let cos_m1 = 1.0 - cos;
+        let axis = axis.normalized();
+
+        (
+            (
+                axis.x * axis.x * cos_m1 + cos,
+                axis.x * axis.y * cos_m1 + axis.z * sin,
+                axis.x * axis.z * cos_m1 - axis.y * sin,
+            ),
+            (
+                axis.y * axis.x * cos_m1 - axis.z * sin,
+                axis.y * axis.y * cos_m1 + cos,
+                axis.y * axis.z * cos_m1 + axis.x * sin,
+            ),
+            (
+                axis.z * axis.x * cos_m1 + axis.y * sin,
+                axis.z * axis.y * cos_m1 - axis.x * sin,
+                axis.z * axis.z * cos_m1 + cos,
+            ),
+        )
+            .into()
+    }
+
     /// Calculates and returns a `Mat4` object representing the calling `Mat3` object rotated
     /// by a radians value.
     ///
@@ -136,6 +284,24 @@ impl Mat3 {
         *self = self.rotated(radians);
     }
 
+    /// Constructs a 3x3 scaling matrix from a `Vec2<f32>`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gamemath::{Mat3, Vec2};
+    ///
+    /// let m = Mat3::scaling(Vec2::new(3.0, 6.0));
+    ///
+    /// assert_eq!(m, Mat3::identity().scaled(Vec2::new(3.0, 6.0)));
+    /// assert_eq!(m, ((3.0, 0.0, 0.0),
+    ///                (0.0, 6.0, 0.0),
+    ///                (0.0, 0.0, 1.0)).into());
+    /// ```
+    pub fn scaling(factor: Vec2<f32>) -> Mat3 {
+        ((factor.x, 0.0, 0.0), (0.0, factor.y, 0.0), (0.0, 0.0, 1.0)).into()
+    }
+
     /// Calculates and returns a `Mat3` object representing the calling `Mat3` object scaled
     /// by a `Vec2<f32>`.
     ///
@@ -178,6 +344,29 @@ impl Mat3 {
         *self = self.scaled(factor);
     }
 
+    /// Constructs a 3x3 translation matrix from a `Vec2<f32>`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gamemath::{Mat3, Vec2};
+    ///
+    /// let m = Mat3::translation(Vec2::new(1.0, 2.0));
+    ///
+    /// assert_eq!(m, Mat3::identity().translated(Vec2::new(1.0, 2.0)));
+    /// assert_eq!(m, ((1.0, 0.0, 0.0),
+    ///                (0.0, 1.0, 0.0),
+    ///                (1.0, 2.0, 1.0)).into());
+    /// ```
+    pub fn translation(translation: Vec2<f32>) -> Mat3 {
+        (
+            (1.0, 0.0, 0.0),
+            (0.0, 1.0, 0.0),
+            (translation.x, translation.y, 1.0),
+        )
+            .into()
+    }
+
     /// Calculates and returns a `Mat3` object representing the calling `Mat3` object translated
     /// by a `Vec2<f32>`.
     ///
@@ -223,6 +412,131 @@ impl Mat3 {
     pub fn translate(&mut self, translation: Vec2<f32>) {
         *self = self.translated(translation);
     }
+
+    /// Returns a copy of the row at an index of the calling `Mat3` object, which is the same
+    /// `Vec3<f32>` that indexing the matrix with a single index returns.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gamemath::{Mat3, Vec3};
+    ///
+    /// let m: Mat3 = ((0.0, 1.0, 2.0),
+    ///                (3.0, 4.0, 5.0),
+    ///                (6.0, 7.0, 8.0)).into();
+    ///
+    /// assert_eq!(m.row(1), Vec3::new(3.0, 4.0, 5.0));
+    /// ```
+    pub fn row(&self, index: usize) -> Vec3<f32> {
+        self[index]
+    }
+
+    /// Assembles and returns the column at an index of the calling `Mat3` object.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gamemath::{Mat3, Vec3};
+    ///
+    /// let m: Mat3 = ((0.0, 1.0, 2.0),
+    ///                (3.0, 4.0, 5.0),
+    ///                (6.0, 7.0, 8.0)).into();
+    ///
+    /// assert_eq!(m.col(0), Vec3::new(0.0, 3.0, 6.0));
+    /// ```
+    pub fn col(&self, index: usize) -> Vec3<f32> {
+        (self[0][index], self[1][index], self[2][index]).into()
+    }
+
+    /// Constructs a `Mat3` from three `Vec3<f32>` used as its columns, as opposed to converting
+    /// from a tuple of vectors, which uses them as rows.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gamemath::{Mat3, Vec3};
+    ///
+    /// let a = Vec3::new(1.0, 2.0, 3.0);
+    /// let b = Vec3::new(4.0, 5.0, 6.0);
+    /// let c = Vec3::new(7.0, 8.0, 9.0);
+    /// let m = Mat3::from_columns(a, b, c);
+    ///
+    /// assert_eq!(m, Mat3::from((a, b, c)).transposed());
+    /// assert_eq!(m[0], Vec3::new(a.x, b.x, c.x));
+    /// ```
+    pub fn from_columns(x: Vec3<f32>, y: Vec3<f32>, z: Vec3<f32>) -> Mat3 {
+        ((x.x, y.x, z.x), (x.y, y.y, z.y), (x.z, y.z, z.z)).into()
+    }
+
+    /// Returns a reference to the row at an index of the calling `Mat3` object, or `None` if the
+    /// index is out of range, instead of panicking like indexing does.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gamemath::Mat3;
+    ///
+    /// let m = Mat3::identity();
+    ///
+    /// assert_eq!(m.get(0), Some(&m[0]));
+    /// assert_eq!(m.get(3), None);
+    /// ```
+    pub fn get(&self, index: usize) -> Option<&Vec3<f32>> {
+        self.rows.get(index)
+    }
+
+    /// Returns an iterator over references to the elements of the calling `Mat3` object, going
+    /// through them row by row.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gamemath::Mat3;
+    ///
+    /// let elements = [1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0];
+    /// let m = Mat3::from(elements);
+    ///
+    /// assert_eq!(m.iter().count(), 9);
+    /// assert_eq!(m.iter().cloned().collect::<Vec<f32>>(), elements.to_vec());
+    /// ```
+    pub fn iter(&self) -> impl Iterator<Item = &f32> {
+        self.rows.iter().flat_map(|row| {
+            core::iter::once(&row.x)
+                .chain(core::iter::once(&row.y))
+                .chain(core::iter::once(&row.z))
+        })
+    }
+
+    /// Returns an iterator over mutable references to the elements of the calling `Mat3`
+    /// object, going through them row by row.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gamemath::Mat3;
+    ///
+    /// let mut m = Mat3::identity();
+    ///
+    /// for element in m.iter_mut() {
+    ///     *element += 1.0;
+    /// }
+    ///
+    /// assert_eq!(m[0][0], 2.0);
+    /// assert_eq!(m[1][0], 1.0);
+    /// ```
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = &mut f32> {
+        self.rows.iter_mut().flat_map(|row| {
+            let Vec3 {
+                ref mut x,
+                ref mut y,
+                ref mut z,
+            } = *row;
+
+            core::iter::once(x)
+                .chain(core::iter::once(y))
+                .chain(core::iter::once(z))
+        })
+    }
 }
 
 impl Default for Mat3 {
@@ -283,6 +597,31 @@ impl From<[f32; 9]> for Mat3 {
     }
 }
 
+/// Converts a slice of elements in row-major order into a `Mat3`, failing if the length of the
+/// slice is not exactly 9.
+///
+/// # Examples
+///
+/// ```
+/// use gamemath::Mat3;
+/// use std::convert::TryFrom;
+///
+/// let elements: Vec<f32> = (0..10).map(|i| i as f32).collect();
+/// let m = Mat3::try_from(&elements[..9]).unwrap();
+///
+/// assert_eq!(m[0][1], 1.0);
+/// assert_eq!(m[1][0], 3.0);
+/// assert!(Mat3::try_from(&elements[..8]).is_err());
+/// assert!(Mat3::try_from(&elements[..]).is_err());
+/// ```
+impl<'a> core::convert::TryFrom<&'a [f32]> for Mat3 {
+    type Error = core::array::TryFromSliceError;
+
+    fn try_from(slice: &'a [f32]) -> Result<Mat3, core::array::TryFromSliceError> {
+        <[f32; 9] as core::convert::TryFrom<&[f32]>>::try_from(slice).map(Mat3::from)
+    }
+}
+
 impl From<[Vec3<f32>; 3]> for Mat3 {
     fn from(slice: [Vec3<f32>; 3]) -> Mat3 {
         Mat3 {
@@ -299,7 +638,7 @@ impl From<(Vec3<f32>, Vec3<f32>, Vec3<f32>)> for Mat3 {
     }
 }
 
-impl std::ops::Index<usize> for Mat3 {
+impl core::ops::Index<usize> for Mat3 {
     type Output = Vec3<f32>;
 
     fn index(&self, index: usize) -> &Vec3<f32> {
@@ -312,7 +651,7 @@ impl std::ops::Index<usize> for Mat3 {
     }
 }
 
-impl std::ops::IndexMut<usize> for Mat3 {
+impl core::ops::IndexMut<usize> for Mat3 {
     fn index_mut(&mut self, index: usize) -> &mut Vec3<f32> {
         match index {
             0 => &mut self.rows[0],
@@ -323,7 +662,7 @@ impl std::ops::IndexMut<usize> for Mat3 {
     }
 }
 
-impl std::ops::Index<(usize, usize)> for Mat3 {
+impl core::ops::Index<(usize, usize)> for Mat3 {
     type Output = f32;
 
     fn index(&self, index: (usize, usize)) -> &f32 {
@@ -331,13 +670,13 @@ impl std::ops::Index<(usize, usize)> for Mat3 {
     }
 }
 
-impl std::ops::IndexMut<(usize, usize)> for Mat3 {
+impl core::ops::IndexMut<(usize, usize)> for Mat3 {
     fn index_mut(&mut self, index: (usize, usize)) -> &mut f32 {
         &mut self.rows[index.0][index.1]
     }
 }
 
-impl std::ops::Add for Mat3 {
+impl core::ops::Add for Mat3 {
     type Output = Mat3;
 
     fn add(self, right: Mat3) -> Mat3 {
@@ -347,13 +686,13 @@ impl std::ops::Add for Mat3 {
     }
 }
 
-impl std::ops::AddAssign for Mat3 {
+impl core::ops::AddAssign for Mat3 {
     fn add_assign(&mut self, right: Mat3) {
         *self = *self + right;
     }
 }
 
-impl std::ops::Sub for Mat3 {
+impl core::ops::Sub for Mat3 {
     type Output = Mat3;
 
     fn sub(self, right: Mat3) -> Mat3 {
@@ -363,13 +702,59 @@ impl std::ops::Sub for Mat3 {
     }
 }
 
-impl std::ops::SubAssign for Mat3 {
+impl core::ops::SubAssign for Mat3 {
     fn sub_assign(&mut self, right: Mat3) {
         *self = *self - right;
     }
 }
 
-impl std::ops::Mul<Vec3<f32>> for Mat3 {
+/// Negates every element of a `Mat3`.
+///
+/// # Examples
+///
+/// ```
+/// use gamemath::Mat3;
+///
+/// let m = -Mat3::identity();
+///
+/// assert_eq!(m[0][0], -1.0);
+/// assert_eq!(m[2][2], -1.0);
+/// assert_eq!(m + Mat3::identity(), Mat3::from(0.0));
+/// ```
+impl core::ops::Neg for Mat3 {
+    type Output = Mat3;
+
+    fn neg(self) -> Mat3 {
+        Mat3 {
+            rows: [-self[0], -self[1], -self[2]],
+        }
+    }
+}
+
+/// Divides every element of a `Mat3` by a scalar value. Dividing by zero is not guarded against,
+/// and follows the IEEE 754 rules, producing infinite or NaN elements.
+///
+/// # Examples
+///
+/// ```
+/// use gamemath::Mat3;
+///
+/// let m = Mat3::from([2.0; 9]) / 2.0;
+///
+/// assert_eq!(m, Mat3::from([1.0; 9]));
+/// assert_eq!(Mat3::identity() / 4.0, Mat3::from(0.25));
+/// ```
+impl core::ops::Div<f32> for Mat3 {
+    type Output = Mat3;
+
+    fn div(self, right: f32) -> Mat3 {
+        Mat3 {
+            rows: [self[0] / right, self[1] / right, self[2] / right],
+        }
+    }
+}
+
+impl core::ops::Mul<Vec3<f32>> for Mat3 {
     type Output = Vec3<f32>;
 
     fn mul(self, vec: Vec3<f32>) -> Vec3<f32> {
@@ -377,7 +762,7 @@ impl std::ops::Mul<Vec3<f32>> for Mat3 {
     }
 }
 
-impl std::ops::Mul<Mat3> for Mat3 {
+impl core::ops::Mul<Mat3> for Mat3 {
     type Output = Mat3;
 
     fn mul(self, right: Mat3) -> Mat3 {
@@ -408,7 +793,7 @@ impl std::ops::Mul<Mat3> for Mat3 {
     }
 }
 
-impl std::ops::MulAssign<Mat3> for Mat3 {
+impl core::ops::MulAssign<Mat3> for Mat3 {
     fn mul_assign(&mut self, right: Mat3) {
         *self = *self * right;
     }
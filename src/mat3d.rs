@@ -0,0 +1,800 @@
+use core;
+#[cfg(all(not(feature = "std"), not(test)))]
+use math::Float;
+use vec2::Vec2;
+use vec3::Vec3;
+
+type Row = (f64, f64, f64);
+type InlineMat3 = (f64, f64, f64, f64, f64, f64, f64, f64, f64);
+
+/// A 3x3-component Euclidean matrix of `f64` components, mirroring `Mat3` for computations that
+/// need double precision.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Mat3d {
+    /// The three rows of the matrix, represented by an array of three `Vec3<f64>` objects.
+    pub rows: [Vec3<f64>; 3],
+}
+
+impl Mat3d {
+    /// Constructs a 3x3 identity matrix.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gamemath::{Mat3d, Vec3};
+    ///
+    /// let m = Mat3d::identity();
+    ///
+    /// assert_eq!(m[0], Vec3::new(1.0, 0.0, 0.0));
+    /// assert_eq!(m[1], Vec3::new(0.0, 1.0, 0.0));
+    /// assert_eq!(m[2], Vec3::new(0.0, 0.0, 1.0));
+    /// ```
+    pub fn identity() -> Mat3d {
+        Self::default()
+    }
+
+    /// Extracts and returns a transposed representation of the calling `Mat3d` object.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gamemath::Mat3d;
+    ///
+    /// let m: Mat3d = ((0.0, 1.0, 2.0),
+    ///                 (3.0, 4.0, 5.0),
+    ///                 (6.0, 7.0, 8.0)).into();
+    ///
+    /// assert_eq!(m.transposed(), ((0.0, 3.0, 6.0),
+    ///                             (1.0, 4.0, 7.0),
+    ///                             (2.0, 5.0, 8.0)).into());
+    /// ```
+    pub fn transposed(&self) -> Mat3d {
+        (
+            (self[0][0], self[1][0], self[2][0]),
+            (self[0][1], self[1][1], self[2][1]),
+            (self[0][2], self[1][2], self[2][2]),
+        )
+            .into()
+    }
+
+    /// Performs a transpose operation on the calling `Mat3d` object.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gamemath::Mat3d;
+    ///
+    /// let mut m: Mat3d = ((0.0, 1.0, 2.0),
+    ///                     (3.0, 4.0, 5.0),
+    ///                     (6.0, 7.0, 8.0)).into();
+    ///
+    /// m.transpose();
+    ///
+    /// assert_eq!(m, ((0.0, 3.0, 6.0),
+    ///                (1.0, 4.0, 7.0),
+    ///                (2.0, 5.0, 8.0)).into());
+    /// ```
+    pub fn transpose(&mut self) {
+        *self = self.transposed();
+    }
+
+    /// Calculates and returns the determinant of the calling `Mat3d` object.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gamemath::Mat3d;
+    ///
+    /// let m: Mat3d = ((1.0, 2.0, 3.0),
+    ///                (0.0, 1.0, 4.0),
+    ///                (5.0, 6.0, 0.0)).into();
+    ///
+    /// assert_eq!(m.determinant(), 1.0);
+    /// ```
+    pub fn determinant(&self) -> f64 {
+        self[0].dot(self[1].cross(self[2]))
+    }
+
+    /// Calculates and returns the inverted matrix of the calling `Mat3d` object, or `None` if the
+    /// matrix is singular. A matrix counts as singular when its determinant is zero, not finite, or
+    /// negligible next to the product of its row lengths, so uniformly scaled matrices invert
+    /// no matter how small the scale is.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gamemath::{Approx, Mat3d};
+    ///
+    /// let m: Mat3d = ((1.0, 2.0, 3.0),
+    ///                (0.0, 1.0, 4.0),
+    ///                (5.0, 6.0, 0.0)).into();
+    /// let singular: Mat3d = ((1.0, 2.0, 3.0),
+    ///                       (4.0, 5.0, 6.0),
+    ///                       (7.0, 8.0, 9.0)).into();
+    ///
+    /// assert_eq!(m.try_inverse().map(|inverse| m * inverse), Some(Mat3d::identity()));
+    /// assert_eq!(singular.try_inverse(), None);
+    ///
+    /// let small = Mat3d::from(0.001_f64);
+    /// let small_inverse = small.try_inverse().unwrap();
+    ///
+    /// assert_eq!(Approx(small * small_inverse, 1e-5), Approx(Mat3d::identity(), 1e-5));
+    /// ```
+    pub fn try_inverse(&self) -> Option<Mat3d> {
+        let determinant = self.determinant();
+
+        if self.is_singular(determinant) {
+            return None;
+        }
+
+        Some(Mat3d::from_columns(
+            self[1].cross(self[2]) / determinant,
+            self[2].cross(self[0]) / determinant,
+            self[0].cross(self[1]) / determinant,
+        ))
+    }
+
+    fn is_singular(&self, determinant: f64) -> bool {
+        let row_lengths = self[0].length() * self[1].length() * self[2].length();
+
+        !determinant.is_finite() || determinant.abs() <= f64::EPSILON * row_lengths
+    }
+
+    /// Solves the linear system `self * x = b` for `x`, or returns `None` if the calling `Mat3d`
+    /// object is singular by the same test as `try_inverse`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gamemath::{Mat3d, Vec3};
+    ///
+    /// let m: Mat3d = ((2.0, 1.0, -1.0),
+    ///                (-3.0, -1.0, 2.0),
+    ///                (-2.0, 1.0, 2.0)).into();
+    /// let b = Vec3::new(8.0, -11.0, -3.0);
+    /// let x = m.solve(b).unwrap();
+    ///
+    /// assert!((x - Vec3::new(2.0, 3.0, -1.0)).length() < 0.00001);
+    /// assert!((m * x - b).length() < 0.00001);
+    /// assert!((x - m.try_inverse().unwrap() * b).length() < 0.00001);
+    ///
+    /// let singular: Mat3d = ((1.0, 2.0, 3.0),
+    ///                        (4.0, 5.0, 6.0),
+    ///                        (7.0, 8.0, 9.0)).into();
+    ///
+    /// assert_eq!(singular.solve(b), None);
+    /// assert!(Mat3d::from(0.001).solve(b).is_some());
+    /// ```
+    pub fn solve(&self, b: Vec3<f64>) -> Option<Vec3<f64>> {
+        let determinant = self.determinant();
+
+        if self.is_singular(determinant) {
+            return None;
+        }
+
+        Some(
+            (self[1].cross(self[2]) * b.x
+                + self[2].cross(self[0]) * b.y
+                + self[0].cross(self[1]) * b.z)
+                / determinant,
+        )
+    }
+
+    /// Constructs a 3x3 rotation matrix from a radians value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gamemath::{Mat3d, Vec3};
+    ///
+    /// let m = Mat3d::rotation(1.0);
+    ///
+    /// assert_eq!(m[0], Vec3::new(0.5403023058681398,  -0.8414709848078965, 0.0));
+    /// assert_eq!(m[1], Vec3::new(0.8414709848078965,  0.5403023058681398,  0.0));
+    /// assert_eq!(m[2], Vec3::new(0.0,                 0.0,                 1.0));
+    /// ```
+    pub fn rotation(radians: f64) -> Mat3d {
+        let sin = radians.sin();
+        let cos = radians.cos();
+
+        ((cos, -sin, 0.0), (sin, cos, 0.0), (0.0, 0.0, 1.0)).into()
+    }
+
+    /// Constructs a 3x3 matrix representing a 3D rotation from a radians value and an axis
+    /// `Vec3<f64>`. The elements are laid out like the upper-left 3x3 part of `Mat4d::rotation`,
+    /// which is also the layout expected by `Quatd::from_mat3`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gamemath::{Mat3d, Mat4d, Quatd, Vec3};
+    ///
+    /// let axis = Vec3::new(1.0, 2.0, 3.0);
+    /// let m = Mat3d::rotation_3d(1.0, axis);
+    /// let m4 = Mat4d::rotation(1.0, axis);
+    ///
+    /// for i in 0..3 {
+    ///     for j in 0..3 {
+    ///         assert_eq!(m[i][j], m4[i][j]);
+    ///     }
+    /// }
+    ///
+    /// assert!(Quatd::from_mat3(m).approx_eq(Quatd::rotation(1.0, axis), 0.000001));
+    /// ```
+    pub fn rotation_3d(radians: f64, axis: Vec3<f64>) -> Mat3d {
+        let sin = radians.sin();
+        let cos = radians.cos();
+        let cos_m1 = 1.0 - cos;
+        let axis = axis.normalized();
+
+        (
+            (
+                axis.x * axis.x * cos_m1 + cos,
+                axis.x * axis.y * cos_m1 + axis.z * sin,
+                axis.x * axis.z * cos_m1 - axis.y * sin,
+            ),
+            (
+                axis.y * axis.x * cos_m1 - axis.z * sin,
+                axis.y * axis.y * cos_m1 + cos,
+                axis.y * axis.z * cos_m1 + axis.x * sin,
+            ),
+            (
+                axis.z * axis.x * cos_m1 + axis.y * sin,
+                axis.z * axis.y * cos_m1 - axis.x * sin,
+                axis.z * axis.z * cos_m1 + cos,
+            ),
+        )
+            .into()
+    }
+
+    /// Calculates and returns a `Mat4d` object representing the calling `Mat3d` object rotated
+    /// by a radians value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gamemath::Mat3d;
+    ///
+    /// let m = Mat3d::identity().rotated(1.0);
+    ///
+    /// assert_eq!(m, ((0.5403023058681398,  -0.8414709848078965, 0.0),
+    ///                (0.8414709848078965,  0.5403023058681398,  0.0),
+    ///                (0.0,                 0.0,                 1.0)).into());
+    /// ```
+    pub fn rotated(&self, radians: f64) -> Mat3d {
+        *self * Mat3d::rotation(radians)
+    }
+
+    /// Rotates the calling `Mat3d` object by a radians value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gamemath::Mat3d;
+    ///
+    /// let mut m = Mat3d::identity();
+    ///
+    /// m.rotate(1.0);
+    ///
+    /// assert_eq!(m, ((0.5403023058681398,  -0.8414709848078965, 0.0),
+    ///                (0.8414709848078965,  0.5403023058681398,  0.0),
+    ///                (0.0,                 0.0,                 1.0)).into());
+    /// ```
+    pub fn rotate(&mut self, radians: f64) {
+        *self = self.rotated(radians);
+    }
+
+    /// Constructs a 3x3 scaling matrix from a `Vec2<f64>`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gamemath::{Mat3d, Vec2};
+    ///
+    /// let m = Mat3d::scaling(Vec2::new(3.0, 6.0));
+    ///
+    /// assert_eq!(m, Mat3d::identity().scaled(Vec2::new(3.0, 6.0)));
+    /// assert_eq!(m, ((3.0, 0.0, 0.0),
+    ///                (0.0, 6.0, 0.0),
+    ///                (0.0, 0.0, 1.0)).into());
+    /// ```
+    pub fn scaling(factor: Vec2<f64>) -> Mat3d {
+        ((factor.x, 0.0, 0.0), (0.0, factor.y, 0.0), (0.0, 0.0, 1.0)).into()
+    }
+
+    /// Calculates and returns a `Mat3d` object representing the calling `Mat3d` object scaled
+    /// by a `Vec2<f64>`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gamemath::{Mat3d, Vec2};
+    ///
+    /// let m = Mat3d::identity();
+    ///
+    /// assert_eq!(m.scaled(Vec2::new(1.0, 2.0)), ((1.0, 0.0, 0.0),
+    ///                                            (0.0, 2.0, 0.0),
+    ///                                            (0.0, 0.0, 1.0)).into());
+    /// ```
+    pub fn scaled(&self, factor: Vec2<f64>) -> Mat3d {
+        let mut matrix = *self;
+
+        matrix[0] *= factor.x;
+        matrix[1] *= factor.y;
+
+        matrix
+    }
+
+    /// Performs the scale operation on the calling `Mat3d` object, scaling it by a `Vec2<f64>`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gamemath::{Mat3d, Vec2};
+    ///
+    /// let mut m = Mat3d::identity();
+    ///
+    /// m.scale(Vec2::new(1.0, 2.0));
+    ///
+    /// assert_eq!(m, ((1.0, 0.0, 0.0),
+    ///                (0.0, 2.0, 0.0),
+    ///                (0.0, 0.0, 1.0)).into());
+    /// ```
+    pub fn scale(&mut self, factor: Vec2<f64>) {
+        *self = self.scaled(factor);
+    }
+
+    /// Constructs a 3x3 translation matrix from a `Vec2<f64>`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gamemath::{Mat3d, Vec2};
+    ///
+    /// let m = Mat3d::translation(Vec2::new(1.0, 2.0));
+    ///
+    /// assert_eq!(m, Mat3d::identity().translated(Vec2::new(1.0, 2.0)));
+    /// assert_eq!(m, ((1.0, 0.0, 0.0),
+    ///                (0.0, 1.0, 0.0),
+    ///                (1.0, 2.0, 1.0)).into());
+    /// ```
+    pub fn translation(translation: Vec2<f64>) -> Mat3d {
+        (
+            (1.0, 0.0, 0.0),
+            (0.0, 1.0, 0.0),
+            (translation.x, translation.y, 1.0),
+        )
+            .into()
+    }
+
+    /// Calculates and returns a `Mat3d` object representing the calling `Mat3d` object translated
+    /// by a `Vec2<f64>`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gamemath::{Mat3d, Vec2};
+    ///
+    /// let m = Mat3d::identity();
+    ///
+    /// assert_eq!(m.translated(Vec2::new(1.0, 2.0)), ((1.0, 0.0, 0.0),
+    ///                                                (0.0, 1.0, 0.0),
+    ///                                                (1.0, 2.0, 1.0)).into());
+    /// ```
+    pub fn translated(&self, translation: Vec2<f64>) -> Mat3d {
+        let mut result = *self;
+
+        result[2][0] += self[0][0] * translation.x + self[1][0] * translation.y;
+
+        result[2][1] += self[0][1] * translation.x + self[1][1] * translation.y;
+
+        result[2][2] += self[0][2] * translation.x + self[1][2] * translation.y;
+
+        result
+    }
+
+    /// Performs the translate operation on the calling `Mat3d` object, translating it by a
+    /// `Vec2<f64>`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gamemath::{Mat3d, Vec2};
+    ///
+    /// let mut m = Mat3d::identity();
+    ///
+    /// m.translate(Vec2::new(1.0, 2.0));
+    ///
+    /// assert_eq!(m, ((1.0, 0.0, 0.0),
+    ///                (0.0, 1.0, 0.0),
+    ///                (1.0, 2.0, 1.0)).into());
+    /// ```
+    pub fn translate(&mut self, translation: Vec2<f64>) {
+        *self = self.translated(translation);
+    }
+
+    /// Returns a copy of the row at an index of the calling `Mat3d` object, which is the same
+    /// `Vec3<f64>` that indexing the matrix with a single index returns.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gamemath::{Mat3d, Vec3};
+    ///
+    /// let m: Mat3d = ((0.0, 1.0, 2.0),
+    ///                 (3.0, 4.0, 5.0),
+    ///                 (6.0, 7.0, 8.0)).into();
+    ///
+    /// assert_eq!(m.row(1), Vec3::new(3.0, 4.0, 5.0));
+    /// ```
+    pub fn row(&self, index: usize) -> Vec3<f64> {
+        self[index]
+    }
+
+    /// Assembles and returns the column at an index of the calling `Mat3d` object.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gamemath::{Mat3d, Vec3};
+    ///
+    /// let m: Mat3d = ((0.0, 1.0, 2.0),
+    ///                 (3.0, 4.0, 5.0),
+    ///                 (6.0, 7.0, 8.0)).into();
+    ///
+    /// assert_eq!(m.col(0), Vec3::new(0.0, 3.0, 6.0));
+    /// ```
+    pub fn col(&self, index: usize) -> Vec3<f64> {
+        (self[0][index], self[1][index], self[2][index]).into()
+    }
+
+    /// Constructs a `Mat3d` from three `Vec3<f64>` used as its columns, as opposed to converting
+    /// from a tuple of vectors, which uses them as rows.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gamemath::{Mat3d, Vec3};
+    ///
+    /// let a = Vec3::new(1.0, 2.0, 3.0);
+    /// let b = Vec3::new(4.0, 5.0, 6.0);
+    /// let c = Vec3::new(7.0, 8.0, 9.0);
+    /// let m = Mat3d::from_columns(a, b, c);
+    ///
+    /// assert_eq!(m, Mat3d::from((a, b, c)).transposed());
+    /// assert_eq!(m[0], Vec3::new(a.x, b.x, c.x));
+    /// ```
+    pub fn from_columns(x: Vec3<f64>, y: Vec3<f64>, z: Vec3<f64>) -> Mat3d {
+        ((x.x, y.x, z.x), (x.y, y.y, z.y), (x.z, y.z, z.z)).into()
+    }
+
+    /// Returns a reference to the row at an index of the calling `Mat3d` object, or `None` if
+    /// the index is out of range, instead of panicking like indexing does.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gamemath::Mat3d;
+    ///
+    /// let m = Mat3d::identity();
+    ///
+    /// assert_eq!(m.get(0), Some(&m[0]));
+    /// assert_eq!(m.get(3), None);
+    /// ```
+    pub fn get(&self, index: usize) -> Option<&Vec3<f64>> {
+        self.rows.get(index)
+    }
+
+    /// Returns an iterator over references to the elements of the calling `Mat3d` object, going
+    /// through them row by row.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gamemath::Mat3d;
+    ///
+    /// let elements = [1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0];
+    /// let m = Mat3d::from(elements);
+    ///
+    /// assert_eq!(m.iter().count(), 9);
+    /// assert_eq!(m.iter().cloned().collect::<Vec<f64>>(), elements.to_vec());
+    /// ```
+    pub fn iter(&self) -> impl Iterator<Item = &f64> {
+        self.rows.iter().flat_map(|row| {
+            core::iter::once(&row.x)
+                .chain(core::iter::once(&row.y))
+                .chain(core::iter::once(&row.z))
+        })
+    }
+
+    /// Returns an iterator over mutable references to the elements of the calling `Mat3d`
+    /// object, going through them row by row.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gamemath::Mat3d;
+    ///
+    /// let mut m = Mat3d::identity();
+    ///
+    /// for element in m.iter_mut() {
+    ///     *element += 1.0;
+    /// }
+    ///
+    /// assert_eq!(m[0][0], 2.0);
+    /// assert_eq!(m[1][0], 1.0);
+    /// ```
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = &mut f64> {
+        self.rows.iter_mut().flat_map(|row| {
+            let Vec3 {
+                ref mut x,
+                ref mut y,
+                ref mut z,
+            } = *row;
+
+            core::iter::once(x)
+                .chain(core::iter::once(y))
+                .chain(core::iter::once(z))
+        })
+    }
+}
+
+impl Default for Mat3d {
+    fn default() -> Mat3d {
+        ((1.0, 0.0, 0.0), (0.0, 1.0, 0.0), (0.0, 0.0, 1.0)).into()
+    }
+}
+
+impl From<f64> for Mat3d {
+    fn from(value: f64) -> Mat3d {
+        Mat3d {
+            rows: [
+                (value, 0.0, 0.0).into(),
+                (0.0, value, 0.0).into(),
+                (0.0, 0.0, value).into(),
+            ],
+        }
+    }
+}
+
+impl From<(Row, Row, Row)> for Mat3d {
+    fn from(tuple: (Row, Row, Row)) -> Mat3d {
+        Mat3d {
+            rows: [tuple.0.into(), tuple.1.into(), tuple.2.into()],
+        }
+    }
+}
+
+impl From<InlineMat3> for Mat3d {
+    fn from(tuple: InlineMat3) -> Mat3d {
+        Mat3d {
+            rows: [
+                (tuple.0, tuple.1, tuple.2).into(),
+                (tuple.3, tuple.4, tuple.5).into(),
+                (tuple.6, tuple.7, tuple.8).into(),
+            ],
+        }
+    }
+}
+
+impl From<[[f64; 3]; 3]> for Mat3d {
+    fn from(slice: [[f64; 3]; 3]) -> Mat3d {
+        Mat3d {
+            rows: [slice[0].into(), slice[1].into(), slice[2].into()],
+        }
+    }
+}
+
+impl From<[f64; 9]> for Mat3d {
+    fn from(slice: [f64; 9]) -> Mat3d {
+        Mat3d {
+            rows: [
+                (slice[0], slice[1], slice[2]).into(),
+                (slice[3], slice[4], slice[5]).into(),
+                (slice[6], slice[7], slice[8]).into(),
+            ],
+        }
+    }
+}
+
+/// Converts a slice of elements in row-major order into a `Mat3d`, failing if the length of the
+/// slice is not exactly 9.
+///
+/// # Examples
+///
+/// ```
+/// use gamemath::Mat3d;
+/// use std::convert::TryFrom;
+///
+/// let elements: Vec<f64> = (0..10).map(|i| i as f64).collect();
+/// let m = Mat3d::try_from(&elements[..9]).unwrap();
+///
+/// assert_eq!(m[0][1], 1.0);
+/// assert_eq!(m[1][0], 3.0);
+/// assert!(Mat3d::try_from(&elements[..8]).is_err());
+/// assert!(Mat3d::try_from(&elements[..]).is_err());
+/// ```
+impl<'a> core::convert::TryFrom<&'a [f64]> for Mat3d {
+    type Error = core::array::TryFromSliceError;
+
+    fn try_from(slice: &'a [f64]) -> Result<Mat3d, core::array::TryFromSliceError> {
+        <[f64; 9] as core::convert::TryFrom<&[f64]>>::try_from(slice).map(Mat3d::from)
+    }
+}
+
+impl From<[Vec3<f64>; 3]> for Mat3d {
+    fn from(slice: [Vec3<f64>; 3]) -> Mat3d {
+        Mat3d {
+            rows: [slice[0], slice[1], slice[2]],
+        }
+    }
+}
+
+impl From<(Vec3<f64>, Vec3<f64>, Vec3<f64>)> for Mat3d {
+    fn from(tuple: (Vec3<f64>, Vec3<f64>, Vec3<f64>)) -> Mat3d {
+        Mat3d {
+            rows: [tuple.0, tuple.1, tuple.2],
+        }
+    }
+}
+
+impl core::ops::Index<usize> for Mat3d {
+    type Output = Vec3<f64>;
+
+    fn index(&self, index: usize) -> &Vec3<f64> {
+        match index {
+            0 => &self.rows[0],
+            1 => &self.rows[1],
+            2 => &self.rows[2],
+            _ => panic!("Mat3d index out of range!"),
+        }
+    }
+}
+
+impl core::ops::IndexMut<usize> for Mat3d {
+    fn index_mut(&mut self, index: usize) -> &mut Vec3<f64> {
+        match index {
+            0 => &mut self.rows[0],
+            1 => &mut self.rows[1],
+            2 => &mut self.rows[2],
+            _ => panic!("Mat3d index out of range!"),
+        }
+    }
+}
+
+impl core::ops::Index<(usize, usize)> for Mat3d {
+    type Output = f64;
+
+    fn index(&self, index: (usize, usize)) -> &f64 {
+        &self.rows[index.0][index.1]
+    }
+}
+
+impl core::ops::IndexMut<(usize, usize)> for Mat3d {
+    fn index_mut(&mut self, index: (usize, usize)) -> &mut f64 {
+        &mut self.rows[index.0][index.1]
+    }
+}
+
+impl core::ops::Add for Mat3d {
+    type Output = Mat3d;
+
+    fn add(self, right: Mat3d) -> Mat3d {
+        Mat3d {
+            rows: [self[0] + right[0], self[1] + right[1], self[2] + right[2]],
+        }
+    }
+}
+
+impl core::ops::AddAssign for Mat3d {
+    fn add_assign(&mut self, right: Mat3d) {
+        *self = *self + right;
+    }
+}
+
+impl core::ops::Sub for Mat3d {
+    type Output = Mat3d;
+
+    fn sub(self, right: Mat3d) -> Mat3d {
+        Mat3d {
+            rows: [self[0] - right[0], self[1] - right[1], self[2] - right[2]],
+        }
+    }
+}
+
+impl core::ops::SubAssign for Mat3d {
+    fn sub_assign(&mut self, right: Mat3d) {
+        *self = *self - right;
+    }
+}
+
+/// Negates every element of a `Mat3d`.
+///
+/// # Examples
+///
+/// ```
+/// use gamemath::Mat3d;
+///
+/// let m = -Mat3d::identity();
+///
+/// assert_eq!(m[0][0], -1.0);
+/// assert_eq!(m[2][2], -1.0);
+/// assert_eq!(m + Mat3d::identity(), Mat3d::from(0.0));
+/// ```
+impl core::ops::Neg for Mat3d {
+    type Output = Mat3d;
+
+    fn neg(self) -> Mat3d {
+        Mat3d {
+            rows: [-self[0], -self[1], -self[2]],
+        }
+    }
+}
+
+/// Divides every element of a `Mat3d` by a scalar value. Dividing by zero is not guarded against,
+/// and follows the IEEE 754 rules, producing infinite or NaN elements.
+///
+/// # Examples
+///
+/// ```
+/// use gamemath::Mat3d;
+///
+/// let m = Mat3d::from([2.0; 9]) / 2.0;
+///
+/// assert_eq!(m, Mat3d::from([1.0; 9]));
+/// assert_eq!(Mat3d::identity() / 4.0, Mat3d::from(0.25));
+/// ```
+impl core::ops::Div<f64> for Mat3d {
+    type Output = Mat3d;
+
+    fn div(self, right: f64) -> Mat3d {
+        Mat3d {
+            rows: [self[0] / right, self[1] / right, self[2] / right],
+        }
+    }
+}
+
+impl core::ops::Mul<Vec3<f64>> for Mat3d {
+    type Output = Vec3<f64>;
+
+    fn mul(self, vec: Vec3<f64>) -> Vec3<f64> {
+        (self[0].dot(vec), self[1].dot(vec), self[2].dot(vec)).into()
+    }
+}
+
+impl core::ops::Mul<Mat3d> for Mat3d {
+    type Output = Mat3d;
+
+    fn mul(self, right: Mat3d) -> Mat3d {
+        let mut result: Mat3d = 0.0.into();
+
+        result[0][0] =
+            self[0][0] * right[0][0] + self[1][0] * right[0][1] + self[2][0] * right[0][2];
+        result[0][1] =
+            self[0][1] * right[0][0] + self[1][1] * right[0][1] + self[2][1] * right[0][2];
+        result[0][2] =
+            self[0][2] * right[0][0] + self[1][2] * right[0][1] + self[2][2] * right[0][2];
+
+        result[1][0] =
+            self[0][0] * right[1][0] + self[1][0] * right[1][1] + self[2][0] * right[1][2];
+        result[1][1] =
+            self[0][1] * right[1][0] + self[1][1] * right[1][1] + self[2][1] * right[1][2];
+        result[1][2] =
+            self[0][2] * right[1][0] + self[1][2] * right[1][1] + self[2][2] * right[1][2];
+
+        result[2][0] =
+            self[0][0] * right[2][0] + self[1][0] * right[2][1] + self[2][0] * right[2][2];
+        result[2][1] =
+            self[0][1] * right[2][0] + self[1][1] * right[2][1] + self[2][1] * right[2][2];
+        result[2][2] =
+            self[0][2] * right[2][0] + self[1][2] * right[2][1] + self[2][2] * right[2][2];
+
+        result
+    }
+}
+
+impl core::ops::MulAssign<Mat3d> for Mat3d {
+    fn mul_assign(&mut self, right: Mat3d) {
+        *self = *self * right;
+    }
+}
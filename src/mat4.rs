@@ -1,12 +1,14 @@
+use core;
+use core::f32::consts::PI;
+use mat3::Mat3;
+#[cfg(all(not(feature = "std"), not(test)))]
+use math::Float;
+use plane::Plane;
+use projection_error::ProjectionError;
 use quat::Quat;
-use std;
-use std::f32::consts::PI;
 use vec3::Vec3;
 use vec4::Vec4;
 
-// TODO: Consider making Mat4 of a generic type instead of forcing f32.
-//       But would any type other than f64 ever be useful?
-
 type Row = (f32, f32, f32, f32);
 type InlineMat4 = (
     f32,
@@ -87,6 +89,65 @@ impl Mat4 {
         result
     }
 
+    /// Constructs a 4x4 frustum matrix from a top, left, right, bottom, near and far value like
+    /// `frustum`, but returns an error instead of a matrix of infinite or NaN elements when the
+    /// parameters describe a degenerate frustum or any of them is NaN.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gamemath::{Mat4, ProjectionError};
+    ///
+    /// let m = Mat4::try_frustum(-10.0, -10.0, 10.0, 10.0, 0.1, 100.0);
+    ///
+    /// assert_eq!(m, Ok(Mat4::frustum(-10.0, -10.0, 10.0, 10.0, 0.1, 100.0)));
+    ///
+    /// assert_eq!(
+    ///     Mat4::try_frustum(-10.0, 5.0, 5.0, 10.0, 0.1, 100.0),
+    ///     Err(ProjectionError::ZeroWidth)
+    /// );
+    /// assert_eq!(
+    ///     Mat4::try_frustum(10.0, -10.0, 10.0, 10.0, 0.1, 100.0),
+    ///     Err(ProjectionError::ZeroHeight)
+    /// );
+    /// assert_eq!(
+    ///     Mat4::try_frustum(-10.0, f32::NAN, 10.0, 10.0, 0.1, 100.0),
+    ///     Err(ProjectionError::ZeroWidth)
+    /// );
+    /// assert_eq!(
+    ///     Mat4::try_frustum(f32::NAN, -10.0, 10.0, 10.0, 0.1, 100.0),
+    ///     Err(ProjectionError::ZeroHeight)
+    /// );
+    /// assert_eq!(
+    ///     Mat4::try_frustum(-10.0, -10.0, 10.0, 10.0, 0.0, 100.0),
+    ///     Err(ProjectionError::NonPositiveNear)
+    /// );
+    /// assert_eq!(
+    ///     Mat4::try_frustum(-10.0, -10.0, 10.0, 10.0, 1.0, 1.0),
+    ///     Err(ProjectionError::FarNotBeyondNear)
+    /// );
+    /// ```
+    pub fn try_frustum(
+        top: f32,
+        left: f32,
+        right: f32,
+        bottom: f32,
+        near: f32,
+        far: f32,
+    ) -> Result<Mat4, ProjectionError> {
+        if near <= 0.0 || near.is_nan() {
+            Err(ProjectionError::NonPositiveNear)
+        } else if far <= near || far.is_nan() {
+            Err(ProjectionError::FarNotBeyondNear)
+        } else if right == left || right.is_nan() || left.is_nan() {
+            Err(ProjectionError::ZeroWidth)
+        } else if top == bottom || top.is_nan() || bottom.is_nan() {
+            Err(ProjectionError::ZeroHeight)
+        } else {
+            Ok(Self::frustum(top, left, right, bottom, near, far))
+        }
+    }
+
     /// Constructs a 4x4 perspective-projection matrix from a fov, aspect, near and far value.
     ///
     /// # Examples
@@ -108,10 +169,147 @@ impl Mat4 {
         Self::frustum(y_max, -x_max, x_max, -y_max, near, far)
     }
 
-    /// Constructs a 4x4 view-matrix from a eye, target and up `Vec3<f32>`.
-    /// The resulting view-matrix will be "positioned" at the coordinates of the eye vector, loking
+    /// Constructs a 4x4 perspective-projection matrix from a fov in degrees, aspect, near and far
+    /// value like `perspective`, but returns an error instead of a matrix of infinite or NaN
+    /// elements when the parameters describe a degenerate projection.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gamemath::{Mat4, ProjectionError};
+    ///
+    /// let m = Mat4::try_perspective(55.0, 1920.0 / 1080.0, 0.01, 100.0);
+    ///
+    /// assert_eq!(m, Ok(Mat4::perspective(55.0, 1920.0 / 1080.0, 0.01, 100.0)));
+    ///
+    /// assert_eq!(
+    ///     Mat4::try_perspective(0.0, 1.0, 0.01, 100.0),
+    ///     Err(ProjectionError::InvalidFov)
+    /// );
+    /// assert_eq!(
+    ///     Mat4::try_perspective(180.0, 1.0, 0.01, 100.0),
+    ///     Err(ProjectionError::InvalidFov)
+    /// );
+    /// assert_eq!(
+    ///     Mat4::try_perspective(55.0, 0.0, 0.01, 100.0),
+    ///     Err(ProjectionError::NonPositiveAspect)
+    /// );
+    /// assert_eq!(
+    ///     Mat4::try_perspective(55.0, 1.0, -0.01, 100.0),
+    ///     Err(ProjectionError::NonPositiveNear)
+    /// );
+    /// assert_eq!(
+    ///     Mat4::try_perspective(55.0, 1.0, 10.0, 1.0),
+    ///     Err(ProjectionError::FarNotBeyondNear)
+    /// );
+    /// ```
+    pub fn try_perspective(
+        fov: f32,
+        aspect: f32,
+        near: f32,
+        far: f32,
+    ) -> Result<Mat4, ProjectionError> {
+        if fov <= 0.0 || fov >= 180.0 || fov.is_nan() {
+            Err(ProjectionError::InvalidFov)
+        } else if aspect <= 0.0 || aspect.is_nan() {
+            Err(ProjectionError::NonPositiveAspect)
+        } else {
+            let y_max = near * (fov * (PI / 360.0)).tan();
+            let x_max = y_max * aspect;
+
+            Self::try_frustum(y_max, -x_max, x_max, -y_max, near, far)
+        }
+    }
+
+    /// Constructs a 4x4 perspective-projection matrix from a vertical fov in radians, aspect, near
+    /// and far value.
+    ///
+    /// This is the same projection as `perspective`, which takes the fov in degrees instead.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gamemath::Mat4;
+    ///
+    /// let m = Mat4::perspective_rad(55.0_f32.to_radians(), 1920.0 / 1080.0, 0.01, 100.0);
+    ///
+    /// assert!(m.approx_eq(Mat4::perspective(55.0, 1920.0 / 1080.0, 0.01, 100.0), 0.00001));
+    /// ```
+    pub fn perspective_rad(fov_y_radians: f32, aspect: f32, near: f32, far: f32) -> Mat4 {
+        let y_max = near * (fov_y_radians / 2.0).tan();
+        let x_max = y_max * aspect;
+
+        Self::frustum(y_max, -x_max, x_max, -y_max, near, far)
+    }
+
+    /// Combines a view matrix and a projection matrix into a single view-projection matrix,
+    /// calculated as `projection * view`. Transforming a point by the result first applies the
+    /// view matrix and then the projection matrix, and `transform_point` can be used to get the
+    /// normalized device coordinates of a point after the perspective divide.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gamemath::{Mat4, Vec3};
+    ///
+    /// let view = Mat4::identity().translated(Vec3::new(0.0, 0.0, -5.0));
+    /// let projection = Mat4::perspective(90.0, 1.0, 1.0, 100.0);
+    /// let m = Mat4::view_projection(view, projection);
+    ///
+    /// assert_eq!(m, projection * view);
+    ///
+    /// let ndc = m.transform_point(Vec3::new(2.5, -2.5, 0.0));
+    ///
+    /// assert!((ndc - Vec3::new(0.5, -0.5, 305.0 / 495.0)).length() < 0.000001);
+    /// ```
+    pub fn view_projection(view: Mat4, projection: Mat4) -> Mat4 {
+        projection * view
+    }
+
+    /// Constructs a 4x4 perspective-projection matrix from a fov, aspect, near and far value,
+    /// for the clip space used by Vulkan and wgpu.
+    ///
+    /// Unlike `perspective`, which follows the OpenGL convention of mapping depth to `-1..1` with
+    /// Y pointing up, the resulting matrix maps depth to `0..1` and flips the Y axis so that it
+    /// points down in normalized device coordinates.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gamemath::{Mat4, Vec3};
+    ///
+    /// let m = Mat4::perspective_vulkan(55.0, 1920.0 / 1080.0, 0.1, 100.0);
+    ///
+    /// let near = m.transform_point(Vec3::new(0.0, 0.0, -0.1));
+    /// let far = m.transform_point(Vec3::new(0.0, 0.0, -100.0));
+    /// let above = m.transform_point(Vec3::new(0.0, 1.0, -10.0));
+    ///
+    /// assert!(near.z.abs() < 0.000001);
+    /// assert!((far.z - 1.0).abs() < 0.000001);
+    /// assert!(above.y < 0.0);
+    /// ```
+    pub fn perspective_vulkan(fov: f32, aspect: f32, near: f32, far: f32) -> Mat4 {
+        let mut result: Mat4 = 0.0.into();
+
+        let focal_length = 1.0 / (fov * (PI / 360.0)).tan();
+        let near_to_far = 1.0 / (near - far);
+
+        result[0][0] = focal_length / aspect;
+        result[1][1] = -focal_length;
+        result[2][2] = far * near_to_far;
+        result[2][3] = -1.0;
+        result[3][2] = near * far * near_to_far;
+
+        result
+    }
+
+    /// Constructs a 4x4 camera matrix from a eye, target and up `Vec3<f32>`.
+    /// The resulting matrix will be "positioned" at the coordinates of the eye vector, loking
     /// in the direction of the coordinates of the target vecctor and with its up direction in the
-    /// direction of the up vector.
+    /// direction of the up vector. This is the world transform of the camera, which moves the
+    /// origin to the eye, so the view-matrix is its inverse.
+    ///
+    /// This follows the right-handed convention and is the same as `look_at_rh`.
     ///
     /// # Examples
     ///
@@ -126,8 +324,38 @@ impl Mat4 {
     /// assert_eq!(m[1], Vec4::new(0.0, -1.0, 0.0, 0.0));
     /// assert_eq!(m[2], Vec4::new(0.0, 0.0, 1.0, 0.0));
     /// assert_eq!(m[3], Vec4::new(0.0, 0.0, 1.0, 1.0));
+    /// assert_eq!(m.transform_point(Vec3::new(0.0, 0.0, 0.0)), Vec3::new(0.0, 0.0, 1.0));
+    ///
+    /// let view = m.inverted();
+    ///
+    /// assert_eq!(view.transform_point(Vec3::new(0.0, 0.0, 1.0)), Vec3::new(0.0, 0.0, 0.0));
     /// ```
     pub fn look_at(eye: Vec3<f32>, target: Vec3<f32>, up: Vec3<f32>) -> Mat4 {
+        Self::look_at_rh(eye, target, up)
+    }
+
+    /// Constructs a 4x4 camera matrix from a eye, target and up `Vec3<f32>`, following the
+    /// right-handed convention where the camera looks down its negative z axis. The z axis of the
+    /// resulting matrix points from the target towards the eye. Like `look_at`, this is the world
+    /// transform of the camera, and its inverse is the view-matrix.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gamemath::{Mat4, Vec3, Vec4};
+    ///
+    /// let eye = Vec3::new(0.0, 0.0, 1.0);
+    /// let target = Vec3::new(0.0, 0.0, 0.0);
+    /// let up = Vec3::new(0.0, 1.0, 0.0);
+    ///
+    /// let rh = Mat4::look_at_rh(eye, target, up);
+    /// let lh = Mat4::look_at_lh(eye, target, up);
+    ///
+    /// assert_eq!(rh, Mat4::look_at(eye, target, up));
+    /// assert_eq!(rh[2], Vec4::new(0.0, 0.0, 1.0, 0.0));
+    /// assert_eq!(rh.get_forward_vector(), -lh.get_forward_vector());
+    /// ```
+    pub fn look_at_rh(eye: Vec3<f32>, target: Vec3<f32>, up: Vec3<f32>) -> Mat4 {
         let forward = (eye - target).normalized();
         let right = up.normalized().cross(forward).normalized();
         let up = forward.cross(right).normalized();
@@ -141,6 +369,94 @@ impl Mat4 {
             .into()
     }
 
+    /// Constructs a 4x4 camera matrix from a eye, target and up `Vec3<f32>`, following the
+    /// left-handed convention where the camera looks down its positive z axis. The z axis of the
+    /// resulting matrix points from the eye towards the target. Like `look_at`, this is the world
+    /// transform of the camera, and its inverse is the view-matrix.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gamemath::{Mat4, Vec3, Vec4};
+    ///
+    /// let eye = Vec3::new(0.0, 0.0, 1.0);
+    /// let target = Vec3::new(0.0, 0.0, 0.0);
+    /// let up = Vec3::new(0.0, 1.0, 0.0);
+    ///
+    /// let m = Mat4::look_at_lh(eye, target, up);
+    ///
+    /// assert_eq!(m[2], Vec4::new(0.0, 0.0, -1.0, 0.0));
+    /// assert_eq!(m[2], -Mat4::look_at_rh(eye, target, up)[2]);
+    /// assert_eq!(m[3], Vec4::new(0.0, 0.0, 1.0, 1.0));
+    /// ```
+    pub fn look_at_lh(eye: Vec3<f32>, target: Vec3<f32>, up: Vec3<f32>) -> Mat4 {
+        let forward = (target - eye).normalized();
+        let right = up.normalized().cross(forward).normalized();
+        let up = forward.cross(right).normalized();
+
+        (
+            (right.x, right.y, right.z, 0.0),
+            (up.x, up.y, up.z, 0.0),
+            (forward.x, forward.y, forward.z, 0.0),
+            (eye.x, eye.y, eye.z, 1.0),
+        )
+            .into()
+    }
+
+    /// Constructs a 4x4 billboard matrix placed at a position and oriented so that its z axis,
+    /// the forward vector of the quad, points from the position towards a camera position, while
+    /// keeping its y axis as close to an up `Vec3<f32>` as possible. Useful for keeping sprites and
+    /// particles facing the camera.
+    ///
+    /// When the direction towards the camera is parallel to the up vector, an arbitrary right
+    /// vector perpendicular to the direction is picked instead. When the position and the camera
+    /// position coincide, the billboard faces the positive z axis.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gamemath::{Mat4, Vec3, Vec4};
+    ///
+    /// let position = Vec3::new(1.0, 2.0, 3.0);
+    /// let camera = Vec3::new(4.0, 2.0, 7.0);
+    /// let up = Vec3::new(0.0, 1.0, 0.0);
+    ///
+    /// let m = Mat4::billboard(position, camera, up);
+    ///
+    /// assert_eq!(m[2].xyz(), (camera - position).normalized());
+    /// assert_eq!(m[1].xyz(), up);
+    /// assert_eq!(m[3], Vec4::new(1.0, 2.0, 3.0, 1.0));
+    ///
+    /// let m = Mat4::billboard(position, Vec3::new(1.0, 10.0, 3.0), up);
+    ///
+    /// assert_eq!(m[2].xyz(), up);
+    /// assert!(m[0].xyz().dot(up).abs() < 0.000001);
+    /// assert!((m.determinant() - 1.0).abs() < 0.000001);
+    /// ```
+    pub fn billboard(position: Vec3<f32>, camera_pos: Vec3<f32>, up: Vec3<f32>) -> Mat4 {
+        let to_camera = camera_pos - position;
+        let forward = if to_camera.length_squared() > f32::EPSILON {
+            to_camera.normalized()
+        } else {
+            Vec3::new(0.0, 0.0, 1.0)
+        };
+        let right = up.cross(forward);
+        let right = if right.length_squared() > f32::EPSILON {
+            right.normalized()
+        } else {
+            forward.orthonormal_basis().0
+        };
+        let up = forward.cross(right);
+
+        (
+            (right.x, right.y, right.z, 0.0),
+            (up.x, up.y, up.z, 0.0),
+            (forward.x, forward.y, forward.z, 0.0),
+            (position.x, position.y, position.z, 1.0),
+        )
+            .into()
+    }
+
     /// Constructs a 4x4 perspective-orthogonal matrix from a top, left, right, bottom, near and far value.
     ///
     /// # Examples
@@ -174,6 +490,47 @@ impl Mat4 {
         result
     }
 
+    /// Constructs a 4x4 orthogonal-projection matrix from a top, left, right, bottom, near and far
+    /// value, for the clip space used by Vulkan and wgpu.
+    ///
+    /// The X and Y axes are mapped exactly like `orthogonal`, but depth is mapped to `0..1`
+    /// instead of the `-1..1` range used by OpenGL.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gamemath::{Mat4, Vec3};
+    ///
+    /// let m = Mat4::orthogonal_vulkan(0.0, 0.0, 1920.0, 1080.0, 0.1, 100.0);
+    /// let gl = Mat4::orthogonal(0.0, 0.0, 1920.0, 1080.0, 0.1, 100.0);
+    ///
+    /// let near = m.transform_point(Vec3::new(480.0, 270.0, -0.1));
+    /// let far = m.transform_point(Vec3::new(480.0, 270.0, -100.0));
+    /// let reference = gl.transform_point(Vec3::new(480.0, 270.0, -0.1));
+    ///
+    /// assert!(near.z.abs() < 0.000001);
+    /// assert!((far.z - 1.0).abs() < 0.000001);
+    /// assert_eq!((near.x, near.y), (reference.x, reference.y));
+    /// assert_eq!((far.x, far.y), (reference.x, reference.y));
+    /// ```
+    pub fn orthogonal_vulkan(
+        top: f32,
+        left: f32,
+        right: f32,
+        bottom: f32,
+        near: f32,
+        far: f32,
+    ) -> Mat4 {
+        let mut result = Self::orthogonal(top, left, right, bottom, near, far);
+
+        let near_to_far = 1.0 / (near - far);
+
+        result[2][2] = near_to_far;
+        result[3][2] = near * near_to_far;
+
+        result
+    }
+
     /// Extracts and returns a `Vec3<f32>` pointing left, away from the position of a view-matrix.
     /// Pretty much only makes sense for a view-matrix.
     ///
@@ -332,6 +689,53 @@ impl Mat4 {
         *self = self.transposed();
     }
 
+    /// Calculates and returns the product of the transpose of the calling `Mat4` object and
+    /// another `Mat4`, the same as `self.transposed() * other`, without constructing the
+    /// transposed matrix.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gamemath::{Mat4, Vec3};
+    ///
+    /// let a = Mat4::rotation(0.5, Vec3::new(1.0, 2.0, 3.0)).translated(Vec3::new(4.0, 5.0, 6.0));
+    /// let b = Mat4::identity().scaled(Vec3::new(1.0, -2.0, 3.0));
+    ///
+    /// assert!(a.mul_transpose(b).approx_eq(a.transposed() * b, 0.00001));
+    /// ```
+    pub fn mul_transpose(&self, other: Mat4) -> Mat4 {
+        let mut result: Mat4 = 0.0.into();
+
+        for i in 0..4 {
+            for j in 0..4 {
+                result[i][j] = self[j].dot(other[i]);
+            }
+        }
+
+        result
+    }
+
+    /// Calculates and returns the Gram matrix of the calling `Mat4` object, which is the
+    /// product of its transpose and itself. The result is always symmetric.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gamemath::Mat4;
+    ///
+    /// let m: Mat4 = (( 0.5, -1.0,  2.0,  3.5),
+    ///                ( 4.0,  0.25, 6.0, -7.0),
+    ///                (-8.0,  9.0,  1.5, 11.0),
+    ///                (12.0, 13.0, -14.0, 0.75)).into();
+    /// let gram = m.gram();
+    ///
+    /// assert_eq!(gram, gram.transposed());
+    /// assert!(gram.approx_eq(m.transposed() * m, 0.0001));
+    /// ```
+    pub fn gram(&self) -> Mat4 {
+        self.mul_transpose(*self)
+    }
+
     /// calculates and returns the determinant value of the calling `Mat4` object.
     ///
     /// # Examples
@@ -444,7 +848,8 @@ impl Mat4 {
         result
     }
 
-    /// calculates and returns the inverted matrix of the calling `Mat4` object.
+    /// calculates and returns the inverted matrix of the calling `Mat4` object. A zero matrix is
+    /// returned if the calling matrix can not be inverted.
     ///
     /// # Examples
     ///
@@ -459,11 +864,16 @@ impl Mat4 {
     ///                           ( 0.0, -1.0,  0.0,  0.0),
     ///                           ( 0.0,  0.0,  1.0,  0.0),
     ///                           ( 0.0,  0.0, -1.0,  1.0)).into());
+    ///
+    /// let reflection = Mat4::identity().scaled(Vec3::new(-1.0, 1.0, 1.0));
+    ///
+    /// assert_eq!(reflection.inverted(), reflection);
+    /// assert_eq!(reflection.inverted().transposed(), reflection.inverse_transpose());
     /// ```
     pub fn inverted(&self) -> Mat4 {
         let determinant = self.determinant();
 
-        if determinant > 0.0 {
+        if determinant != 0.0 {
             let mut result: Mat4 = 0.0.into();
             let adjoint = self.adjointed();
 
@@ -515,6 +925,92 @@ impl Mat4 {
         *self = self.inverted();
     }
 
+    /// Calculates and returns the transpose of the inverted matrix of the calling `Mat4` object,
+    /// which is used for transforming normals. A zero matrix is returned if the calling matrix
+    /// can not be inverted.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gamemath::{Mat4, Vec3};
+    ///
+    /// let m = Mat4::identity()
+    ///     .translated(Vec3::new(1.0, -2.0, 3.0))
+    ///     .rotated(0.5, Vec3::new(1.0, 1.0, 0.0))
+    ///     .scaled(Vec3::new(1.0, 2.0, 4.0));
+    ///
+    /// assert_eq!(m.inverse_transpose(), m.inverted().transposed());
+    /// assert_eq!(Mat4::from(0.0).inverse_transpose(), Mat4::from(0.0));
+    /// ```
+    pub fn inverse_transpose(&self) -> Mat4 {
+        let determinant = self.determinant();
+
+        if determinant != 0.0 {
+            let mut result: Mat4 = 0.0.into();
+            let adjoint = self.adjointed();
+
+            for i in 0..4 {
+                for j in 0..4 {
+                    result[i][j] = adjoint[j][i] / determinant;
+                }
+            }
+
+            result
+        } else {
+            0.0.into()
+        }
+    }
+
+    /// Calculates and returns the inverted matrix of the calling `Mat4` object, or `None` if the
+    /// matrix is singular. A matrix counts as singular when its determinant is zero, not finite, or
+    /// negligible next to the product of its row lengths, so uniformly scaled matrices invert
+    /// no matter how small the scale is. Unlike `inverted`, this makes a failed inversion impossible
+    /// to miss.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gamemath::{Mat4, Vec3};
+    ///
+    /// let m = Mat4::identity()
+    ///     .translated(Vec3::new(1.0, -2.0, 3.0))
+    ///     .rotated(0.5, Vec3::new(1.0, 1.0, 0.0))
+    ///     .scaled(Vec3::new(1.0, 2.0, 4.0));
+    /// let inverse = m.try_inverse().unwrap();
+    ///
+    /// assert!((m * inverse).approx_eq(Mat4::identity(), 0.00001));
+    /// assert_eq!(Mat4::identity().scaled(Vec3::new(1.0, 0.0, 1.0)).try_inverse(), None);
+    ///
+    /// let small = Mat4::from(0.001_f32);
+    /// let small_inverse = small.try_inverse().unwrap();
+    ///
+    /// assert!((small * small_inverse).approx_eq(Mat4::identity(), 0.00001));
+    /// ```
+    pub fn try_inverse(&self) -> Option<Mat4> {
+        let determinant = self.determinant();
+
+        if self.is_singular(determinant) {
+            return None;
+        }
+
+        let mut result: Mat4 = 0.0.into();
+        let adjoint = self.adjointed();
+
+        for i in 0..4 {
+            for j in 0..4 {
+                result[i][j] = adjoint[i][j] / determinant;
+            }
+        }
+
+        Some(result)
+    }
+
+    fn is_singular(&self, determinant: f32) -> bool {
+        let row_lengths = self[0].length() * self[1].length() * self[2].length() * self[3].length();
+
+        !determinant.is_finite() || determinant.abs() <= f32::EPSILON * row_lengths
+    }
+
     /// Constructs a 4x4 rotation matrix from a radians value and an axis `Vec3<f32>`.
     ///
     /// # Examples
@@ -524,10 +1020,10 @@ impl Mat4 {
     ///
     /// let m = Mat4::rotation(1.0, Vec3::new(0.0, 0.0, 1.0));
     ///
-    /// assert_eq!(m[0], Vec4::new(0.5403023,  -0.84147096, 0.0, 0.0));
-    /// assert_eq!(m[1], Vec4::new(0.84147096,  0.5403023,  0.0, 0.0));
-    /// assert_eq!(m[2], Vec4::new(0.0,         0.0,        1.0, 0.0));
-    /// assert_eq!(m[3], Vec4::new(0.0,         0.0,        0.0, 1.0));
+    /// assert_eq!(m[0], Vec4::new( 0.5403023,  0.84147096, 0.0, 0.0));
+    /// assert_eq!(m[1], Vec4::new(-0.84147096, 0.5403023,  0.0, 0.0));
+    /// assert_eq!(m[2], Vec4::new( 0.0,        0.0,        1.0, 0.0));
+    /// assert_eq!(m[3], Vec4::new( 0.0,        0.0,        0.0, 1.0));
     /// ```
     pub fn rotation(radians: f32, axis: Vec3<f32>) -> Mat4 {
         let sin = radians.sin();
@@ -538,19 +1034,19 @@ impl Mat4 {
         (
             (
                 axis.x * axis.x * cos_m1 + cos,
-                axis.x * axis.y * cos_m1 - axis.z * sin,
-                axis.x * axis.z * cos_m1 + axis.y * sin,
+                axis.x * axis.y * cos_m1 + axis.z * sin,
+                axis.x * axis.z * cos_m1 - axis.y * sin,
                 0.0,
             ),
             (
-                axis.y * axis.x * cos_m1 + axis.z * sin,
+                axis.y * axis.x * cos_m1 - axis.z * sin,
                 axis.y * axis.y * cos_m1 + cos,
-                axis.y * axis.z * cos_m1 - axis.x * sin,
+                axis.y * axis.z * cos_m1 + axis.x * sin,
                 0.0,
             ),
             (
-                axis.z * axis.x * cos_m1 - axis.y * sin,
-                axis.z * axis.y * cos_m1 + axis.x * sin,
+                axis.z * axis.x * cos_m1 + axis.y * sin,
+                axis.z * axis.y * cos_m1 - axis.x * sin,
                 axis.z * axis.z * cos_m1 + cos,
                 0.0,
             ),
@@ -559,66 +1055,147 @@ impl Mat4 {
             .into()
     }
 
-    /// Calculates and returns a `Mat4` object representing the calling `Mat4` object rotated
-    /// around a `Vec3<f32>` axis, by a radians value.
+    /// Constructs a 4x4 rotation matrix from a radians value, rotating around the X axis.
+    /// This is equivalent to, but cheaper than, calling `Mat4::rotation` with a unit X axis.
     ///
     /// # Examples
     ///
     /// ```
-    /// use gamemath::{Mat4, Vec3, Vec4};
+    /// use gamemath::{Mat4, Vec3};
     ///
-    /// let m = Mat4::identity().rotated(1.0, Vec3::new(0.0, 0.0, 1.0));
+    /// for &radians in [0.0, 1.0, -2.5, 4.0].iter() {
+    ///     let m = Mat4::rotation(radians, Vec3::new(1.0, 0.0, 0.0));
     ///
-    /// assert_eq!(m, ((0.5403023,  -0.84147096, 0.0, 0.0),
-    ///                (0.84147096,  0.5403023,  0.0, 0.0),
-    ///                (0.0,         0.0,        1.0, 0.0),
-    ///                (0.0,         0.0,        0.0, 1.0)).into());
+    ///     assert!(Mat4::rotation_x(radians).approx_eq(m, 0.000001));
+    /// }
     /// ```
-    pub fn rotated(&self, radians: f32, axis: Vec3<f32>) -> Mat4 {
-        *self * Mat4::rotation(radians, axis)
+    pub fn rotation_x(radians: f32) -> Mat4 {
+        let sin = radians.sin();
+        let cos = radians.cos();
+
+        (
+            (1.0, 0.0, 0.0, 0.0),
+            (0.0, cos, sin, 0.0),
+            (0.0, -sin, cos, 0.0),
+            (0.0, 0.0, 0.0, 1.0),
+        )
+            .into()
     }
 
-    /// Rotates the calling `Mat4` object around a `Vec3<f32>` axis, by a radians value.
+    /// Constructs a 4x4 rotation matrix from a radians value, rotating around the Y axis.
+    /// This is equivalent to, but cheaper than, calling `Mat4::rotation` with a unit Y axis.
     ///
     /// # Examples
     ///
     /// ```
-    /// use gamemath::{Mat4, Vec3, Vec4};
-    ///
-    /// let mut m = Mat4::identity();
+    /// use gamemath::{Mat4, Vec3};
     ///
-    /// m.rotate(1.0, Vec3::new(0.0, 0.0, 1.0));
+    /// for &radians in [0.0, 1.0, -2.5, 4.0].iter() {
+    ///     let m = Mat4::rotation(radians, Vec3::new(0.0, 1.0, 0.0));
     ///
-    /// assert_eq!(m, ((0.5403023,  -0.84147096, 0.0, 0.0),
-    ///                (0.84147096,  0.5403023,  0.0, 0.0),
-    ///                (0.0,         0.0,        1.0, 0.0),
-    ///                (0.0,         0.0,        0.0, 1.0)).into());
+    ///     assert!(Mat4::rotation_y(radians).approx_eq(m, 0.000001));
+    /// }
     /// ```
-    pub fn rotate(&mut self, radians: f32, axis: Vec3<f32>) {
-        *self = self.rotated(radians, axis);
+    pub fn rotation_y(radians: f32) -> Mat4 {
+        let sin = radians.sin();
+        let cos = radians.cos();
+
+        (
+            (cos, 0.0, -sin, 0.0),
+            (0.0, 1.0, 0.0, 0.0),
+            (sin, 0.0, cos, 0.0),
+            (0.0, 0.0, 0.0, 1.0),
+        )
+            .into()
     }
 
-    /// Calculates and returns a `Mat4` object representing the calling `Mat4` object scaled
-    /// by a `Vec3<f32>`.
+    /// Constructs a 4x4 rotation matrix from a radians value, rotating around the Z axis.
+    /// This is equivalent to, but cheaper than, calling `Mat4::rotation` with a unit Z axis.
     ///
     /// # Examples
     ///
     /// ```
     /// use gamemath::{Mat4, Vec3};
     ///
-    /// let m = Mat4::identity();
+    /// for &radians in [0.0, 1.0, -2.5, 4.0].iter() {
+    ///     let m = Mat4::rotation(radians, Vec3::new(0.0, 0.0, 1.0));
     ///
-    /// assert_eq!(m.scaled(Vec3::new(1.0, 2.0, 3.0)), ((1.0, 0.0, 0.0, 0.0),
-    ///                                                 (0.0, 2.0, 0.0, 0.0),
-    ///                                                 (0.0, 0.0, 3.0, 0.0),
-    ///                                                 (0.0, 0.0, 0.0, 1.0)).into());
+    ///     assert!(Mat4::rotation_z(radians).approx_eq(m, 0.000001));
+    /// }
     /// ```
-    pub fn scaled(&self, factor: Vec3<f32>) -> Mat4 {
-        let mut matrix = *self;
+    pub fn rotation_z(radians: f32) -> Mat4 {
+        let sin = radians.sin();
+        let cos = radians.cos();
 
-        matrix[0] *= factor.x;
-        matrix[1] *= factor.y;
-        matrix[2] *= factor.z;
+        (
+            (cos, sin, 0.0, 0.0),
+            (-sin, cos, 0.0, 0.0),
+            (0.0, 0.0, 1.0, 0.0),
+            (0.0, 0.0, 0.0, 1.0),
+        )
+            .into()
+    }
+
+    /// Calculates and returns a `Mat4` object representing the calling `Mat4` object rotated
+    /// around a `Vec3<f32>` axis, by a radians value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gamemath::{Mat4, Vec3, Vec4};
+    ///
+    /// let m = Mat4::identity().rotated(1.0, Vec3::new(0.0, 0.0, 1.0));
+    ///
+    /// assert_eq!(m, (( 0.5403023,  0.84147096, 0.0, 0.0),
+    ///                (-0.84147096, 0.5403023,  0.0, 0.0),
+    ///                ( 0.0,        0.0,        1.0, 0.0),
+    ///                ( 0.0,        0.0,        0.0, 1.0)).into());
+    /// ```
+    pub fn rotated(&self, radians: f32, axis: Vec3<f32>) -> Mat4 {
+        *self * Mat4::rotation(radians, axis)
+    }
+
+    /// Rotates the calling `Mat4` object around a `Vec3<f32>` axis, by a radians value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gamemath::{Mat4, Vec3, Vec4};
+    ///
+    /// let mut m = Mat4::identity();
+    ///
+    /// m.rotate(1.0, Vec3::new(0.0, 0.0, 1.0));
+    ///
+    /// assert_eq!(m, (( 0.5403023,  0.84147096, 0.0, 0.0),
+    ///                (-0.84147096, 0.5403023,  0.0, 0.0),
+    ///                ( 0.0,        0.0,        1.0, 0.0),
+    ///                ( 0.0,        0.0,        0.0, 1.0)).into());
+    /// ```
+    pub fn rotate(&mut self, radians: f32, axis: Vec3<f32>) {
+        *self = self.rotated(radians, axis);
+    }
+
+    /// Calculates and returns a `Mat4` object representing the calling `Mat4` object scaled
+    /// by a `Vec3<f32>`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gamemath::{Mat4, Vec3};
+    ///
+    /// let m = Mat4::identity();
+    ///
+    /// assert_eq!(m.scaled(Vec3::new(1.0, 2.0, 3.0)), ((1.0, 0.0, 0.0, 0.0),
+    ///                                                 (0.0, 2.0, 0.0, 0.0),
+    ///                                                 (0.0, 0.0, 3.0, 0.0),
+    ///                                                 (0.0, 0.0, 0.0, 1.0)).into());
+    /// ```
+    pub fn scaled(&self, factor: Vec3<f32>) -> Mat4 {
+        let mut matrix = *self;
+
+        matrix[0] *= factor.x;
+        matrix[1] *= factor.y;
+        matrix[2] *= factor.z;
 
         matrix
     }
@@ -696,6 +1273,726 @@ impl Mat4 {
     pub fn translate(&mut self, translation: Vec3<f32>) {
         *self = self.translated(translation);
     }
+
+    /// Constructs a 4x4 transformation matrix from a translation, a rotation and a scale, which
+    /// transforms points by first scaling, then rotating and finally translating them.
+    ///
+    /// Since `translated`, `rotated` and `scaled` each apply their transformation before the
+    /// ones already in the matrix, chaining them in the order translation, rotation and scale
+    /// builds the same matrix.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gamemath::{Mat4, Quat, Vec3};
+    ///
+    /// let translation = Vec3::new(1.0, 2.0, 3.0);
+    /// let scale = Vec3::new(2.0, 3.0, 4.0);
+    /// let axis = Vec3::new(1.0, 1.0, 0.0);
+    ///
+    /// let m = Mat4::from_trs(translation, Quat::rotation(0.5, axis), scale);
+    /// let chained = Mat4::identity()
+    ///     .translated(translation)
+    ///     .rotated(0.5, axis)
+    ///     .scaled(scale);
+    ///
+    /// assert!(m.approx_eq(chained, 0.000001));
+    /// assert_eq!(m.transform_point(Vec3::new(0.0, 0.0, 0.0)), translation);
+    /// ```
+    pub fn from_trs(translation: Vec3<f32>, rotation: Quat, scale: Vec3<f32>) -> Mat4 {
+        let mut result = rotation.extract_matrix().scaled(scale);
+
+        result[3][0] = translation.x;
+        result[3][1] = translation.y;
+        result[3][2] = translation.z;
+
+        result
+    }
+
+    /// Constructs a 4x4 transformation matrix from a translation, a rotation and a scale, the
+    /// same way as `from_trs`. This is the inverse operation of `decompose`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gamemath::{Mat4, Quat, Vec3};
+    ///
+    /// let m = Mat4::identity()
+    ///     .translated(Vec3::new(1.0, -2.0, 3.0))
+    ///     .rotated(0.5, Vec3::new(1.0, 1.0, 0.0))
+    ///     .scaled(Vec3::new(1.0, 2.0, 4.0));
+    /// let (translation, rotation, scale) = m.decompose();
+    ///
+    /// assert!(Mat4::recompose(translation, rotation, scale).approx_eq(m, 0.00001));
+    /// ```
+    pub fn recompose(translation: Vec3<f32>, rotation: Quat, scale: Vec3<f32>) -> Mat4 {
+        Mat4::from_trs(translation, rotation, scale)
+    }
+
+    /// Splits the calling `Mat4` object into a translation, a rotation and a scale, such that
+    /// `recompose` builds the matrix again. The matrix is expected to be affine, without shear
+    /// and with a non-zero scale along every axis.
+    ///
+    /// A mirroring transformation is returned as a negative scale along the x axis, since a
+    /// rotation can not represent it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gamemath::{Mat4, Quat, Vec3};
+    ///
+    /// let rotation = Quat::rotation(1.0, Vec3::new(0.0, 1.0, 0.0));
+    /// let m = Mat4::from_trs(Vec3::new(1.0, 2.0, 3.0), rotation, Vec3::new(2.0, 3.0, 4.0));
+    /// let (t, r, s) = m.decompose();
+    ///
+    /// assert_eq!(t, Vec3::new(1.0, 2.0, 3.0));
+    /// assert!(r.approx_eq(rotation, 0.00001));
+    /// assert!((s - Vec3::new(2.0, 3.0, 4.0)).length() < 0.00001);
+    ///
+    /// let (_, _, s) = Mat4::identity().scaled(Vec3::new(1.0, 1.0, -1.0)).decompose();
+    ///
+    /// assert_eq!(s, Vec3::new(-1.0, 1.0, 1.0));
+    /// ```
+    pub fn decompose(&self) -> (Vec3<f32>, Quat, Vec3<f32>) {
+        let mut scale = self.extract_scale();
+
+        if self.determinant() < 0.0 {
+            scale.x = -scale.x;
+        }
+
+        let rotation: Mat3 = (
+            self[0].truncate() / scale.x,
+            self[1].truncate() / scale.y,
+            self[2].truncate() / scale.z,
+        )
+            .into();
+
+        let translation = self.extract_translation();
+
+        (translation, Quat::from_mat3(rotation), scale)
+    }
+
+    /// Extracts and returns the translation of the calling `Mat4` object, stored in the xyz
+    /// components of its last row.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gamemath::{Mat4, Vec3};
+    ///
+    /// let m = Mat4::identity()
+    ///     .translated(Vec3::new(1.0, 2.0, 3.0))
+    ///     .scaled(Vec3::new(2.0, 3.0, 4.0));
+    ///
+    /// assert_eq!(m.extract_translation(), Vec3::new(1.0, 2.0, 3.0));
+    /// ```
+    pub fn extract_translation(&self) -> Vec3<f32> {
+        self[3].truncate()
+    }
+
+    /// Extracts and returns the scale of the calling `Mat4` object, calculated as the lengths of
+    /// its three basis vectors. A negative scale can not be told apart from a rotation and is
+    /// returned as positive.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gamemath::{Mat4, Vec3};
+    ///
+    /// let m = Mat4::identity()
+    ///     .translated(Vec3::new(1.0, 2.0, 3.0))
+    ///     .scaled(Vec3::new(2.0, 3.0, 4.0));
+    ///
+    /// assert_eq!(m.extract_scale(), Vec3::new(2.0, 3.0, 4.0));
+    /// ```
+    pub fn extract_scale(&self) -> Vec3<f32> {
+        Vec3::new(
+            self[0].truncate().length(),
+            self[1].truncate().length(),
+            self[2].truncate().length(),
+        )
+    }
+
+    /// Replaces the translation of the calling `Mat4` object, leaving its rotation and scale
+    /// untouched.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gamemath::{Mat4, Vec3};
+    ///
+    /// let mut m = Mat4::identity()
+    ///     .translated(Vec3::new(1.0, 2.0, 3.0))
+    ///     .rotated(1.0, Vec3::new(0.0, 1.0, 0.0));
+    /// let original = m;
+    ///
+    /// m.set_translation(Vec3::new(-4.0, 5.0, 6.0));
+    ///
+    /// assert_eq!(m.extract_translation(), Vec3::new(-4.0, 5.0, 6.0));
+    /// assert_eq!(m[0], original[0]);
+    /// assert_eq!(m[1], original[1]);
+    /// assert_eq!(m[2], original[2]);
+    /// ```
+    pub fn set_translation(&mut self, t: Vec3<f32>) {
+        self[3].x = t.x;
+        self[3].y = t.y;
+        self[3].z = t.z;
+    }
+
+    /// Replaces the scale of the calling `Mat4` object by rescaling its three basis vectors to the
+    /// lengths given by a `Vec3<f32>`, leaving its rotation and translation untouched. A basis
+    /// vector of zero length has no direction left to keep, and is replaced by the matching axis of
+    /// the identity matrix before being scaled.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gamemath::{Mat4, Vec3};
+    ///
+    /// let mut m = Mat4::identity()
+    ///     .translated(Vec3::new(1.0, 2.0, 3.0))
+    ///     .rotated(1.0, Vec3::new(0.0, 1.0, 0.0))
+    ///     .scaled(Vec3::new(2.0, 2.0, 2.0));
+    ///
+    /// m.set_scale(Vec3::new(1.0, 3.0, 0.5));
+    ///
+    /// let expected = Mat4::identity()
+    ///     .translated(Vec3::new(1.0, 2.0, 3.0))
+    ///     .rotated(1.0, Vec3::new(0.0, 1.0, 0.0))
+    ///     .scaled(Vec3::new(1.0, 3.0, 0.5));
+    ///
+    /// for i in 0..4 {
+    ///     assert!((m[i] - expected[i]).length() < 0.000001);
+    /// }
+    ///
+    /// let mut m = Mat4::identity().scaled(Vec3::new(0.0, 1.0, 1.0));
+    ///
+    /// m.set_scale(Vec3::new(2.0, 2.0, 2.0));
+    ///
+    /// assert_eq!(m, Mat4::identity().scaled(Vec3::new(2.0, 2.0, 2.0)));
+    /// ```
+    pub fn set_scale(&mut self, s: Vec3<f32>) {
+        for (i, scale) in [s.x, s.y, s.z].iter().enumerate() {
+            let basis = self[i].truncate();
+            let length = basis.length();
+            let direction = if length > 0.0 {
+                basis / length
+            } else {
+                let mut axis = Vec3::default();
+                axis[i] = 1.0;
+                axis
+            };
+
+            self[i] = (direction * *scale).extend(self[i].w);
+        }
+    }
+
+    /// Constructs a 4x4 shearing matrix from six shear factors. Each factor is named after the
+    /// axis it skews followed by the axis it skews by, so a point `p` is transformed into
+    /// `(p.x + xy * p.y + xz * p.z, p.y + yx * p.x + yz * p.z, p.z + zx * p.x + zy * p.y)`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gamemath::{Mat4, Vec3};
+    ///
+    /// let m = Mat4::shearing(1.0, 0.0, 0.0, 0.0, 0.0, 0.5);
+    ///
+    /// assert_eq!(m.transform_point(Vec3::new(1.0, 2.0, 3.0)), Vec3::new(3.0, 2.0, 4.0));
+    /// assert_eq!(Mat4::shearing(0.0, 0.0, 0.0, 0.0, 0.0, 0.0), Mat4::identity());
+    /// ```
+    pub fn shearing(xy: f32, xz: f32, yx: f32, yz: f32, zx: f32, zy: f32) -> Mat4 {
+        (
+            (1.0, yx, zx, 0.0),
+            (xy, 1.0, zy, 0.0),
+            (xz, yz, 1.0, 0.0),
+            (0.0, 0.0, 0.0, 1.0),
+        )
+            .into()
+    }
+
+    /// Calculates and returns a `Mat4` object representing the calling `Mat4` object sheared by
+    /// six shear factors, as described by `Mat4::shearing`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gamemath::{Mat4, Vec3};
+    ///
+    /// let m = Mat4::identity()
+    ///     .translated(Vec3::new(1.0, 0.0, 0.0))
+    ///     .sheared(0.0, 0.0, 2.0, 0.0, 0.0, 0.0);
+    ///
+    /// assert_eq!(m.transform_point(Vec3::new(1.0, 1.0, 1.0)), Vec3::new(2.0, 3.0, 1.0));
+    /// ```
+    pub fn sheared(&self, xy: f32, xz: f32, yx: f32, yz: f32, zx: f32, zy: f32) -> Mat4 {
+        *self * Mat4::shearing(xy, xz, yx, yz, zx, zy)
+    }
+
+    /// Performs the shear operation on the calling `Mat4` object, shearing it by six shear
+    /// factors, as described by `Mat4::shearing`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gamemath::{Mat4, Vec3};
+    ///
+    /// let mut m = Mat4::identity();
+    ///
+    /// m.shear(0.0, 0.5, 0.0, 0.0, 0.0, 0.0);
+    ///
+    /// assert_eq!(m.transform_point(Vec3::new(1.0, 1.0, 2.0)), Vec3::new(2.0, 1.0, 2.0));
+    /// ```
+    pub fn shear(&mut self, xy: f32, xz: f32, yx: f32, yz: f32, zx: f32, zy: f32) {
+        *self = self.sheared(xy, xz, yx, yz, zx, zy);
+    }
+
+    /// Calculates and returns a `Mat4` object with the upper-left 3x3 basis of the calling `Mat4`
+    /// object orthonormalized using the Gram-Schmidt process. The translation is left untouched.
+    ///
+    /// Useful for correcting the drift that builds up in a matrix after many incremental
+    /// rotations.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gamemath::{Mat4, Vec3};
+    ///
+    /// let m: Mat4 = ((1.0, 0.01, 0.0,  0.0),
+    ///                (0.02, 1.0, 0.0,  0.0),
+    ///                (0.0, 0.03, 0.98, 0.0),
+    ///                (5.0, 6.0,  7.0,  1.0)).into();
+    ///
+    /// let o = m.orthonormalized();
+    /// let x: Vec3<f32> = o[0].into();
+    /// let y: Vec3<f32> = o[1].into();
+    /// let z: Vec3<f32> = o[2].into();
+    ///
+    /// assert!(x.dot(y).abs() < 0.000001);
+    /// assert!(x.dot(z).abs() < 0.000001);
+    /// assert!(y.dot(z).abs() < 0.000001);
+    /// assert!((x.length() - 1.0).abs() < 0.000001);
+    /// assert!((y.length() - 1.0).abs() < 0.000001);
+    /// assert!((z.length() - 1.0).abs() < 0.000001);
+    /// assert_eq!(o[3], m[3]);
+    /// ```
+    pub fn orthonormalized(&self) -> Mat4 {
+        let mut result = *self;
+        let x: Vec3<f32> = self[0].into();
+        let y: Vec3<f32> = self[1].into();
+        let z: Vec3<f32> = self[2].into();
+
+        let x = x.normalized();
+        let y = (y - x * x.dot(y)).normalized();
+        let z = (z - x * x.dot(z) - y * y.dot(z)).normalized();
+
+        result[0] = Vec4::new(x.x, x.y, x.z, self[0][3]);
+        result[1] = Vec4::new(y.x, y.y, y.z, self[1][3]);
+        result[2] = Vec4::new(z.x, z.y, z.z, self[2][3]);
+
+        result
+    }
+
+    /// Performs the Gram-Schmidt orthonormalization on the upper-left 3x3 basis of the calling
+    /// `Mat4` object, leaving the translation untouched.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gamemath::{Mat4, Vec3};
+    ///
+    /// let mut m: Mat4 = ((2.0, 0.0, 0.0, 0.0),
+    ///                    (0.0, 3.0, 0.0, 0.0),
+    ///                    (0.0, 0.0, 4.0, 0.0),
+    ///                    (1.0, 2.0, 3.0, 1.0)).into();
+    ///
+    /// m.orthonormalize();
+    ///
+    /// assert_eq!(m, ((1.0, 0.0, 0.0, 0.0),
+    ///                (0.0, 1.0, 0.0, 0.0),
+    ///                (0.0, 0.0, 1.0, 0.0),
+    ///                (1.0, 2.0, 3.0, 1.0)).into());
+    /// ```
+    pub fn orthonormalize(&mut self) {
+        *self = self.orthonormalized();
+    }
+
+    /// Returns `true` if every element of the calling `Mat4` object is neither infinite nor NaN.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gamemath::Mat4;
+    ///
+    /// let mut m = Mat4::identity();
+    ///
+    /// assert!(m.is_finite());
+    ///
+    /// m[2][1] = f32::INFINITY;
+    ///
+    /// assert!(!m.is_finite());
+    /// ```
+    pub fn is_finite(&self) -> bool {
+        self[0].is_finite() && self[1].is_finite() && self[2].is_finite() && self[3].is_finite()
+    }
+
+    /// Returns `true` if any element of the calling `Mat4` object is NaN.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gamemath::Mat4;
+    ///
+    /// let mut m = Mat4::identity();
+    ///
+    /// assert!(!m.is_nan());
+    ///
+    /// m[3][0] = f32::NAN;
+    ///
+    /// assert!(m.is_nan());
+    /// ```
+    pub fn is_nan(&self) -> bool {
+        self[0].is_nan() || self[1].is_nan() || self[2].is_nan() || self[3].is_nan()
+    }
+
+    /// Transforms a point represented by a `Vec3<f32>` by the calling `Mat4` object.
+    /// The point is treated as having a W component of 1.0, so it is affected by translation, and
+    /// the result is divided by the resulting W component to apply any perspective projection.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gamemath::{Mat4, Vec3};
+    ///
+    /// let m = Mat4::identity().translated(Vec3::new(1.0, 2.0, 3.0));
+    ///
+    /// assert_eq!(m.transform_point(Vec3::new(1.0, 1.0, 1.0)), Vec3::new(2.0, 3.0, 4.0));
+    /// ```
+    pub fn transform_point(&self, point: Vec3<f32>) -> Vec3<f32> {
+        let result = self[0] * point.x + self[1] * point.y + self[2] * point.z + self[3];
+
+        Vec3::new(result.x, result.y, result.z) / result.w
+    }
+
+    /// Transforms a direction represented by a `Vec3<f32>` by the calling `Mat4` object.
+    /// The direction is treated as having a W component of 0.0, so it is not affected by
+    /// translation, and no perspective divide is performed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gamemath::{Mat4, Vec3};
+    ///
+    /// let m = Mat4::identity().translated(Vec3::new(1.0, 2.0, 3.0));
+    ///
+    /// assert_eq!(m.transform_vector(Vec3::new(1.0, 1.0, 1.0)), Vec3::new(1.0, 1.0, 1.0));
+    /// ```
+    pub fn transform_vector(&self, vector: Vec3<f32>) -> Vec3<f32> {
+        let result = self[0] * vector.x + self[1] * vector.y + self[2] * vector.z;
+
+        Vec3::new(result.x, result.y, result.z)
+    }
+
+    /// Returns a copy of the row at an index of the calling `Mat4` object, which is the same
+    /// `Vec4<f32>` that indexing the matrix with a single index returns.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gamemath::{Mat4, Vec4};
+    ///
+    /// let m: Mat4 = (( 0.0,  1.0,  2.0,  3.0),
+    ///                ( 4.0,  5.0,  6.0,  7.0),
+    ///                ( 8.0,  9.0, 10.0, 11.0),
+    ///                (12.0, 13.0, 14.0, 15.0)).into();
+    ///
+    /// assert_eq!(m.row(1), Vec4::new(4.0, 5.0, 6.0, 7.0));
+    /// ```
+    pub fn row(&self, index: usize) -> Vec4<f32> {
+        self[index]
+    }
+
+    /// Assembles and returns the column at an index of the calling `Mat4` object.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gamemath::{Mat4, Vec4};
+    ///
+    /// let m: Mat4 = (( 0.0,  1.0,  2.0,  3.0),
+    ///                ( 4.0,  5.0,  6.0,  7.0),
+    ///                ( 8.0,  9.0, 10.0, 11.0),
+    ///                (12.0, 13.0, 14.0, 15.0)).into();
+    ///
+    /// assert_eq!(m.col(0), Vec4::new(0.0, 4.0, 8.0, 12.0));
+    /// ```
+    pub fn col(&self, index: usize) -> Vec4<f32> {
+        (
+            self[0][index],
+            self[1][index],
+            self[2][index],
+            self[3][index],
+        )
+            .into()
+    }
+
+    /// Constructs a `Mat4` from four `Vec4<f32>` used as its columns, as opposed to converting
+    /// from a tuple of vectors, which uses them as rows.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gamemath::{Mat4, Vec4};
+    ///
+    /// let a = Vec4::new(1.0, 2.0, 3.0, 4.0);
+    /// let b = Vec4::new(5.0, 6.0, 7.0, 8.0);
+    /// let c = Vec4::new(9.0, 10.0, 11.0, 12.0);
+    /// let d = Vec4::new(13.0, 14.0, 15.0, 16.0);
+    /// let m = Mat4::from_columns(a, b, c, d);
+    ///
+    /// assert_eq!(m, Mat4::from((a, b, c, d)).transposed());
+    /// assert_eq!(m[0], Vec4::new(a.x, b.x, c.x, d.x));
+    /// ```
+    pub fn from_columns(x: Vec4<f32>, y: Vec4<f32>, z: Vec4<f32>, w: Vec4<f32>) -> Mat4 {
+        (
+            (x.x, y.x, z.x, w.x),
+            (x.y, y.y, z.y, w.y),
+            (x.z, y.z, z.z, w.z),
+            (x.w, y.w, z.w, w.w),
+        )
+            .into()
+    }
+
+    /// Returns the elements of the calling `Mat4` object as a column-major 2D array, where each
+    /// inner array holds one column. This is the transpose of the row-major layout used when
+    /// converting a `Mat4` to and from a `[[f32; 4]; 4]`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gamemath::Mat4;
+    ///
+    /// let rows = [[ 0.0,  1.0,  2.0,  3.0],
+    ///             [ 4.0,  5.0,  6.0,  7.0],
+    ///             [ 8.0,  9.0, 10.0, 11.0],
+    ///             [12.0, 13.0, 14.0, 15.0]];
+    /// let m = Mat4::from(rows);
+    /// let cols = m.to_cols_array_2d();
+    ///
+    /// assert_eq!(cols[0], [0.0, 4.0, 8.0, 12.0]);
+    /// assert_ne!(cols, rows);
+    /// assert_ne!(Mat4::from(cols), m);
+    /// assert_eq!(Mat4::from_cols_array_2d(&cols), m);
+    /// ```
+    pub fn to_cols_array_2d(&self) -> [[f32; 4]; 4] {
+        let mut cols = [[0.0; 4]; 4];
+
+        for (i, col) in cols.iter_mut().enumerate() {
+            for (j, element) in col.iter_mut().enumerate() {
+                *element = self[j][i];
+            }
+        }
+
+        cols
+    }
+
+    /// Constructs a `Mat4` from a column-major 2D array, where each inner array holds one column.
+    /// This is the transpose of the row-major layout used when converting from a
+    /// `[[f32; 4]; 4]`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gamemath::{Mat4, Vec4};
+    ///
+    /// let m = Mat4::from_cols_array_2d(&[[ 0.0,  1.0,  2.0,  3.0],
+    ///                                    [ 4.0,  5.0,  6.0,  7.0],
+    ///                                    [ 8.0,  9.0, 10.0, 11.0],
+    ///                                    [12.0, 13.0, 14.0, 15.0]]);
+    ///
+    /// assert_eq!(m[0], Vec4::new(0.0, 4.0, 8.0, 12.0));
+    /// assert_eq!(m[1], Vec4::new(1.0, 5.0, 9.0, 13.0));
+    /// ```
+    pub fn from_cols_array_2d(cols: &[[f32; 4]; 4]) -> Mat4 {
+        Mat4::from(*cols).transposed()
+    }
+
+    /// Extracts the six clipping planes of the calling `Mat4` object, treating it as a combined
+    /// view-projection matrix. The planes are returned in the order left, right, bottom, top,
+    /// near and far, are normalized and face the inside of the frustum.
+    ///
+    /// Points are transformed as row vectors, like in `transform_point`, so the vectors used for
+    /// the Gribb-Hartmann extraction are the columns of the matrix, which are the rows of its
+    /// transpose.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gamemath::{Mat4, Vec3};
+    ///
+    /// let m = Mat4::perspective(55.0, 1920.0 / 1080.0, 0.1, 100.0);
+    /// let planes = m.frustum_planes();
+    ///
+    /// for plane in planes.iter() {
+    ///     assert!(plane.signed_distance(Vec3::new(0.5, -0.5, -10.0)) > 0.0);
+    /// }
+    ///
+    /// assert!(planes[4].signed_distance(Vec3::new(0.0, 0.0, 1.0)) < 0.0);
+    /// assert!(planes[5].signed_distance(Vec3::new(0.0, 0.0, -200.0)) < 0.0);
+    /// ```
+    pub fn frustum_planes(&self) -> [Plane; 6] {
+        let clip = self.transposed();
+        let x = clip[0];
+        let y = clip[1];
+        let z = clip[2];
+        let w = clip[3];
+
+        let plane = |v: Vec4<f32>| Plane::new(Vec3::new(v.x, v.y, v.z), v.w).normalized();
+
+        [
+            plane(w + x),
+            plane(w - x),
+            plane(w + y),
+            plane(w - y),
+            plane(w + z),
+            plane(w - z),
+        ]
+    }
+
+    /// Returns `true` if the calling `Mat4` object is an affine transformation, meaning that
+    /// its projective part, `col(3)`, is `(0.0, 0.0, 0.0, 1.0)` within an epsilon.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gamemath::{Mat4, Vec3};
+    ///
+    /// let camera = Mat4::look_at(Vec3::new(1.0, 2.0, 3.0),
+    ///                            Vec3::new(0.0, 0.0, 0.0),
+    ///                            Vec3::new(0.0, 1.0, 0.0));
+    ///
+    /// assert!(camera.is_affine(1e-6));
+    /// assert!(!Mat4::perspective(55.0, 1920.0 / 1080.0, 0.01, 100.0).is_affine(1e-6));
+    /// ```
+    pub fn is_affine(&self, epsilon: f32) -> bool {
+        self[0][3].abs() <= epsilon
+            && self[1][3].abs() <= epsilon
+            && self[2][3].abs() <= epsilon
+            && (self[3][3] - 1.0).abs() <= epsilon
+    }
+
+    /// Returns `true` if every element of the calling `Mat4` object is within an epsilon of the
+    /// corresponding element of the identity matrix.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gamemath::{Mat4, Vec3};
+    ///
+    /// assert!(Mat4::identity().is_identity(0.0));
+    /// assert!(Mat4::rotation(1.0, Vec3::new(0.0, 1.0, 0.0))
+    ///     .rotated(-1.0, Vec3::new(0.0, 1.0, 0.0))
+    ///     .is_identity(1e-6));
+    /// assert!(!Mat4::identity().translated(Vec3::new(0.0, 0.1, 0.0)).is_identity(1e-6));
+    /// ```
+    pub fn is_identity(&self, epsilon: f32) -> bool {
+        self.approx_eq(Mat4::identity(), epsilon)
+    }
+
+    /// Returns `true` if every element of the calling `Mat4` object is within an epsilon of the
+    /// corresponding element of another `Mat4`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gamemath::{Mat4, Vec3};
+    ///
+    /// let a = Mat4::rotation(1.0, Vec3::new(0.0, 1.0, 0.0));
+    /// let b = Mat4::rotation(0.5, Vec3::new(0.0, 1.0, 0.0))
+    ///     .rotated(0.5, Vec3::new(0.0, 1.0, 0.0));
+    ///
+    /// assert!(a.approx_eq(b, 0.000001));
+    /// assert!(!a.approx_eq(Mat4::identity(), 0.000001));
+    /// ```
+    pub fn approx_eq(&self, other: Mat4, epsilon: f32) -> bool {
+        (0..4).all(|i| (0..4).all(|j| (self[i][j] - other[i][j]).abs() <= epsilon))
+    }
+
+    /// Returns a reference to the row at an index of the calling `Mat4` object, or `None` if the
+    /// index is out of range, instead of panicking like indexing does.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gamemath::Mat4;
+    ///
+    /// let m = Mat4::identity();
+    ///
+    /// assert_eq!(m.get(0), Some(&m[0]));
+    /// assert_eq!(m.get(4), None);
+    /// ```
+    pub fn get(&self, index: usize) -> Option<&Vec4<f32>> {
+        self.rows.get(index)
+    }
+
+    /// Returns an iterator over references to the elements of the calling `Mat4` object, going
+    /// through them row by row.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gamemath::Mat4;
+    ///
+    /// let elements = [ 0.0,  1.0,  2.0,  3.0,
+    ///                  4.0,  5.0,  6.0,  7.0,
+    ///                  8.0,  9.0, 10.0, 11.0,
+    ///                 12.0, 13.0, 14.0, 15.0];
+    /// let m = Mat4::from(elements);
+    ///
+    /// assert_eq!(m.iter().count(), 16);
+    /// assert_eq!(m.iter().cloned().collect::<Vec<f32>>(), elements.to_vec());
+    /// ```
+    pub fn iter(&self) -> impl Iterator<Item = &f32> {
+        self.rows.iter().flat_map(|row| {
+            core::iter::once(&row.x)
+                .chain(core::iter::once(&row.y))
+                .chain(core::iter::once(&row.z))
+                .chain(core::iter::once(&row.w))
+        })
+    }
+
+    /// Returns an iterator over mutable references to the elements of the calling `Mat4`
+    /// object, going through them row by row.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gamemath::Mat4;
+    ///
+    /// let mut m = Mat4::identity();
+    ///
+    /// for element in m.iter_mut() {
+    ///     *element += 1.0;
+    /// }
+    ///
+    /// assert_eq!(m[0][0], 2.0);
+    /// assert_eq!(m[1][0], 1.0);
+    /// ```
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = &mut f32> {
+        self.rows.iter_mut().flat_map(|row| {
+            let Vec4 {
+                ref mut x,
+                ref mut y,
+                ref mut z,
+                ref mut w,
+            } = *row;
+
+            core::iter::once(x)
+                .chain(core::iter::once(y))
+                .chain(core::iter::once(z))
+                .chain(core::iter::once(w))
+        })
+    }
 }
 
 impl Default for Mat4 {
@@ -775,6 +2072,31 @@ impl From<[f32; 16]> for Mat4 {
     }
 }
 
+/// Converts a slice of elements in row-major order into a `Mat4`, failing if the length of the
+/// slice is not exactly 16.
+///
+/// # Examples
+///
+/// ```
+/// use gamemath::Mat4;
+/// use std::convert::TryFrom;
+///
+/// let elements: Vec<f32> = (0..17).map(|i| i as f32).collect();
+/// let m = Mat4::try_from(&elements[..16]).unwrap();
+///
+/// assert_eq!(m[0][1], 1.0);
+/// assert_eq!(m[1][0], 4.0);
+/// assert!(Mat4::try_from(&elements[..15]).is_err());
+/// assert!(Mat4::try_from(&elements[..]).is_err());
+/// ```
+impl<'a> core::convert::TryFrom<&'a [f32]> for Mat4 {
+    type Error = core::array::TryFromSliceError;
+
+    fn try_from(slice: &'a [f32]) -> Result<Mat4, core::array::TryFromSliceError> {
+        <[f32; 16] as core::convert::TryFrom<&[f32]>>::try_from(slice).map(Mat4::from)
+    }
+}
+
 impl From<[Vec4<f32>; 4]> for Mat4 {
     fn from(slice: [Vec4<f32>; 4]) -> Mat4 {
         Mat4 {
@@ -797,7 +2119,7 @@ impl From<Quat> for Mat4 {
     }
 }
 
-impl std::ops::Index<usize> for Mat4 {
+impl core::ops::Index<usize> for Mat4 {
     type Output = Vec4<f32>;
 
     fn index(&self, index: usize) -> &Vec4<f32> {
@@ -811,7 +2133,7 @@ impl std::ops::Index<usize> for Mat4 {
     }
 }
 
-impl std::ops::IndexMut<usize> for Mat4 {
+impl core::ops::IndexMut<usize> for Mat4 {
     fn index_mut(&mut self, index: usize) -> &mut Vec4<f32> {
         match index {
             0 => &mut self.rows[0],
@@ -823,7 +2145,7 @@ impl std::ops::IndexMut<usize> for Mat4 {
     }
 }
 
-impl std::ops::Index<(usize, usize)> for Mat4 {
+impl core::ops::Index<(usize, usize)> for Mat4 {
     type Output = f32;
 
     fn index(&self, index: (usize, usize)) -> &f32 {
@@ -831,13 +2153,13 @@ impl std::ops::Index<(usize, usize)> for Mat4 {
     }
 }
 
-impl std::ops::IndexMut<(usize, usize)> for Mat4 {
+impl core::ops::IndexMut<(usize, usize)> for Mat4 {
     fn index_mut(&mut self, index: (usize, usize)) -> &mut f32 {
         &mut self.rows[index.0][index.1]
     }
 }
 
-impl std::ops::Add for Mat4 {
+impl core::ops::Add for Mat4 {
     type Output = Mat4;
 
     fn add(self, right: Mat4) -> Mat4 {
@@ -852,13 +2174,13 @@ impl std::ops::Add for Mat4 {
     }
 }
 
-impl std::ops::AddAssign for Mat4 {
+impl core::ops::AddAssign for Mat4 {
     fn add_assign(&mut self, right: Mat4) {
         *self = *self + right;
     }
 }
 
-impl std::ops::Sub for Mat4 {
+impl core::ops::Sub for Mat4 {
     type Output = Mat4;
 
     fn sub(self, right: Mat4) -> Mat4 {
@@ -873,13 +2195,65 @@ impl std::ops::Sub for Mat4 {
     }
 }
 
-impl std::ops::SubAssign for Mat4 {
+impl core::ops::SubAssign for Mat4 {
     fn sub_assign(&mut self, right: Mat4) {
         *self = *self - right;
     }
 }
 
-impl std::ops::Mul<Vec4<f32>> for Mat4 {
+/// Negates every element of a `Mat4`.
+///
+/// # Examples
+///
+/// ```
+/// use gamemath::Mat4;
+///
+/// let m = -Mat4::identity();
+///
+/// assert_eq!(m[0][0], -1.0);
+/// assert_eq!(m[3][3], -1.0);
+/// assert_eq!(m + Mat4::identity(), Mat4::from(0.0));
+/// ```
+impl core::ops::Neg for Mat4 {
+    type Output = Mat4;
+
+    fn neg(self) -> Mat4 {
+        Mat4 {
+            rows: [-self[0], -self[1], -self[2], -self[3]],
+        }
+    }
+}
+
+/// Divides every element of a `Mat4` by a scalar value. Dividing by zero is not guarded against,
+/// and follows the IEEE 754 rules, producing infinite or NaN elements.
+///
+/// # Examples
+///
+/// ```
+/// use gamemath::Mat4;
+///
+/// let m = Mat4::from([2.0; 16]) / 2.0;
+///
+/// assert_eq!(m, Mat4::from([1.0; 16]));
+/// assert_eq!(Mat4::identity() / 4.0, Mat4::from(0.25));
+/// ```
+impl core::ops::Div<f32> for Mat4 {
+    type Output = Mat4;
+
+    fn div(self, right: f32) -> Mat4 {
+        Mat4 {
+            rows: [
+                self[0] / right,
+                self[1] / right,
+                self[2] / right,
+                self[3] / right,
+            ],
+        }
+    }
+}
+
+#[cfg(not(all(feature = "simd", target_arch = "x86_64")))]
+impl core::ops::Mul<Vec4<f32>> for Mat4 {
     type Output = Vec4<f32>;
 
     fn mul(self, vec: Vec4<f32>) -> Vec4<f32> {
@@ -893,7 +2267,8 @@ impl std::ops::Mul<Vec4<f32>> for Mat4 {
     }
 }
 
-impl std::ops::Mul<Mat4> for Mat4 {
+#[cfg(not(all(feature = "simd", target_arch = "x86_64")))]
+impl core::ops::Mul<Mat4> for Mat4 {
     type Output = Mat4;
 
     fn mul(self, right: Mat4) -> Mat4 {
@@ -987,7 +2362,7 @@ impl std::ops::Mul<Mat4> for Mat4 {
     }
 }
 
-impl std::ops::MulAssign<Mat4> for Mat4 {
+impl core::ops::MulAssign<Mat4> for Mat4 {
     fn mul_assign(&mut self, right: Mat4) {
         *self = *self * right;
     }
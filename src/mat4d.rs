@@ -0,0 +1,2331 @@
+use core;
+use core::f64::consts::PI;
+use mat3d::Mat3d;
+#[cfg(all(not(feature = "std"), not(test)))]
+use math::Float;
+use projection_error::ProjectionError;
+use quatd::Quatd;
+use vec3::Vec3;
+use vec4::Vec4;
+
+type Row = (f64, f64, f64, f64);
+type InlineMat4 = (
+    f64,
+    f64,
+    f64,
+    f64,
+    f64,
+    f64,
+    f64,
+    f64,
+    f64,
+    f64,
+    f64,
+    f64,
+    f64,
+    f64,
+    f64,
+    f64,
+);
+
+/// A 4x4-component Euclidean matrix of `f64` components, mirroring `Mat4` for computations that
+/// need double precision.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Mat4d {
+    /// The four rows of the matrix, represented by an array of four `Vec4<f64>` objects.
+    pub rows: [Vec4<f64>; 4],
+}
+
+impl Mat4d {
+    /// Constructs a 4x4 identity matrix.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gamemath::{Mat4d, Vec4};
+    ///
+    /// let m = Mat4d::identity();
+    ///
+    /// assert_eq!(m[0], Vec4::new(1.0, 0.0, 0.0, 0.0));
+    /// assert_eq!(m[1], Vec4::new(0.0, 1.0, 0.0, 0.0));
+    /// assert_eq!(m[2], Vec4::new(0.0, 0.0, 1.0, 0.0));
+    /// assert_eq!(m[3], Vec4::new(0.0, 0.0, 0.0, 1.0));
+    /// ```
+    pub fn identity() -> Mat4d {
+        Self::default()
+    }
+
+    /// Constructs a 4x4 frustum matrix from a top, left, right, bottom, near and far value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gamemath::{Mat4d, Vec4};
+    ///
+    /// let m = Mat4d::frustum(-10.0, -10.0, 10.0, 10.0, 0.1, 100.0);
+    ///
+    /// assert_eq!(m[0], Vec4::new(0.01, 0.0, 0.0, 0.0));
+    /// assert_eq!(m[1], Vec4::new(0.0, -0.01, 0.0, 0.0));
+    /// assert_eq!(m[2], Vec4::new(0.0, 0.0, -1.002002002002002, -1.0));
+    /// assert_eq!(m[3], Vec4::new(0.0, 0.0, -0.20020020020020018, 0.0));
+    /// ```
+    pub fn frustum(top: f64, left: f64, right: f64, bottom: f64, near: f64, far: f64) -> Mat4d {
+        let mut result: Mat4d = 0.0.into();
+
+        let double_near = near * 2.0;
+        let delta_x = right - left;
+        let delta_y = top - bottom;
+        let delta_z = far - near;
+
+        result[0][0] = double_near / delta_x;
+        result[1][1] = double_near / delta_y;
+        result[2][0] = (right + left) / delta_x;
+        result[2][1] = (top + bottom) / delta_y;
+        result[2][2] = (-far - near) / delta_z;
+        result[2][3] = -1.0;
+        result[3][2] = (-double_near * far) / delta_z;
+
+        result
+    }
+
+    /// Constructs a 4x4 frustum matrix from a top, left, right, bottom, near and far value like
+    /// `frustum`, but returns an error instead of a matrix of infinite or NaN elements when the
+    /// parameters describe a degenerate frustum or any of them is NaN.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gamemath::{Mat4d, ProjectionError};
+    ///
+    /// let m = Mat4d::try_frustum(-10.0, -10.0, 10.0, 10.0, 0.1, 100.0);
+    ///
+    /// assert_eq!(m, Ok(Mat4d::frustum(-10.0, -10.0, 10.0, 10.0, 0.1, 100.0)));
+    ///
+    /// assert_eq!(
+    ///     Mat4d::try_frustum(-10.0, 5.0, 5.0, 10.0, 0.1, 100.0),
+    ///     Err(ProjectionError::ZeroWidth)
+    /// );
+    /// assert_eq!(
+    ///     Mat4d::try_frustum(10.0, -10.0, 10.0, 10.0, 0.1, 100.0),
+    ///     Err(ProjectionError::ZeroHeight)
+    /// );
+    /// assert_eq!(
+    ///     Mat4d::try_frustum(-10.0, f64::NAN, 10.0, 10.0, 0.1, 100.0),
+    ///     Err(ProjectionError::ZeroWidth)
+    /// );
+    /// assert_eq!(
+    ///     Mat4d::try_frustum(f64::NAN, -10.0, 10.0, 10.0, 0.1, 100.0),
+    ///     Err(ProjectionError::ZeroHeight)
+    /// );
+    /// assert_eq!(
+    ///     Mat4d::try_frustum(-10.0, -10.0, 10.0, 10.0, 0.0, 100.0),
+    ///     Err(ProjectionError::NonPositiveNear)
+    /// );
+    /// assert_eq!(
+    ///     Mat4d::try_frustum(-10.0, -10.0, 10.0, 10.0, 1.0, 1.0),
+    ///     Err(ProjectionError::FarNotBeyondNear)
+    /// );
+    /// ```
+    pub fn try_frustum(
+        top: f64,
+        left: f64,
+        right: f64,
+        bottom: f64,
+        near: f64,
+        far: f64,
+    ) -> Result<Mat4d, ProjectionError> {
+        if near <= 0.0 || near.is_nan() {
+            Err(ProjectionError::NonPositiveNear)
+        } else if far <= near || far.is_nan() {
+            Err(ProjectionError::FarNotBeyondNear)
+        } else if right == left || right.is_nan() || left.is_nan() {
+            Err(ProjectionError::ZeroWidth)
+        } else if top == bottom || top.is_nan() || bottom.is_nan() {
+            Err(ProjectionError::ZeroHeight)
+        } else {
+            Ok(Self::frustum(top, left, right, bottom, near, far))
+        }
+    }
+
+    /// Constructs a 4x4 perspective-projection matrix from a fov, aspect, near and far value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gamemath::{Mat4d, Vec4};
+    ///
+    /// let m = Mat4d::perspective(55.0, 1920.0 / 1080.0, 0.01, 100.0);
+    ///
+    /// assert_eq!(m[0], Vec4::new(1.080552446421281, 0.0, 0.0, 0.0));
+    /// assert_eq!(m[1], Vec4::new(0.0, 1.920982126971166, 0.0, 0.0));
+    /// assert_eq!(m[2], Vec4::new(0.0, 0.0, -1.0002000200020003, -1.0));
+    /// assert_eq!(m[3], Vec4::new(0.0, 0.0, -0.020002000200020003, 0.0));
+    /// ```
+    pub fn perspective(fov: f64, aspect: f64, near: f64, far: f64) -> Mat4d {
+        let y_max = near * (fov * (PI / 360.0)).tan();
+        let x_max = y_max * aspect;
+
+        Self::frustum(y_max, -x_max, x_max, -y_max, near, far)
+    }
+
+    /// Constructs a 4x4 perspective-projection matrix from a fov in degrees, aspect, near and far
+    /// value like `perspective`, but returns an error instead of a matrix of infinite or NaN
+    /// elements when the parameters describe a degenerate projection.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gamemath::{Mat4d, ProjectionError};
+    ///
+    /// let m = Mat4d::try_perspective(55.0, 1920.0 / 1080.0, 0.01, 100.0);
+    ///
+    /// assert_eq!(m, Ok(Mat4d::perspective(55.0, 1920.0 / 1080.0, 0.01, 100.0)));
+    ///
+    /// assert_eq!(
+    ///     Mat4d::try_perspective(0.0, 1.0, 0.01, 100.0),
+    ///     Err(ProjectionError::InvalidFov)
+    /// );
+    /// assert_eq!(
+    ///     Mat4d::try_perspective(180.0, 1.0, 0.01, 100.0),
+    ///     Err(ProjectionError::InvalidFov)
+    /// );
+    /// assert_eq!(
+    ///     Mat4d::try_perspective(55.0, 0.0, 0.01, 100.0),
+    ///     Err(ProjectionError::NonPositiveAspect)
+    /// );
+    /// assert_eq!(
+    ///     Mat4d::try_perspective(55.0, 1.0, -0.01, 100.0),
+    ///     Err(ProjectionError::NonPositiveNear)
+    /// );
+    /// assert_eq!(
+    ///     Mat4d::try_perspective(55.0, 1.0, 10.0, 1.0),
+    ///     Err(ProjectionError::FarNotBeyondNear)
+    /// );
+    /// ```
+    pub fn try_perspective(
+        fov: f64,
+        aspect: f64,
+        near: f64,
+        far: f64,
+    ) -> Result<Mat4d, ProjectionError> {
+        if fov <= 0.0 || fov >= 180.0 || fov.is_nan() {
+            Err(ProjectionError::InvalidFov)
+        } else if aspect <= 0.0 || aspect.is_nan() {
+            Err(ProjectionError::NonPositiveAspect)
+        } else {
+            let y_max = near * (fov * (PI / 360.0)).tan();
+            let x_max = y_max * aspect;
+
+            Self::try_frustum(y_max, -x_max, x_max, -y_max, near, far)
+        }
+    }
+
+    /// Constructs a 4x4 perspective-projection matrix from a vertical fov in radians, aspect, near
+    /// and far value.
+    ///
+    /// This is the same projection as `perspective`, which takes the fov in degrees instead.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gamemath::Mat4d;
+    ///
+    /// let m = Mat4d::perspective_rad(55.0_f64.to_radians(), 1920.0 / 1080.0, 0.01, 100.0);
+    ///
+    /// assert!(m.approx_eq(Mat4d::perspective(55.0, 1920.0 / 1080.0, 0.01, 100.0), 0.00001));
+    /// ```
+    pub fn perspective_rad(fov_y_radians: f64, aspect: f64, near: f64, far: f64) -> Mat4d {
+        let y_max = near * (fov_y_radians / 2.0).tan();
+        let x_max = y_max * aspect;
+
+        Self::frustum(y_max, -x_max, x_max, -y_max, near, far)
+    }
+
+    /// Combines a view matrix and a projection matrix into a single view-projection matrix,
+    /// calculated as `projection * view`. Transforming a point by the result first applies the
+    /// view matrix and then the projection matrix, and `transform_point` can be used to get the
+    /// normalized device coordinates of a point after the perspective divide.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gamemath::{Mat4d, Vec3};
+    ///
+    /// let view = Mat4d::identity().translated(Vec3::new(0.0, 0.0, -5.0));
+    /// let projection = Mat4d::perspective(90.0, 1.0, 1.0, 100.0);
+    /// let m = Mat4d::view_projection(view, projection);
+    ///
+    /// assert_eq!(m, projection * view);
+    ///
+    /// let ndc = m.transform_point(Vec3::new(2.5, -2.5, 0.0));
+    ///
+    /// assert!((ndc - Vec3::new(0.5, -0.5, 305.0 / 495.0)).length() < 0.000001);
+    /// ```
+    pub fn view_projection(view: Mat4d, projection: Mat4d) -> Mat4d {
+        projection * view
+    }
+
+    /// Constructs a 4x4 perspective-projection matrix from a fov, aspect, near and far value,
+    /// for the clip space used by Vulkan and wgpu.
+    ///
+    /// Unlike `perspective`, which follows the OpenGL convention of mapping depth to `-1..1` with
+    /// Y pointing up, the resulting matrix maps depth to `0..1` and flips the Y axis so that it
+    /// points down in normalized device coordinates.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gamemath::{Mat4d, Vec3};
+    ///
+    /// let m = Mat4d::perspective_vulkan(55.0, 1920.0 / 1080.0, 0.1, 100.0);
+    ///
+    /// let near = m.transform_point(Vec3::new(0.0, 0.0, -0.1));
+    /// let far = m.transform_point(Vec3::new(0.0, 0.0, -100.0));
+    /// let above = m.transform_point(Vec3::new(0.0, 1.0, -10.0));
+    ///
+    /// assert!(near.z.abs() < 0.000001);
+    /// assert!((far.z - 1.0).abs() < 0.000001);
+    /// assert!(above.y < 0.0);
+    /// ```
+    pub fn perspective_vulkan(fov: f64, aspect: f64, near: f64, far: f64) -> Mat4d {
+        let mut result: Mat4d = 0.0.into();
+
+        let focal_length = 1.0 / (fov * (PI / 360.0)).tan();
+        let near_to_far = 1.0 / (near - far);
+
+        result[0][0] = focal_length / aspect;
+        result[1][1] = -focal_length;
+        result[2][2] = far * near_to_far;
+        result[2][3] = -1.0;
+        result[3][2] = near * far * near_to_far;
+
+        result
+    }
+
+    /// Constructs a 4x4 camera matrix from a eye, target and up `Vec3<f64>`.
+    /// The resulting matrix will be "positioned" at the coordinates of the eye vector, loking
+    /// in the direction of the coordinates of the target vecctor and with its up direction in the
+    /// direction of the up vector. This is the world transform of the camera, which moves the
+    /// origin to the eye, so the view-matrix is its inverse.
+    ///
+    /// This follows the right-handed convention and is the same as `look_at_rh`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gamemath::{Mat4d, Vec3, Vec4};
+    ///
+    /// let m = Mat4d::look_at(Vec3::new(0.0, 0.0, 1.0),
+    ///                        Vec3::new(0.0, 0.0, 0.0),
+    ///                        Vec3::new(0.0, -1.0, 0.0));
+    ///
+    /// assert_eq!(m[0], Vec4::new(-1.0, 0.0, 0.0, 0.0));
+    /// assert_eq!(m[1], Vec4::new(0.0, -1.0, 0.0, 0.0));
+    /// assert_eq!(m[2], Vec4::new(0.0, 0.0, 1.0, 0.0));
+    /// assert_eq!(m[3], Vec4::new(0.0, 0.0, 1.0, 1.0));
+    /// assert_eq!(m.transform_point(Vec3::new(0.0, 0.0, 0.0)), Vec3::new(0.0, 0.0, 1.0));
+    ///
+    /// let view = m.inverted();
+    ///
+    /// assert_eq!(view.transform_point(Vec3::new(0.0, 0.0, 1.0)), Vec3::new(0.0, 0.0, 0.0));
+    ///
+    /// // Far away from the origin, small offsets are still represented exactly.
+    /// let eye = Vec3::new(1.0e9, 2.0e9, 1.0);
+    /// let m = Mat4d::look_at(eye, eye - Vec3::new(0.0, 0.0, 1.0), Vec3::new(0.0, 1.0, 0.0));
+    ///
+    /// assert_eq!(m.transform_point(Vec3::new(0.5, 0.25, 0.0)),
+    ///            Vec3::new(1.0e9 + 0.5, 2.0e9 + 0.25, 1.0));
+    /// ```
+    pub fn look_at(eye: Vec3<f64>, target: Vec3<f64>, up: Vec3<f64>) -> Mat4d {
+        Self::look_at_rh(eye, target, up)
+    }
+
+    /// Constructs a 4x4 camera matrix from a eye, target and up `Vec3<f64>`, following the
+    /// right-handed convention where the camera looks down its negative z axis. The z axis of the
+    /// resulting matrix points from the target towards the eye. Like `look_at`, this is the world
+    /// transform of the camera, and its inverse is the view-matrix.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gamemath::{Mat4d, Vec3, Vec4};
+    ///
+    /// let eye = Vec3::new(0.0, 0.0, 1.0);
+    /// let target = Vec3::new(0.0, 0.0, 0.0);
+    /// let up = Vec3::new(0.0, 1.0, 0.0);
+    ///
+    /// let rh = Mat4d::look_at_rh(eye, target, up);
+    /// let lh = Mat4d::look_at_lh(eye, target, up);
+    ///
+    /// assert_eq!(rh, Mat4d::look_at(eye, target, up));
+    /// assert_eq!(rh[2], Vec4::new(0.0, 0.0, 1.0, 0.0));
+    /// assert_eq!(rh.get_forward_vector(), -lh.get_forward_vector());
+    /// ```
+    pub fn look_at_rh(eye: Vec3<f64>, target: Vec3<f64>, up: Vec3<f64>) -> Mat4d {
+        let forward = (eye - target).normalized();
+        let right = up.normalized().cross(forward).normalized();
+        let up = forward.cross(right).normalized();
+
+        (
+            (right.x, right.y, right.z, 0.0),
+            (up.x, up.y, up.z, 0.0),
+            (forward.x, forward.y, forward.z, 0.0),
+            (eye.x, eye.y, eye.z, 1.0),
+        )
+            .into()
+    }
+
+    /// Constructs a 4x4 camera matrix from a eye, target and up `Vec3<f64>`, following the
+    /// left-handed convention where the camera looks down its positive z axis. The z axis of the
+    /// resulting matrix points from the eye towards the target. Like `look_at`, this is the world
+    /// transform of the camera, and its inverse is the view-matrix.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gamemath::{Mat4d, Vec3, Vec4};
+    ///
+    /// let eye = Vec3::new(0.0, 0.0, 1.0);
+    /// let target = Vec3::new(0.0, 0.0, 0.0);
+    /// let up = Vec3::new(0.0, 1.0, 0.0);
+    ///
+    /// let m = Mat4d::look_at_lh(eye, target, up);
+    ///
+    /// assert_eq!(m[2], Vec4::new(0.0, 0.0, -1.0, 0.0));
+    /// assert_eq!(m[2], -Mat4d::look_at_rh(eye, target, up)[2]);
+    /// assert_eq!(m[3], Vec4::new(0.0, 0.0, 1.0, 1.0));
+    /// ```
+    pub fn look_at_lh(eye: Vec3<f64>, target: Vec3<f64>, up: Vec3<f64>) -> Mat4d {
+        let forward = (target - eye).normalized();
+        let right = up.normalized().cross(forward).normalized();
+        let up = forward.cross(right).normalized();
+
+        (
+            (right.x, right.y, right.z, 0.0),
+            (up.x, up.y, up.z, 0.0),
+            (forward.x, forward.y, forward.z, 0.0),
+            (eye.x, eye.y, eye.z, 1.0),
+        )
+            .into()
+    }
+
+    /// Constructs a 4x4 billboard matrix placed at a position and oriented so that its z axis,
+    /// the forward vector of the quad, points from the position towards a camera position, while
+    /// keeping its y axis as close to an up `Vec3<f64>` as possible. Useful for keeping sprites and
+    /// particles facing the camera.
+    ///
+    /// When the direction towards the camera is parallel to the up vector, an arbitrary right
+    /// vector perpendicular to the direction is picked instead. When the position and the camera
+    /// position coincide, the billboard faces the positive z axis.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gamemath::{Mat4d, Vec3, Vec4};
+    ///
+    /// let position = Vec3::new(1.0, 2.0, 3.0);
+    /// let camera = Vec3::new(4.0, 2.0, 7.0);
+    /// let up = Vec3::new(0.0, 1.0, 0.0);
+    ///
+    /// let m = Mat4d::billboard(position, camera, up);
+    ///
+    /// assert_eq!(m[2].xyz(), (camera - position).normalized());
+    /// assert_eq!(m[1].xyz(), up);
+    /// assert_eq!(m[3], Vec4::new(1.0, 2.0, 3.0, 1.0));
+    ///
+    /// let m = Mat4d::billboard(position, Vec3::new(1.0, 10.0, 3.0), up);
+    ///
+    /// assert_eq!(m[2].xyz(), up);
+    /// assert!(m[0].xyz().dot(up).abs() < 0.000001);
+    /// assert!((m.determinant() - 1.0).abs() < 0.000001);
+    /// ```
+    pub fn billboard(position: Vec3<f64>, camera_pos: Vec3<f64>, up: Vec3<f64>) -> Mat4d {
+        let to_camera = camera_pos - position;
+        let forward = if to_camera.length_squared() > f64::EPSILON {
+            to_camera.normalized()
+        } else {
+            Vec3::new(0.0, 0.0, 1.0)
+        };
+        let right = up.cross(forward);
+        let right = if right.length_squared() > f64::EPSILON {
+            right.normalized()
+        } else {
+            forward.orthonormal_basis().0
+        };
+        let up = forward.cross(right);
+
+        (
+            (right.x, right.y, right.z, 0.0),
+            (up.x, up.y, up.z, 0.0),
+            (forward.x, forward.y, forward.z, 0.0),
+            (position.x, position.y, position.z, 1.0),
+        )
+            .into()
+    }
+
+    /// Constructs a 4x4 perspective-orthogonal matrix from a top, left, right, bottom, near and far value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gamemath::{Mat4d, Vec4};
+    ///
+    /// let m = Mat4d::orthogonal(-1.0, -1.0, 1.0, 1.0, 0.01, 100.0);
+    ///
+    /// assert_eq!(m[0], Vec4::new(1.0, 0.0, 0.0, 0.0));
+    /// assert_eq!(m[1], Vec4::new(0.0, -1.0, 0.0, 0.0));
+    /// assert_eq!(m[2], Vec4::new(0.0, 0.0, -0.020002000200020003, 0.0));
+    /// assert_eq!(m[3], Vec4::new(0.0, 0.0, -1.0002000200020003, 1.0));
+    /// ```
+    pub fn orthogonal(top: f64, left: f64, right: f64, bottom: f64, near: f64, far: f64) -> Mat4d {
+        let mut result: Mat4d = 0.0.into();
+
+        let left_to_right = 1.0 / (left - right);
+        let bottom_to_top = 1.0 / (bottom - top);
+        let near_to_far = 1.0 / (near - far);
+
+        result[0][0] = -2.0 * left_to_right;
+        result[1][1] = -2.0 * bottom_to_top;
+        result[2][2] = 2.0 * near_to_far;
+
+        result[3][0] = left_to_right * (left + right);
+        result[3][1] = bottom_to_top * (top + bottom);
+        result[3][2] = near_to_far * (near + far);
+        result[3][3] = 1.0;
+
+        result
+    }
+
+    /// Constructs a 4x4 orthogonal-projection matrix from a top, left, right, bottom, near and far
+    /// value, for the clip space used by Vulkan and wgpu.
+    ///
+    /// The X and Y axes are mapped exactly like `orthogonal`, but depth is mapped to `0..1`
+    /// instead of the `-1..1` range used by OpenGL.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gamemath::{Mat4d, Vec3};
+    ///
+    /// let m = Mat4d::orthogonal_vulkan(0.0, 0.0, 1920.0, 1080.0, 0.1, 100.0);
+    /// let gl = Mat4d::orthogonal(0.0, 0.0, 1920.0, 1080.0, 0.1, 100.0);
+    ///
+    /// let near = m.transform_point(Vec3::new(480.0, 270.0, -0.1));
+    /// let far = m.transform_point(Vec3::new(480.0, 270.0, -100.0));
+    /// let reference = gl.transform_point(Vec3::new(480.0, 270.0, -0.1));
+    ///
+    /// assert!(near.z.abs() < 0.000001);
+    /// assert!((far.z - 1.0).abs() < 0.000001);
+    /// assert_eq!((near.x, near.y), (reference.x, reference.y));
+    /// assert_eq!((far.x, far.y), (reference.x, reference.y));
+    /// ```
+    pub fn orthogonal_vulkan(
+        top: f64,
+        left: f64,
+        right: f64,
+        bottom: f64,
+        near: f64,
+        far: f64,
+    ) -> Mat4d {
+        let mut result = Self::orthogonal(top, left, right, bottom, near, far);
+
+        let near_to_far = 1.0 / (near - far);
+
+        result[2][2] = near_to_far;
+        result[3][2] = near * near_to_far;
+
+        result
+    }
+
+    /// Extracts and returns a `Vec3<f64>` pointing left, away from the position of a view-matrix.
+    /// Pretty much only makes sense for a view-matrix.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gamemath::{Mat4d, Vec3};
+    ///
+    /// let m = Mat4d::look_at(Vec3::new(0.0, 0.0, 1.0),
+    ///                        Vec3::new(0.0, 0.0, 0.0),
+    ///                        Vec3::new(0.0, -1.0, 0.0));
+    ///
+    /// assert_eq!(m.get_left_vector(), Vec3::new(-1.0, 0.0, 0.0));
+    /// ```
+    pub fn get_left_vector(&self) -> Vec3<f64> {
+        (self[0][0], self[1][0], self[2][0]).into()
+    }
+
+    /// Extracts and returns a `Vec3<f64>` pointing right, away from the position of a view-matrix.
+    /// Pretty much only makes sense for a view-matrix.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gamemath::{Mat4d, Vec3};
+    ///
+    /// let m = Mat4d::look_at(Vec3::new(0.0, 0.0, 1.0),
+    ///                        Vec3::new(0.0, 0.0, 0.0),
+    ///                        Vec3::new(0.0, -1.0, 0.0));
+    ///
+    /// assert_eq!(m.get_right_vector(), Vec3::new(1.0, 0.0, 0.0));
+    /// ```
+    pub fn get_right_vector(&self) -> Vec3<f64> {
+        -self.get_left_vector()
+    }
+
+    /// Extracts and returns a `Vec3<f64>` pointing up, away from the position of a view-matrix.
+    /// Pretty much only makes sense for a view-matrix.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gamemath::{Mat4d, Vec3};
+    ///
+    /// let m = Mat4d::look_at(Vec3::new(0.0, 0.0, 1.0),
+    ///                        Vec3::new(0.0, 0.0, 0.0),
+    ///                        Vec3::new(0.0, -1.0, 0.0));
+    ///
+    /// assert_eq!(m.get_up_vector(), Vec3::new(0.0, -1.0, 0.0));
+    /// ```
+    pub fn get_up_vector(&self) -> Vec3<f64> {
+        (self[0][1], self[1][1], self[2][1]).into()
+    }
+
+    /// Extracts and returns a `Vec3<f64>` pointing down, away from the position of a view-matrix.
+    /// Pretty much only makes sense for a view-matrix.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gamemath::{Mat4d, Vec3};
+    ///
+    /// let m = Mat4d::look_at(Vec3::new(0.0, 0.0, 1.0),
+    ///                        Vec3::new(0.0, 0.0, 0.0),
+    ///                        Vec3::new(0.0, -1.0, 0.0));
+    ///
+    /// assert_eq!(m.get_down_vector(), Vec3::new(0.0, 1.0, 0.0));
+    /// ```
+    pub fn get_down_vector(&self) -> Vec3<f64> {
+        -self.get_up_vector()
+    }
+
+    /// Extracts and returns a `Vec3<f64>` pointing backwards, away from the position of a view-matrix.
+    /// Pretty much only makes sense for a view-matrix.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gamemath::{Mat4d, Vec3};
+    ///
+    /// let m = Mat4d::look_at(Vec3::new(0.0, 0.0, 1.0),
+    ///                        Vec3::new(0.0, 0.0, 0.0),
+    ///                        Vec3::new(0.0, -1.0, 0.0));
+    ///
+    /// assert_eq!(m.get_backward_vector(), Vec3::new(0.0, 0.0, 1.0));
+    /// ```
+    pub fn get_backward_vector(&self) -> Vec3<f64> {
+        (self[0][2], self[1][2], self[2][2]).into()
+    }
+
+    /// Extracts and returns a `Vec3<f64>` pointing forwards, away from the position of a view-matrix.
+    /// Pretty much only makes sense for a view-matrix.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gamemath::{Mat4d, Vec3};
+    ///
+    /// let m = Mat4d::look_at(Vec3::new(0.0, 0.0, 1.0),
+    ///                        Vec3::new(0.0, 0.0, 0.0),
+    ///                        Vec3::new(0.0, -1.0, 0.0));
+    ///
+    /// assert_eq!(m.get_forward_vector(), Vec3::new(0.0, 0.0, -1.0));
+    /// ```
+    pub fn get_forward_vector(&self) -> Vec3<f64> {
+        -self.get_backward_vector()
+    }
+
+    /// Extracts and returns a transposed representation of the calling `Mat4d` object.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gamemath::Mat4d;
+    ///
+    /// let m: Mat4d = (( 0.0,  1.0,  2.0,  3.0),
+    ///                 ( 4.0,  5.0,  6.0,  7.0),
+    ///                 ( 8.0,  9.0, 10.0, 11.0),
+    ///                 (12.0, 13.0, 14.0, 15.0)).into();
+    ///
+    /// assert_eq!(m.transposed(), (( 0.0,  4.0,  8.0, 12.0),
+    ///                             ( 1.0,  5.0,  9.0, 13.0),
+    ///                             ( 2.0,  6.0, 10.0, 14.0),
+    ///                             ( 3.0,  7.0, 11.0, 15.0)).into());
+    /// ```
+    pub fn transposed(&self) -> Mat4d {
+        (
+            (self[0][0], self[1][0], self[2][0], self[3][0]),
+            (self[0][1], self[1][1], self[2][1], self[3][1]),
+            (self[0][2], self[1][2], self[2][2], self[3][2]),
+            (self[0][3], self[1][3], self[2][3], self[3][3]),
+        )
+            .into()
+    }
+
+    /// Performs a transpose operation on the calling `Mat4d` object.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gamemath::Mat4d;
+    ///
+    /// let mut m: Mat4d = (( 0.0,  1.0,  2.0,  3.0),
+    ///                     ( 4.0,  5.0,  6.0,  7.0),
+    ///                     ( 8.0,  9.0, 10.0, 11.0),
+    ///                     (12.0, 13.0, 14.0, 15.0)).into();
+    ///
+    /// m.transpose();
+    ///
+    /// assert_eq!(m, (( 0.0,  4.0,  8.0, 12.0),
+    ///                ( 1.0,  5.0,  9.0, 13.0),
+    ///                ( 2.0,  6.0, 10.0, 14.0),
+    ///                ( 3.0,  7.0, 11.0, 15.0)).into());
+    /// ```
+    pub fn transpose(&mut self) {
+        *self = self.transposed();
+    }
+
+    /// Calculates and returns the product of the transpose of the calling `Mat4d` object and
+    /// another `Mat4d`, the same as `self.transposed() * other`, without constructing the
+    /// transposed matrix.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gamemath::{Mat4d, Vec3};
+    ///
+    /// let a = Mat4d::rotation(0.5, Vec3::new(1.0, 2.0, 3.0)).translated(Vec3::new(4.0, 5.0, 6.0));
+    /// let b = Mat4d::identity().scaled(Vec3::new(1.0, -2.0, 3.0));
+    ///
+    /// assert!(a.mul_transpose(b).approx_eq(a.transposed() * b, 0.00001));
+    /// ```
+    pub fn mul_transpose(&self, other: Mat4d) -> Mat4d {
+        let mut result: Mat4d = 0.0.into();
+
+        for i in 0..4 {
+            for j in 0..4 {
+                result[i][j] = self[j].dot(other[i]);
+            }
+        }
+
+        result
+    }
+
+    /// Calculates and returns the Gram matrix of the calling `Mat4d` object, which is the
+    /// product of its transpose and itself. The result is always symmetric.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gamemath::Mat4d;
+    ///
+    /// let m: Mat4d = (( 0.5, -1.0,  2.0,  3.5),
+    ///                ( 4.0,  0.25, 6.0, -7.0),
+    ///                (-8.0,  9.0,  1.5, 11.0),
+    ///                (12.0, 13.0, -14.0, 0.75)).into();
+    /// let gram = m.gram();
+    ///
+    /// assert_eq!(gram, gram.transposed());
+    /// assert!(gram.approx_eq(m.transposed() * m, 0.0001));
+    /// ```
+    pub fn gram(&self) -> Mat4d {
+        self.mul_transpose(*self)
+    }
+
+    /// calculates and returns the determinant value of the calling `Mat4d` object.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gamemath::{Mat4d, Vec3};
+    ///
+    /// let m = Mat4d::look_at(Vec3::new(0.0, 0.0, 1.0),
+    ///                        Vec3::new(0.0, 0.0, 0.0),
+    ///                        Vec3::new(0.0, -1.0, 0.0));
+    ///
+    /// assert_eq!(m.determinant(), 1.0);
+    /// ```
+    pub fn determinant(&self) -> f64 {
+        self[3][0] * self[2][1] * self[1][2] * self[0][3]
+            - self[2][0] * self[3][1] * self[1][2] * self[0][3]
+            - self[3][0] * self[1][1] * self[2][2] * self[0][3]
+            + self[1][0] * self[3][1] * self[2][2] * self[0][3]
+            + self[2][0] * self[1][1] * self[3][2] * self[0][3]
+            - self[1][0] * self[2][1] * self[3][2] * self[0][3]
+            - self[3][0] * self[2][1] * self[0][2] * self[1][3]
+            + self[2][0] * self[3][1] * self[0][2] * self[1][3]
+            + self[3][0] * self[0][1] * self[2][2] * self[1][3]
+            - self[0][0] * self[3][1] * self[2][2] * self[1][3]
+            - self[2][0] * self[0][1] * self[3][2] * self[1][3]
+            + self[0][0] * self[2][1] * self[3][2] * self[1][3]
+            + self[3][0] * self[1][1] * self[0][2] * self[2][3]
+            - self[1][0] * self[3][1] * self[0][2] * self[2][3]
+            - self[3][0] * self[0][1] * self[1][2] * self[2][3]
+            + self[0][0] * self[3][1] * self[1][2] * self[2][3]
+            + self[1][0] * self[0][1] * self[3][2] * self[2][3]
+            - self[0][0] * self[1][1] * self[3][2] * self[2][3]
+            - self[2][0] * self[1][1] * self[0][2] * self[3][3]
+            + self[1][0] * self[2][1] * self[0][2] * self[3][3]
+            + self[2][0] * self[0][1] * self[1][2] * self[3][3]
+            - self[0][0] * self[2][1] * self[1][2] * self[3][3]
+            - self[1][0] * self[0][1] * self[2][2] * self[3][3]
+            + self[0][0] * self[1][1] * self[2][2] * self[3][3]
+    }
+
+    /// calculates and returns the adjoint matrix of the calling `Mat4d` object.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gamemath::Mat4d;
+    ///
+    /// let m: Mat4d = (( 4.0, 15.0,  2.0, 13.0),
+    ///                 ( 5.0, 10.0,  7.0, 12.0),
+    ///                 ( 9.0,  6.0, 11.0,  8.0),
+    ///                 (16.0,  3.0, 14.0,  1.0)).into();
+    ///
+    /// assert_eq!(m.adjointed(), ((-272.0,  816.0, -816.0,  272.0),
+    ///                            ( 272.0, -816.0,  816.0, -272.0),
+    ///                            ( 272.0, -816.0,  816.0, -272.0),
+    ///                            (-272.0,  816.0, -816.0,  272.0)).into());
+    /// ```
+    pub fn adjointed(&self) -> Mat4d {
+        let mut result: Mat4d = 0.0.into();
+
+        result[0][0] = self[1][1] * (self[2][2] * self[3][3] - self[2][3] * self[3][2])
+            - self[2][1] * (self[1][2] * self[3][3] - self[1][3] * self[3][2])
+            + self[3][1] * (self[1][2] * self[2][3] - self[1][3] * self[2][2]);
+        result[0][1] = -(self[0][1] * (self[2][2] * self[3][3] - self[2][3] * self[3][2])
+            - self[2][1] * (self[0][2] * self[3][3] - self[0][3] * self[3][2])
+            + self[3][1] * (self[0][2] * self[2][3] - self[0][3] * self[2][2]));
+        result[0][2] = self[0][1] * (self[1][2] * self[3][3] - self[1][3] * self[3][2])
+            - self[1][1] * (self[0][2] * self[3][3] - self[0][3] * self[3][2])
+            + self[3][1] * (self[0][2] * self[1][3] - self[0][3] * self[1][2]);
+        result[0][3] = -(self[0][1] * (self[1][2] * self[2][3] - self[1][3] * self[2][2])
+            - self[1][1] * (self[0][2] * self[2][3] - self[0][3] * self[2][2])
+            + self[2][1] * (self[0][2] * self[1][3] - self[0][3] * self[1][2]));
+        result[1][0] = -(self[1][0] * (self[2][2] * self[3][3] - self[2][3] * self[3][2])
+            - self[2][0] * (self[1][2] * self[3][3] - self[1][3] * self[3][2])
+            + self[3][0] * (self[1][2] * self[2][3] - self[1][3] * self[2][2]));
+        result[1][1] = self[0][0] * (self[2][2] * self[3][3] - self[2][3] * self[3][2])
+            - self[2][0] * (self[0][2] * self[3][3] - self[0][3] * self[3][2])
+            + self[3][0] * (self[0][2] * self[2][3] - self[0][3] * self[2][2]);
+        result[1][2] = -(self[0][0] * (self[1][2] * self[3][3] - self[1][3] * self[3][2])
+            - self[1][0] * (self[0][2] * self[3][3] - self[0][3] * self[3][2])
+            + self[3][0] * (self[0][2] * self[1][3] - self[0][3] * self[1][2]));
+        result[1][3] = self[0][0] * (self[1][2] * self[2][3] - self[1][3] * self[2][2])
+            - self[1][0] * (self[0][2] * self[2][3] - self[0][3] * self[2][2])
+            + self[2][0] * (self[0][2] * self[1][3] - self[0][3] * self[1][2]);
+        result[2][0] = self[1][0] * (self[2][1] * self[3][3] - self[2][3] * self[3][1])
+            - self[2][0] * (self[1][1] * self[3][3] - self[1][3] * self[3][1])
+            + self[3][0] * (self[1][1] * self[2][3] - self[1][3] * self[2][1]);
+        result[2][1] = -(self[0][0] * (self[2][1] * self[3][3] - self[2][3] * self[3][1])
+            - self[2][0] * (self[0][1] * self[3][3] - self[0][3] * self[3][1])
+            + self[3][0] * (self[0][1] * self[2][3] - self[0][3] * self[2][1]));
+        result[2][2] = self[0][0] * (self[1][1] * self[3][3] - self[1][3] * self[3][1])
+            - self[1][0] * (self[0][1] * self[3][3] - self[0][3] * self[3][1])
+            + self[3][0] * (self[0][1] * self[1][3] - self[0][3] * self[1][1]);
+        result[2][3] = -(self[0][0] * (self[1][1] * self[2][3] - self[1][3] * self[2][1])
+            - self[1][0] * (self[0][1] * self[2][3] - self[0][3] * self[2][1])
+            + self[2][0] * (self[0][1] * self[1][3] - self[0][3] * self[1][1]));
+        result[3][0] = -(self[1][0] * (self[2][1] * self[3][2] - self[2][2] * self[3][1])
+            - self[2][0] * (self[1][1] * self[3][2] - self[1][2] * self[3][1])
+            + self[3][0] * (self[1][1] * self[2][2] - self[1][2] * self[2][1]));
+        result[3][1] = self[0][0] * (self[2][1] * self[3][2] - self[2][2] * self[3][1])
+            - self[2][0] * (self[0][1] * self[3][2] - self[0][2] * self[3][1])
+            + self[3][0] * (self[0][1] * self[2][2] - self[0][2] * self[2][1]);
+        result[3][2] = -(self[0][0] * (self[1][1] * self[3][2] - self[1][2] * self[3][1])
+            - self[1][0] * (self[0][1] * self[3][2] - self[0][2] * self[3][1])
+            + self[3][0] * (self[0][1] * self[1][2] - self[0][2] * self[1][1]));
+        result[3][3] = self[0][0] * (self[1][1] * self[2][2] - self[1][2] * self[2][1])
+            - self[1][0] * (self[0][1] * self[2][2] - self[0][2] * self[2][1])
+            + self[2][0] * (self[0][1] * self[1][2] - self[0][2] * self[1][1]);
+
+        result
+    }
+
+    /// calculates and returns the inverted matrix of the calling `Mat4d` object. A zero matrix is
+    /// returned if the calling matrix can not be inverted.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gamemath::{Mat4d, Vec3};
+    ///
+    /// let m = Mat4d::look_at(Vec3::new(0.0, 0.0, 1.0),
+    ///                        Vec3::new(0.0, 0.0, 0.0),
+    ///                        Vec3::new(0.0, -1.0, 0.0));
+    ///
+    /// assert_eq!(m.inverted(), ((-1.0,  0.0,  0.0,  0.0),
+    ///                           ( 0.0, -1.0,  0.0,  0.0),
+    ///                           ( 0.0,  0.0,  1.0,  0.0),
+    ///                           ( 0.0,  0.0, -1.0,  1.0)).into());
+    ///
+    /// let reflection = Mat4d::identity().scaled(Vec3::new(-1.0, 1.0, 1.0));
+    ///
+    /// assert_eq!(reflection.inverted(), reflection);
+    /// assert_eq!(reflection.inverted().transposed(), reflection.inverse_transpose());
+    /// ```
+    pub fn inverted(&self) -> Mat4d {
+        let determinant = self.determinant();
+
+        if determinant != 0.0 {
+            let mut result: Mat4d = 0.0.into();
+            let adjoint = self.adjointed();
+
+            result[0][0] = adjoint[0][0] / determinant;
+            result[0][1] = adjoint[0][1] / determinant;
+            result[0][2] = adjoint[0][2] / determinant;
+            result[0][3] = adjoint[0][3] / determinant;
+
+            result[1][0] = adjoint[1][0] / determinant;
+            result[1][1] = adjoint[1][1] / determinant;
+            result[1][2] = adjoint[1][2] / determinant;
+            result[1][3] = adjoint[1][3] / determinant;
+
+            result[2][0] = adjoint[2][0] / determinant;
+            result[2][1] = adjoint[2][1] / determinant;
+            result[2][2] = adjoint[2][2] / determinant;
+            result[2][3] = adjoint[2][3] / determinant;
+
+            result[3][0] = adjoint[3][0] / determinant;
+            result[3][1] = adjoint[3][1] / determinant;
+            result[3][2] = adjoint[3][2] / determinant;
+            result[3][3] = adjoint[3][3] / determinant;
+
+            result
+        } else {
+            0.0.into()
+        }
+    }
+
+    /// Performes the inversion operation on the calling `Mat4d` object.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gamemath::{Mat4d, Vec3};
+    ///
+    /// let mut m = Mat4d::look_at(Vec3::new(0.0, 0.0, 1.0),
+    ///                            Vec3::new(0.0, 0.0, 0.0),
+    ///                            Vec3::new(0.0, -1.0, 0.0));
+    ///
+    /// m.invert();
+    ///
+    /// assert_eq!(m, ((-1.0,  0.0,  0.0,  0.0),
+    ///                ( 0.0, -1.0,  0.0,  0.0),
+    ///                ( 0.0,  0.0,  1.0,  0.0),
+    ///                ( 0.0,  0.0, -1.0,  1.0)).into());
+    /// ```
+    pub fn invert(&mut self) {
+        *self = self.inverted();
+    }
+
+    /// Calculates and returns the transpose of the inverted matrix of the calling `Mat4d` object,
+    /// which is used for transforming normals. A zero matrix is returned if the calling matrix
+    /// can not be inverted.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gamemath::{Mat4d, Vec3};
+    ///
+    /// let m = Mat4d::identity()
+    ///     .translated(Vec3::new(1.0, -2.0, 3.0))
+    ///     .rotated(0.5, Vec3::new(1.0, 1.0, 0.0))
+    ///     .scaled(Vec3::new(1.0, 2.0, 4.0));
+    ///
+    /// assert_eq!(m.inverse_transpose(), m.inverted().transposed());
+    /// assert_eq!(Mat4d::from(0.0).inverse_transpose(), Mat4d::from(0.0));
+    /// ```
+    pub fn inverse_transpose(&self) -> Mat4d {
+        let determinant = self.determinant();
+
+        if determinant != 0.0 {
+            let mut result: Mat4d = 0.0.into();
+            let adjoint = self.adjointed();
+
+            for i in 0..4 {
+                for j in 0..4 {
+                    result[i][j] = adjoint[j][i] / determinant;
+                }
+            }
+
+            result
+        } else {
+            0.0.into()
+        }
+    }
+
+    /// Calculates and returns the inverted matrix of the calling `Mat4d` object, or `None` if the
+    /// matrix is singular. A matrix counts as singular when its determinant is zero, not finite, or
+    /// negligible next to the product of its row lengths, so uniformly scaled matrices invert
+    /// no matter how small the scale is. Unlike `inverted`, this makes a failed inversion impossible
+    /// to miss.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gamemath::{Mat4d, Vec3};
+    ///
+    /// let m = Mat4d::identity()
+    ///     .translated(Vec3::new(1.0, -2.0, 3.0))
+    ///     .rotated(0.5, Vec3::new(1.0, 1.0, 0.0))
+    ///     .scaled(Vec3::new(1.0, 2.0, 4.0));
+    /// let inverse = m.try_inverse().unwrap();
+    ///
+    /// assert!((m * inverse).approx_eq(Mat4d::identity(), 0.00001));
+    /// assert_eq!(Mat4d::identity().scaled(Vec3::new(1.0, 0.0, 1.0)).try_inverse(), None);
+    ///
+    /// let small = Mat4d::from(0.001_f64);
+    /// let small_inverse = small.try_inverse().unwrap();
+    ///
+    /// assert!((small * small_inverse).approx_eq(Mat4d::identity(), 0.00001));
+    /// ```
+    pub fn try_inverse(&self) -> Option<Mat4d> {
+        let determinant = self.determinant();
+
+        if self.is_singular(determinant) {
+            return None;
+        }
+
+        let mut result: Mat4d = 0.0.into();
+        let adjoint = self.adjointed();
+
+        for i in 0..4 {
+            for j in 0..4 {
+                result[i][j] = adjoint[i][j] / determinant;
+            }
+        }
+
+        Some(result)
+    }
+
+    fn is_singular(&self, determinant: f64) -> bool {
+        let row_lengths = self[0].length() * self[1].length() * self[2].length() * self[3].length();
+
+        !determinant.is_finite() || determinant.abs() <= f64::EPSILON * row_lengths
+    }
+
+    /// Constructs a 4x4 rotation matrix from a radians value and an axis `Vec3<f64>`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gamemath::{Mat4d, Vec3, Vec4};
+    ///
+    /// let m = Mat4d::rotation(1.0, Vec3::new(0.0, 0.0, 1.0));
+    ///
+    /// assert_eq!(m[0], Vec4::new( 0.5403023058681398, 0.8414709848078965, 0.0, 0.0));
+    /// assert_eq!(m[1], Vec4::new(-0.8414709848078965, 0.5403023058681398, 0.0, 0.0));
+    /// assert_eq!(m[2], Vec4::new( 0.0,                0.0,                1.0, 0.0));
+    /// assert_eq!(m[3], Vec4::new( 0.0,                0.0,                0.0, 1.0));
+    /// ```
+    pub fn rotation(radians: f64, axis: Vec3<f64>) -> Mat4d {
+        let sin = radians.sin();
+        let cos = radians.cos();
+        let cos_m1 = 1.0 - cos;
+        let axis = axis.normalized();
+
+        (
+            (
+                axis.x * axis.x * cos_m1 + cos,
+                axis.x * axis.y * cos_m1 + axis.z * sin,
+                axis.x * axis.z * cos_m1 - axis.y * sin,
+                0.0,
+            ),
+            (
+                axis.y * axis.x * cos_m1 - axis.z * sin,
+                axis.y * axis.y * cos_m1 + cos,
+                axis.y * axis.z * cos_m1 + axis.x * sin,
+                0.0,
+            ),
+            (
+                axis.z * axis.x * cos_m1 + axis.y * sin,
+                axis.z * axis.y * cos_m1 - axis.x * sin,
+                axis.z * axis.z * cos_m1 + cos,
+                0.0,
+            ),
+            (0.0, 0.0, 0.0, 1.0),
+        )
+            .into()
+    }
+
+    /// Constructs a 4x4 rotation matrix from a radians value, rotating around the X axis.
+    /// This is equivalent to, but cheaper than, calling `Mat4d::rotation` with a unit X axis.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gamemath::{Mat4d, Vec3};
+    ///
+    /// for &radians in [0.0, 1.0, -2.5, 4.0].iter() {
+    ///     let m = Mat4d::rotation(radians, Vec3::new(1.0, 0.0, 0.0));
+    ///
+    ///     assert!(Mat4d::rotation_x(radians).approx_eq(m, 0.000001));
+    /// }
+    /// ```
+    pub fn rotation_x(radians: f64) -> Mat4d {
+        let sin = radians.sin();
+        let cos = radians.cos();
+
+        (
+            (1.0, 0.0, 0.0, 0.0),
+            (0.0, cos, sin, 0.0),
+            (0.0, -sin, cos, 0.0),
+            (0.0, 0.0, 0.0, 1.0),
+        )
+            .into()
+    }
+
+    /// Constructs a 4x4 rotation matrix from a radians value, rotating around the Y axis.
+    /// This is equivalent to, but cheaper than, calling `Mat4d::rotation` with a unit Y axis.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gamemath::{Mat4d, Vec3};
+    ///
+    /// for &radians in [0.0, 1.0, -2.5, 4.0].iter() {
+    ///     let m = Mat4d::rotation(radians, Vec3::new(0.0, 1.0, 0.0));
+    ///
+    ///     assert!(Mat4d::rotation_y(radians).approx_eq(m, 0.000001));
+    /// }
+    /// ```
+    pub fn rotation_y(radians: f64) -> Mat4d {
+        let sin = radians.sin();
+        let cos = radians.cos();
+
+        (
+            (cos, 0.0, -sin, 0.0),
+            (0.0, 1.0, 0.0, 0.0),
+            (sin, 0.0, cos, 0.0),
+            (0.0, 0.0, 0.0, 1.0),
+        )
+            .into()
+    }
+
+    /// Constructs a 4x4 rotation matrix from a radians value, rotating around the Z axis.
+    /// This is equivalent to, but cheaper than, calling `Mat4d::rotation` with a unit Z axis.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gamemath::{Mat4d, Vec3};
+    ///
+    /// for &radians in [0.0, 1.0, -2.5, 4.0].iter() {
+    ///     let m = Mat4d::rotation(radians, Vec3::new(0.0, 0.0, 1.0));
+    ///
+    ///     assert!(Mat4d::rotation_z(radians).approx_eq(m, 0.000001));
+    /// }
+    /// ```
+    pub fn rotation_z(radians: f64) -> Mat4d {
+        let sin = radians.sin();
+        let cos = radians.cos();
+
+        (
+            (cos, sin, 0.0, 0.0),
+            (-sin, cos, 0.0, 0.0),
+            (0.0, 0.0, 1.0, 0.0),
+            (0.0, 0.0, 0.0, 1.0),
+        )
+            .into()
+    }
+
+    /// Calculates and returns a `Mat4d` object representing the calling `Mat4d` object rotated
+    /// around a `Vec3<f64>` axis, by a radians value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gamemath::{Mat4d, Vec3, Vec4};
+    ///
+    /// let m = Mat4d::identity().rotated(1.0, Vec3::new(0.0, 0.0, 1.0));
+    ///
+    /// assert_eq!(m, (( 0.5403023058681398, 0.8414709848078965, 0.0, 0.0),
+    ///                (-0.8414709848078965, 0.5403023058681398, 0.0, 0.0),
+    ///                ( 0.0,                0.0,                1.0, 0.0),
+    ///                ( 0.0,                0.0,                0.0, 1.0)).into());
+    /// ```
+    pub fn rotated(&self, radians: f64, axis: Vec3<f64>) -> Mat4d {
+        *self * Mat4d::rotation(radians, axis)
+    }
+
+    /// Rotates the calling `Mat4d` object around a `Vec3<f64>` axis, by a radians value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gamemath::{Mat4d, Vec3, Vec4};
+    ///
+    /// let mut m = Mat4d::identity();
+    ///
+    /// m.rotate(1.0, Vec3::new(0.0, 0.0, 1.0));
+    ///
+    /// assert_eq!(m, (( 0.5403023058681398, 0.8414709848078965, 0.0, 0.0),
+    ///                (-0.8414709848078965, 0.5403023058681398, 0.0, 0.0),
+    ///                ( 0.0,                0.0,                1.0, 0.0),
+    ///                ( 0.0,                0.0,                0.0, 1.0)).into());
+    /// ```
+    pub fn rotate(&mut self, radians: f64, axis: Vec3<f64>) {
+        *self = self.rotated(radians, axis);
+    }
+
+    /// Calculates and returns a `Mat4d` object representing the calling `Mat4d` object scaled
+    /// by a `Vec3<f64>`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gamemath::{Mat4d, Vec3};
+    ///
+    /// let m = Mat4d::identity();
+    ///
+    /// assert_eq!(m.scaled(Vec3::new(1.0, 2.0, 3.0)), ((1.0, 0.0, 0.0, 0.0),
+    ///                                                 (0.0, 2.0, 0.0, 0.0),
+    ///                                                 (0.0, 0.0, 3.0, 0.0),
+    ///                                                 (0.0, 0.0, 0.0, 1.0)).into());
+    /// ```
+    pub fn scaled(&self, factor: Vec3<f64>) -> Mat4d {
+        let mut matrix = *self;
+
+        matrix[0] *= factor.x;
+        matrix[1] *= factor.y;
+        matrix[2] *= factor.z;
+
+        matrix
+    }
+
+    /// Performs the scale operation on the calling `Mat4d` object, scaling it by a `Vec3<f64>`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gamemath::{Mat4d, Vec3};
+    ///
+    /// let mut m = Mat4d::identity();
+    ///
+    /// m.scale(Vec3::new(1.0, 2.0, 3.0));
+    ///
+    /// assert_eq!(m, ((1.0, 0.0, 0.0, 0.0),
+    ///                (0.0, 2.0, 0.0, 0.0),
+    ///                (0.0, 0.0, 3.0, 0.0),
+    ///                (0.0, 0.0, 0.0, 1.0)).into());
+    /// ```
+    pub fn scale(&mut self, factor: Vec3<f64>) {
+        *self = self.scaled(factor);
+    }
+
+    /// Calculates and returns a `Mat4d` object representing the calling `Mat4d` object translated
+    /// by a `Vec3<f64>`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gamemath::{Mat4d, Vec3};
+    ///
+    /// let m = Mat4d::identity();
+    ///
+    /// assert_eq!(m.translated(Vec3::new(1.0, 2.0, 3.0)), ((1.0, 0.0, 0.0, 0.0),
+    ///                                                     (0.0, 1.0, 0.0, 0.0),
+    ///                                                     (0.0, 0.0, 1.0, 0.0),
+    ///                                                     (1.0, 2.0, 3.0, 1.0)).into());
+    /// ```
+    pub fn translated(&self, translation: Vec3<f64>) -> Mat4d {
+        let mut result = *self;
+
+        result[3][0] +=
+            self[0][0] * translation.x + self[1][0] * translation.y + self[2][0] * translation.z;
+
+        result[3][1] +=
+            self[0][1] * translation.x + self[1][1] * translation.y + self[2][1] * translation.z;
+
+        result[3][2] +=
+            self[0][2] * translation.x + self[1][2] * translation.y + self[2][2] * translation.z;
+
+        result[3][3] +=
+            self[0][3] * translation.x + self[1][3] * translation.y + self[2][3] * translation.z;
+
+        result
+    }
+
+    /// Performs the translate operation on the calling `Mat4d` object, translating it by a
+    /// `Vec3<f64>`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gamemath::{Mat4d, Vec3};
+    ///
+    /// let mut m = Mat4d::identity();
+    ///
+    /// m.translate(Vec3::new(1.0, 2.0, 3.0));
+    ///
+    /// assert_eq!(m, ((1.0, 0.0, 0.0, 0.0),
+    ///                (0.0, 1.0, 0.0, 0.0),
+    ///                (0.0, 0.0, 1.0, 0.0),
+    ///                (1.0, 2.0, 3.0, 1.0)).into());
+    /// ```
+    pub fn translate(&mut self, translation: Vec3<f64>) {
+        *self = self.translated(translation);
+    }
+
+    /// Constructs a 4x4 transformation matrix from a translation, a rotation and a scale, which
+    /// transforms points by first scaling, then rotating and finally translating them.
+    ///
+    /// Since `translated`, `rotated` and `scaled` each apply their transformation before the
+    /// ones already in the matrix, chaining them in the order translation, rotation and scale
+    /// builds the same matrix.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gamemath::{Mat4d, Quatd, Vec3};
+    ///
+    /// let translation = Vec3::new(1.0, 2.0, 3.0);
+    /// let scale = Vec3::new(2.0, 3.0, 4.0);
+    /// let axis = Vec3::new(1.0, 1.0, 0.0);
+    ///
+    /// let m = Mat4d::from_trs(translation, Quatd::rotation(0.5, axis), scale);
+    /// let chained = Mat4d::identity()
+    ///     .translated(translation)
+    ///     .rotated(0.5, axis)
+    ///     .scaled(scale);
+    ///
+    /// assert!(m.approx_eq(chained, 0.000001));
+    /// assert_eq!(m.transform_point(Vec3::new(0.0, 0.0, 0.0)), translation);
+    /// ```
+    pub fn from_trs(translation: Vec3<f64>, rotation: Quatd, scale: Vec3<f64>) -> Mat4d {
+        let mut result = rotation.extract_matrix().scaled(scale);
+
+        result[3][0] = translation.x;
+        result[3][1] = translation.y;
+        result[3][2] = translation.z;
+
+        result
+    }
+
+    /// Constructs a 4x4 transformation matrix from a translation, a rotation and a scale, the
+    /// same way as `from_trs`. This is the inverse operation of `decompose`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gamemath::{Mat4d, Quatd, Vec3};
+    ///
+    /// let m = Mat4d::identity()
+    ///     .translated(Vec3::new(1.0, -2.0, 3.0))
+    ///     .rotated(0.5, Vec3::new(1.0, 1.0, 0.0))
+    ///     .scaled(Vec3::new(1.0, 2.0, 4.0));
+    /// let (translation, rotation, scale) = m.decompose();
+    ///
+    /// assert!(Mat4d::recompose(translation, rotation, scale).approx_eq(m, 0.00001));
+    /// ```
+    pub fn recompose(translation: Vec3<f64>, rotation: Quatd, scale: Vec3<f64>) -> Mat4d {
+        Mat4d::from_trs(translation, rotation, scale)
+    }
+
+    /// Splits the calling `Mat4d` object into a translation, a rotation and a scale, such that
+    /// `recompose` builds the matrix again. The matrix is expected to be affine, without shear
+    /// and with a non-zero scale along every axis.
+    ///
+    /// A mirroring transformation is returned as a negative scale along the x axis, since a
+    /// rotation can not represent it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gamemath::{Mat4d, Quatd, Vec3};
+    ///
+    /// let rotation = Quatd::rotation(1.0, Vec3::new(0.0, 1.0, 0.0));
+    /// let m = Mat4d::from_trs(Vec3::new(1.0, 2.0, 3.0), rotation, Vec3::new(2.0, 3.0, 4.0));
+    /// let (t, r, s) = m.decompose();
+    ///
+    /// assert_eq!(t, Vec3::new(1.0, 2.0, 3.0));
+    /// assert!(r.approx_eq(rotation, 0.00001));
+    /// assert!((s - Vec3::new(2.0, 3.0, 4.0)).length() < 0.00001);
+    ///
+    /// let (_, _, s) = Mat4d::identity().scaled(Vec3::new(1.0, 1.0, -1.0)).decompose();
+    ///
+    /// assert_eq!(s, Vec3::new(-1.0, 1.0, 1.0));
+    /// ```
+    pub fn decompose(&self) -> (Vec3<f64>, Quatd, Vec3<f64>) {
+        let mut scale = self.extract_scale();
+
+        if self.determinant() < 0.0 {
+            scale.x = -scale.x;
+        }
+
+        let rotation: Mat3d = (
+            self[0].truncate() / scale.x,
+            self[1].truncate() / scale.y,
+            self[2].truncate() / scale.z,
+        )
+            .into();
+
+        let translation = self.extract_translation();
+
+        (translation, Quatd::from_mat3(rotation), scale)
+    }
+
+    /// Extracts and returns the translation of the calling `Mat4d` object, stored in the xyz
+    /// components of its last row.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gamemath::{Mat4d, Vec3};
+    ///
+    /// let m = Mat4d::identity()
+    ///     .translated(Vec3::new(1.0, 2.0, 3.0))
+    ///     .scaled(Vec3::new(2.0, 3.0, 4.0));
+    ///
+    /// assert_eq!(m.extract_translation(), Vec3::new(1.0, 2.0, 3.0));
+    /// ```
+    pub fn extract_translation(&self) -> Vec3<f64> {
+        self[3].truncate()
+    }
+
+    /// Extracts and returns the scale of the calling `Mat4d` object, calculated as the lengths of
+    /// its three basis vectors. A negative scale can not be told apart from a rotation and is
+    /// returned as positive.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gamemath::{Mat4d, Vec3};
+    ///
+    /// let m = Mat4d::identity()
+    ///     .translated(Vec3::new(1.0, 2.0, 3.0))
+    ///     .scaled(Vec3::new(2.0, 3.0, 4.0));
+    ///
+    /// assert_eq!(m.extract_scale(), Vec3::new(2.0, 3.0, 4.0));
+    /// ```
+    pub fn extract_scale(&self) -> Vec3<f64> {
+        Vec3::new(
+            self[0].truncate().length(),
+            self[1].truncate().length(),
+            self[2].truncate().length(),
+        )
+    }
+
+    /// Replaces the translation of the calling `Mat4d` object, leaving its rotation and scale
+    /// untouched.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gamemath::{Mat4d, Vec3};
+    ///
+    /// let mut m = Mat4d::identity()
+    ///     .translated(Vec3::new(1.0, 2.0, 3.0))
+    ///     .rotated(1.0, Vec3::new(0.0, 1.0, 0.0));
+    /// let original = m;
+    ///
+    /// m.set_translation(Vec3::new(-4.0, 5.0, 6.0));
+    ///
+    /// assert_eq!(m.extract_translation(), Vec3::new(-4.0, 5.0, 6.0));
+    /// assert_eq!(m[0], original[0]);
+    /// assert_eq!(m[1], original[1]);
+    /// assert_eq!(m[2], original[2]);
+    /// ```
+    pub fn set_translation(&mut self, t: Vec3<f64>) {
+        self[3].x = t.x;
+        self[3].y = t.y;
+        self[3].z = t.z;
+    }
+
+    /// Replaces the scale of the calling `Mat4d` object by rescaling its three basis vectors to the
+    /// lengths given by a `Vec3<f64>`, leaving its rotation and translation untouched. A basis
+    /// vector of zero length has no direction left to keep, and is replaced by the matching axis of
+    /// the identity matrix before being scaled.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gamemath::{Mat4d, Vec3};
+    ///
+    /// let mut m = Mat4d::identity()
+    ///     .translated(Vec3::new(1.0, 2.0, 3.0))
+    ///     .rotated(1.0, Vec3::new(0.0, 1.0, 0.0))
+    ///     .scaled(Vec3::new(2.0, 2.0, 2.0));
+    ///
+    /// m.set_scale(Vec3::new(1.0, 3.0, 0.5));
+    ///
+    /// let expected = Mat4d::identity()
+    ///     .translated(Vec3::new(1.0, 2.0, 3.0))
+    ///     .rotated(1.0, Vec3::new(0.0, 1.0, 0.0))
+    ///     .scaled(Vec3::new(1.0, 3.0, 0.5));
+    ///
+    /// for i in 0..4 {
+    ///     assert!((m[i] - expected[i]).length() < 0.000001);
+    /// }
+    ///
+    /// let mut m = Mat4d::identity().scaled(Vec3::new(0.0, 1.0, 1.0));
+    ///
+    /// m.set_scale(Vec3::new(2.0, 2.0, 2.0));
+    ///
+    /// assert_eq!(m, Mat4d::identity().scaled(Vec3::new(2.0, 2.0, 2.0)));
+    /// ```
+    pub fn set_scale(&mut self, s: Vec3<f64>) {
+        for (i, scale) in [s.x, s.y, s.z].iter().enumerate() {
+            let basis = self[i].truncate();
+            let length = basis.length();
+            let direction = if length > 0.0 {
+                basis / length
+            } else {
+                let mut axis = Vec3::default();
+                axis[i] = 1.0;
+                axis
+            };
+
+            self[i] = (direction * *scale).extend(self[i].w);
+        }
+    }
+
+    /// Constructs a 4x4 shearing matrix from six shear factors. Each factor is named after the
+    /// axis it skews followed by the axis it skews by, so a point `p` is transformed into
+    /// `(p.x + xy * p.y + xz * p.z, p.y + yx * p.x + yz * p.z, p.z + zx * p.x + zy * p.y)`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gamemath::{Mat4d, Vec3};
+    ///
+    /// let m = Mat4d::shearing(1.0, 0.0, 0.0, 0.0, 0.0, 0.5);
+    ///
+    /// assert_eq!(m.transform_point(Vec3::new(1.0, 2.0, 3.0)), Vec3::new(3.0, 2.0, 4.0));
+    /// assert_eq!(Mat4d::shearing(0.0, 0.0, 0.0, 0.0, 0.0, 0.0), Mat4d::identity());
+    /// ```
+    pub fn shearing(xy: f64, xz: f64, yx: f64, yz: f64, zx: f64, zy: f64) -> Mat4d {
+        (
+            (1.0, yx, zx, 0.0),
+            (xy, 1.0, zy, 0.0),
+            (xz, yz, 1.0, 0.0),
+            (0.0, 0.0, 0.0, 1.0),
+        )
+            .into()
+    }
+
+    /// Calculates and returns a `Mat4d` object representing the calling `Mat4d` object sheared by
+    /// six shear factors, as described by `Mat4d::shearing`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gamemath::{Mat4d, Vec3};
+    ///
+    /// let m = Mat4d::identity()
+    ///     .translated(Vec3::new(1.0, 0.0, 0.0))
+    ///     .sheared(0.0, 0.0, 2.0, 0.0, 0.0, 0.0);
+    ///
+    /// assert_eq!(m.transform_point(Vec3::new(1.0, 1.0, 1.0)), Vec3::new(2.0, 3.0, 1.0));
+    /// ```
+    pub fn sheared(&self, xy: f64, xz: f64, yx: f64, yz: f64, zx: f64, zy: f64) -> Mat4d {
+        *self * Mat4d::shearing(xy, xz, yx, yz, zx, zy)
+    }
+
+    /// Performs the shear operation on the calling `Mat4d` object, shearing it by six shear
+    /// factors, as described by `Mat4d::shearing`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gamemath::{Mat4d, Vec3};
+    ///
+    /// let mut m = Mat4d::identity();
+    ///
+    /// m.shear(0.0, 0.5, 0.0, 0.0, 0.0, 0.0);
+    ///
+    /// assert_eq!(m.transform_point(Vec3::new(1.0, 1.0, 2.0)), Vec3::new(2.0, 1.0, 2.0));
+    /// ```
+    pub fn shear(&mut self, xy: f64, xz: f64, yx: f64, yz: f64, zx: f64, zy: f64) {
+        *self = self.sheared(xy, xz, yx, yz, zx, zy);
+    }
+
+    /// Calculates and returns a `Mat4d` object with the upper-left 3x3 basis of the calling `Mat4d`
+    /// object orthonormalized using the Gram-Schmidt process. The translation is left untouched.
+    ///
+    /// Useful for correcting the drift that builds up in a matrix after many incremental
+    /// rotations.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gamemath::{Mat4d, Vec3};
+    ///
+    /// let m: Mat4d = ((1.0, 0.01, 0.0,  0.0),
+    ///                 (0.02, 1.0, 0.0,  0.0),
+    ///                 (0.0, 0.03, 0.98, 0.0),
+    ///                 (5.0, 6.0,  7.0,  1.0)).into();
+    ///
+    /// let o = m.orthonormalized();
+    /// let x: Vec3<f64> = o[0].into();
+    /// let y: Vec3<f64> = o[1].into();
+    /// let z: Vec3<f64> = o[2].into();
+    ///
+    /// assert!(x.dot(y).abs() < 0.000001);
+    /// assert!(x.dot(z).abs() < 0.000001);
+    /// assert!(y.dot(z).abs() < 0.000001);
+    /// assert!((x.length() - 1.0).abs() < 0.000001);
+    /// assert!((y.length() - 1.0).abs() < 0.000001);
+    /// assert!((z.length() - 1.0).abs() < 0.000001);
+    /// assert_eq!(o[3], m[3]);
+    /// ```
+    pub fn orthonormalized(&self) -> Mat4d {
+        let mut result = *self;
+        let x: Vec3<f64> = self[0].into();
+        let y: Vec3<f64> = self[1].into();
+        let z: Vec3<f64> = self[2].into();
+
+        let x = x.normalized();
+        let y = (y - x * x.dot(y)).normalized();
+        let z = (z - x * x.dot(z) - y * y.dot(z)).normalized();
+
+        result[0] = Vec4::new(x.x, x.y, x.z, self[0][3]);
+        result[1] = Vec4::new(y.x, y.y, y.z, self[1][3]);
+        result[2] = Vec4::new(z.x, z.y, z.z, self[2][3]);
+
+        result
+    }
+
+    /// Performs the Gram-Schmidt orthonormalization on the upper-left 3x3 basis of the calling
+    /// `Mat4d` object, leaving the translation untouched.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gamemath::{Mat4d, Vec3};
+    ///
+    /// let mut m: Mat4d = ((2.0, 0.0, 0.0, 0.0),
+    ///                     (0.0, 3.0, 0.0, 0.0),
+    ///                     (0.0, 0.0, 4.0, 0.0),
+    ///                     (1.0, 2.0, 3.0, 1.0)).into();
+    ///
+    /// m.orthonormalize();
+    ///
+    /// assert_eq!(m, ((1.0, 0.0, 0.0, 0.0),
+    ///                (0.0, 1.0, 0.0, 0.0),
+    ///                (0.0, 0.0, 1.0, 0.0),
+    ///                (1.0, 2.0, 3.0, 1.0)).into());
+    /// ```
+    pub fn orthonormalize(&mut self) {
+        *self = self.orthonormalized();
+    }
+
+    /// Returns `true` if every element of the calling `Mat4d` object is neither infinite nor NaN.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gamemath::Mat4d;
+    ///
+    /// let mut m = Mat4d::identity();
+    ///
+    /// assert!(m.is_finite());
+    ///
+    /// m[2][1] = f64::INFINITY;
+    ///
+    /// assert!(!m.is_finite());
+    /// ```
+    pub fn is_finite(&self) -> bool {
+        self[0].is_finite() && self[1].is_finite() && self[2].is_finite() && self[3].is_finite()
+    }
+
+    /// Returns `true` if any element of the calling `Mat4d` object is NaN.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gamemath::Mat4d;
+    ///
+    /// let mut m = Mat4d::identity();
+    ///
+    /// assert!(!m.is_nan());
+    ///
+    /// m[3][0] = f64::NAN;
+    ///
+    /// assert!(m.is_nan());
+    /// ```
+    pub fn is_nan(&self) -> bool {
+        self[0].is_nan() || self[1].is_nan() || self[2].is_nan() || self[3].is_nan()
+    }
+
+    /// Transforms a point represented by a `Vec3<f64>` by the calling `Mat4d` object.
+    /// The point is treated as having a W component of 1.0, so it is affected by translation, and
+    /// the result is divided by the resulting W component to apply any perspective projection.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gamemath::{Mat4d, Vec3};
+    ///
+    /// let m = Mat4d::identity().translated(Vec3::new(1.0, 2.0, 3.0));
+    ///
+    /// assert_eq!(m.transform_point(Vec3::new(1.0, 1.0, 1.0)), Vec3::new(2.0, 3.0, 4.0));
+    /// ```
+    pub fn transform_point(&self, point: Vec3<f64>) -> Vec3<f64> {
+        let result = self[0] * point.x + self[1] * point.y + self[2] * point.z + self[3];
+
+        Vec3::new(result.x, result.y, result.z) / result.w
+    }
+
+    /// Transforms a direction represented by a `Vec3<f64>` by the calling `Mat4d` object.
+    /// The direction is treated as having a W component of 0.0, so it is not affected by
+    /// translation, and no perspective divide is performed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gamemath::{Mat4d, Vec3};
+    ///
+    /// let m = Mat4d::identity().translated(Vec3::new(1.0, 2.0, 3.0));
+    ///
+    /// assert_eq!(m.transform_vector(Vec3::new(1.0, 1.0, 1.0)), Vec3::new(1.0, 1.0, 1.0));
+    /// ```
+    pub fn transform_vector(&self, vector: Vec3<f64>) -> Vec3<f64> {
+        let result = self[0] * vector.x + self[1] * vector.y + self[2] * vector.z;
+
+        Vec3::new(result.x, result.y, result.z)
+    }
+
+    /// Returns a copy of the row at an index of the calling `Mat4d` object, which is the same
+    /// `Vec4<f64>` that indexing the matrix with a single index returns.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gamemath::{Mat4d, Vec4};
+    ///
+    /// let m: Mat4d = (( 0.0,  1.0,  2.0,  3.0),
+    ///                 ( 4.0,  5.0,  6.0,  7.0),
+    ///                 ( 8.0,  9.0, 10.0, 11.0),
+    ///                 (12.0, 13.0, 14.0, 15.0)).into();
+    ///
+    /// assert_eq!(m.row(1), Vec4::new(4.0, 5.0, 6.0, 7.0));
+    /// ```
+    pub fn row(&self, index: usize) -> Vec4<f64> {
+        self[index]
+    }
+
+    /// Assembles and returns the column at an index of the calling `Mat4d` object.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gamemath::{Mat4d, Vec4};
+    ///
+    /// let m: Mat4d = (( 0.0,  1.0,  2.0,  3.0),
+    ///                 ( 4.0,  5.0,  6.0,  7.0),
+    ///                 ( 8.0,  9.0, 10.0, 11.0),
+    ///                 (12.0, 13.0, 14.0, 15.0)).into();
+    ///
+    /// assert_eq!(m.col(0), Vec4::new(0.0, 4.0, 8.0, 12.0));
+    /// ```
+    pub fn col(&self, index: usize) -> Vec4<f64> {
+        (
+            self[0][index],
+            self[1][index],
+            self[2][index],
+            self[3][index],
+        )
+            .into()
+    }
+
+    /// Constructs a `Mat4d` from four `Vec4<f64>` used as its columns, as opposed to converting
+    /// from a tuple of vectors, which uses them as rows.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gamemath::{Mat4d, Vec4};
+    ///
+    /// let a = Vec4::new(1.0, 2.0, 3.0, 4.0);
+    /// let b = Vec4::new(5.0, 6.0, 7.0, 8.0);
+    /// let c = Vec4::new(9.0, 10.0, 11.0, 12.0);
+    /// let d = Vec4::new(13.0, 14.0, 15.0, 16.0);
+    /// let m = Mat4d::from_columns(a, b, c, d);
+    ///
+    /// assert_eq!(m, Mat4d::from((a, b, c, d)).transposed());
+    /// assert_eq!(m[0], Vec4::new(a.x, b.x, c.x, d.x));
+    /// ```
+    pub fn from_columns(x: Vec4<f64>, y: Vec4<f64>, z: Vec4<f64>, w: Vec4<f64>) -> Mat4d {
+        (
+            (x.x, y.x, z.x, w.x),
+            (x.y, y.y, z.y, w.y),
+            (x.z, y.z, z.z, w.z),
+            (x.w, y.w, z.w, w.w),
+        )
+            .into()
+    }
+
+    /// Returns the elements of the calling `Mat4d` object as a column-major 2D array, where each
+    /// inner array holds one column. This is the transpose of the row-major layout used when
+    /// converting a `Mat4d` to and from a `[[f64; 4]; 4]`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gamemath::Mat4d;
+    ///
+    /// let rows = [[ 0.0,  1.0,  2.0,  3.0],
+    ///             [ 4.0,  5.0,  6.0,  7.0],
+    ///             [ 8.0,  9.0, 10.0, 11.0],
+    ///             [12.0, 13.0, 14.0, 15.0]];
+    /// let m = Mat4d::from(rows);
+    /// let cols = m.to_cols_array_2d();
+    ///
+    /// assert_eq!(cols[0], [0.0, 4.0, 8.0, 12.0]);
+    /// assert_ne!(cols, rows);
+    /// assert_ne!(Mat4d::from(cols), m);
+    /// assert_eq!(Mat4d::from_cols_array_2d(&cols), m);
+    /// ```
+    pub fn to_cols_array_2d(&self) -> [[f64; 4]; 4] {
+        let mut cols = [[0.0; 4]; 4];
+
+        for (i, col) in cols.iter_mut().enumerate() {
+            for (j, element) in col.iter_mut().enumerate() {
+                *element = self[j][i];
+            }
+        }
+
+        cols
+    }
+
+    /// Constructs a `Mat4d` from a column-major 2D array, where each inner array holds one column.
+    /// This is the transpose of the row-major layout used when converting from a
+    /// `[[f64; 4]; 4]`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gamemath::{Mat4d, Vec4};
+    ///
+    /// let m = Mat4d::from_cols_array_2d(&[[ 0.0,  1.0,  2.0,  3.0],
+    ///                                     [ 4.0,  5.0,  6.0,  7.0],
+    ///                                     [ 8.0,  9.0, 10.0, 11.0],
+    ///                                     [12.0, 13.0, 14.0, 15.0]]);
+    ///
+    /// assert_eq!(m[0], Vec4::new(0.0, 4.0, 8.0, 12.0));
+    /// assert_eq!(m[1], Vec4::new(1.0, 5.0, 9.0, 13.0));
+    /// ```
+    pub fn from_cols_array_2d(cols: &[[f64; 4]; 4]) -> Mat4d {
+        Mat4d::from(*cols).transposed()
+    }
+
+    /// Returns `true` if the calling `Mat4d` object is an affine transformation, meaning that
+    /// its projective part, `col(3)`, is `(0.0, 0.0, 0.0, 1.0)` within an epsilon.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gamemath::{Mat4d, Vec3};
+    ///
+    /// let camera = Mat4d::look_at(Vec3::new(1.0, 2.0, 3.0),
+    ///                             Vec3::new(0.0, 0.0, 0.0),
+    ///                             Vec3::new(0.0, 1.0, 0.0));
+    ///
+    /// assert!(camera.is_affine(1e-6));
+    /// assert!(!Mat4d::perspective(55.0, 1920.0 / 1080.0, 0.01, 100.0).is_affine(1e-6));
+    /// ```
+    pub fn is_affine(&self, epsilon: f64) -> bool {
+        self[0][3].abs() <= epsilon
+            && self[1][3].abs() <= epsilon
+            && self[2][3].abs() <= epsilon
+            && (self[3][3] - 1.0).abs() <= epsilon
+    }
+
+    /// Returns `true` if every element of the calling `Mat4d` object is within an epsilon of the
+    /// corresponding element of the identity matrix.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gamemath::{Mat4d, Vec3};
+    ///
+    /// assert!(Mat4d::identity().is_identity(0.0));
+    /// assert!(Mat4d::rotation(1.0, Vec3::new(0.0, 1.0, 0.0))
+    ///     .rotated(-1.0, Vec3::new(0.0, 1.0, 0.0))
+    ///     .is_identity(1e-6));
+    /// assert!(!Mat4d::identity().translated(Vec3::new(0.0, 0.1, 0.0)).is_identity(1e-6));
+    /// ```
+    pub fn is_identity(&self, epsilon: f64) -> bool {
+        self.approx_eq(Mat4d::identity(), epsilon)
+    }
+
+    /// Returns `true` if every element of the calling `Mat4d` object is within an epsilon of the
+    /// corresponding element of another `Mat4d`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gamemath::{Mat4d, Vec3};
+    ///
+    /// let a = Mat4d::rotation(1.0, Vec3::new(0.0, 1.0, 0.0));
+    /// let b = Mat4d::rotation(0.5, Vec3::new(0.0, 1.0, 0.0))
+    ///     .rotated(0.5, Vec3::new(0.0, 1.0, 0.0));
+    ///
+    /// assert!(a.approx_eq(b, 0.000001));
+    /// assert!(!a.approx_eq(Mat4d::identity(), 0.000001));
+    /// ```
+    pub fn approx_eq(&self, other: Mat4d, epsilon: f64) -> bool {
+        (0..4).all(|i| (0..4).all(|j| (self[i][j] - other[i][j]).abs() <= epsilon))
+    }
+
+    /// Returns a reference to the row at an index of the calling `Mat4d` object, or `None` if
+    /// the index is out of range, instead of panicking like indexing does.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gamemath::Mat4d;
+    ///
+    /// let m = Mat4d::identity();
+    ///
+    /// assert_eq!(m.get(0), Some(&m[0]));
+    /// assert_eq!(m.get(4), None);
+    /// ```
+    pub fn get(&self, index: usize) -> Option<&Vec4<f64>> {
+        self.rows.get(index)
+    }
+
+    /// Returns an iterator over references to the elements of the calling `Mat4d` object, going
+    /// through them row by row.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gamemath::Mat4d;
+    ///
+    /// let elements = [ 0.0,  1.0,  2.0,  3.0,
+    ///                  4.0,  5.0,  6.0,  7.0,
+    ///                  8.0,  9.0, 10.0, 11.0,
+    ///                 12.0, 13.0, 14.0, 15.0];
+    /// let m = Mat4d::from(elements);
+    ///
+    /// assert_eq!(m.iter().count(), 16);
+    /// assert_eq!(m.iter().cloned().collect::<Vec<f64>>(), elements.to_vec());
+    /// ```
+    pub fn iter(&self) -> impl Iterator<Item = &f64> {
+        self.rows.iter().flat_map(|row| {
+            core::iter::once(&row.x)
+                .chain(core::iter::once(&row.y))
+                .chain(core::iter::once(&row.z))
+                .chain(core::iter::once(&row.w))
+        })
+    }
+
+    /// Returns an iterator over mutable references to the elements of the calling `Mat4d`
+    /// object, going through them row by row.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gamemath::Mat4d;
+    ///
+    /// let mut m = Mat4d::identity();
+    ///
+    /// for element in m.iter_mut() {
+    ///     *element += 1.0;
+    /// }
+    ///
+    /// assert_eq!(m[0][0], 2.0);
+    /// assert_eq!(m[1][0], 1.0);
+    /// ```
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = &mut f64> {
+        self.rows.iter_mut().flat_map(|row| {
+            let Vec4 {
+                ref mut x,
+                ref mut y,
+                ref mut z,
+                ref mut w,
+            } = *row;
+
+            core::iter::once(x)
+                .chain(core::iter::once(y))
+                .chain(core::iter::once(z))
+                .chain(core::iter::once(w))
+        })
+    }
+}
+
+impl Default for Mat4d {
+    fn default() -> Mat4d {
+        (
+            (1.0, 0.0, 0.0, 0.0),
+            (0.0, 1.0, 0.0, 0.0),
+            (0.0, 0.0, 1.0, 0.0),
+            (0.0, 0.0, 0.0, 1.0),
+        )
+            .into()
+    }
+}
+
+impl From<f64> for Mat4d {
+    fn from(value: f64) -> Mat4d {
+        Mat4d {
+            rows: [
+                (value, 0.0, 0.0, 0.0).into(),
+                (0.0, value, 0.0, 0.0).into(),
+                (0.0, 0.0, value, 0.0).into(),
+                (0.0, 0.0, 0.0, value).into(),
+            ],
+        }
+    }
+}
+
+impl From<(Row, Row, Row, Row)> for Mat4d {
+    fn from(tuple: (Row, Row, Row, Row)) -> Mat4d {
+        Mat4d {
+            rows: [
+                tuple.0.into(),
+                tuple.1.into(),
+                tuple.2.into(),
+                tuple.3.into(),
+            ],
+        }
+    }
+}
+
+impl From<InlineMat4> for Mat4d {
+    fn from(tuple: InlineMat4) -> Mat4d {
+        Mat4d {
+            rows: [
+                (tuple.0, tuple.1, tuple.2, tuple.3).into(),
+                (tuple.4, tuple.5, tuple.6, tuple.7).into(),
+                (tuple.8, tuple.9, tuple.10, tuple.11).into(),
+                (tuple.12, tuple.13, tuple.14, tuple.15).into(),
+            ],
+        }
+    }
+}
+
+impl From<[[f64; 4]; 4]> for Mat4d {
+    fn from(slice: [[f64; 4]; 4]) -> Mat4d {
+        Mat4d {
+            rows: [
+                slice[0].into(),
+                slice[1].into(),
+                slice[2].into(),
+                slice[3].into(),
+            ],
+        }
+    }
+}
+
+impl From<[f64; 16]> for Mat4d {
+    fn from(slice: [f64; 16]) -> Mat4d {
+        Mat4d {
+            rows: [
+                (slice[0], slice[1], slice[2], slice[3]).into(),
+                (slice[4], slice[5], slice[6], slice[7]).into(),
+                (slice[8], slice[9], slice[10], slice[11]).into(),
+                (slice[12], slice[13], slice[14], slice[15]).into(),
+            ],
+        }
+    }
+}
+
+/// Converts a slice of elements in row-major order into a `Mat4d`, failing if the length of the
+/// slice is not exactly 16.
+///
+/// # Examples
+///
+/// ```
+/// use gamemath::Mat4d;
+/// use std::convert::TryFrom;
+///
+/// let elements: Vec<f64> = (0..17).map(|i| i as f64).collect();
+/// let m = Mat4d::try_from(&elements[..16]).unwrap();
+///
+/// assert_eq!(m[0][1], 1.0);
+/// assert_eq!(m[1][0], 4.0);
+/// assert!(Mat4d::try_from(&elements[..15]).is_err());
+/// assert!(Mat4d::try_from(&elements[..]).is_err());
+/// ```
+impl<'a> core::convert::TryFrom<&'a [f64]> for Mat4d {
+    type Error = core::array::TryFromSliceError;
+
+    fn try_from(slice: &'a [f64]) -> Result<Mat4d, core::array::TryFromSliceError> {
+        <[f64; 16] as core::convert::TryFrom<&[f64]>>::try_from(slice).map(Mat4d::from)
+    }
+}
+
+impl From<[Vec4<f64>; 4]> for Mat4d {
+    fn from(slice: [Vec4<f64>; 4]) -> Mat4d {
+        Mat4d {
+            rows: [slice[0], slice[1], slice[2], slice[3]],
+        }
+    }
+}
+
+impl From<(Vec4<f64>, Vec4<f64>, Vec4<f64>, Vec4<f64>)> for Mat4d {
+    fn from(tuple: (Vec4<f64>, Vec4<f64>, Vec4<f64>, Vec4<f64>)) -> Mat4d {
+        Mat4d {
+            rows: [tuple.0, tuple.1, tuple.2, tuple.3],
+        }
+    }
+}
+
+impl From<Quatd> for Mat4d {
+    fn from(quat: Quatd) -> Mat4d {
+        quat.extract_matrix()
+    }
+}
+
+impl core::ops::Index<usize> for Mat4d {
+    type Output = Vec4<f64>;
+
+    fn index(&self, index: usize) -> &Vec4<f64> {
+        match index {
+            0 => &self.rows[0],
+            1 => &self.rows[1],
+            2 => &self.rows[2],
+            3 => &self.rows[3],
+            _ => panic!("Mat4d index out of range!"),
+        }
+    }
+}
+
+impl core::ops::IndexMut<usize> for Mat4d {
+    fn index_mut(&mut self, index: usize) -> &mut Vec4<f64> {
+        match index {
+            0 => &mut self.rows[0],
+            1 => &mut self.rows[1],
+            2 => &mut self.rows[2],
+            3 => &mut self.rows[3],
+            _ => panic!("Mat4d index out of range!"),
+        }
+    }
+}
+
+impl core::ops::Index<(usize, usize)> for Mat4d {
+    type Output = f64;
+
+    fn index(&self, index: (usize, usize)) -> &f64 {
+        &self.rows[index.0][index.1]
+    }
+}
+
+impl core::ops::IndexMut<(usize, usize)> for Mat4d {
+    fn index_mut(&mut self, index: (usize, usize)) -> &mut f64 {
+        &mut self.rows[index.0][index.1]
+    }
+}
+
+impl core::ops::Add for Mat4d {
+    type Output = Mat4d;
+
+    fn add(self, right: Mat4d) -> Mat4d {
+        Mat4d {
+            rows: [
+                self[0] + right[0],
+                self[1] + right[1],
+                self[2] + right[2],
+                self[3] + right[3],
+            ],
+        }
+    }
+}
+
+impl core::ops::AddAssign for Mat4d {
+    fn add_assign(&mut self, right: Mat4d) {
+        *self = *self + right;
+    }
+}
+
+impl core::ops::Sub for Mat4d {
+    type Output = Mat4d;
+
+    fn sub(self, right: Mat4d) -> Mat4d {
+        Mat4d {
+            rows: [
+                self[0] - right[0],
+                self[1] - right[1],
+                self[2] - right[2],
+                self[3] - right[3],
+            ],
+        }
+    }
+}
+
+impl core::ops::SubAssign for Mat4d {
+    fn sub_assign(&mut self, right: Mat4d) {
+        *self = *self - right;
+    }
+}
+
+/// Negates every element of a `Mat4d`.
+///
+/// # Examples
+///
+/// ```
+/// use gamemath::Mat4d;
+///
+/// let m = -Mat4d::identity();
+///
+/// assert_eq!(m[0][0], -1.0);
+/// assert_eq!(m[3][3], -1.0);
+/// assert_eq!(m + Mat4d::identity(), Mat4d::from(0.0));
+/// ```
+impl core::ops::Neg for Mat4d {
+    type Output = Mat4d;
+
+    fn neg(self) -> Mat4d {
+        Mat4d {
+            rows: [-self[0], -self[1], -self[2], -self[3]],
+        }
+    }
+}
+
+/// Divides every element of a `Mat4d` by a scalar value. Dividing by zero is not guarded against,
+/// and follows the IEEE 754 rules, producing infinite or NaN elements.
+///
+/// # Examples
+///
+/// ```
+/// use gamemath::Mat4d;
+///
+/// let m = Mat4d::from([2.0; 16]) / 2.0;
+///
+/// assert_eq!(m, Mat4d::from([1.0; 16]));
+/// assert_eq!(Mat4d::identity() / 4.0, Mat4d::from(0.25));
+/// ```
+impl core::ops::Div<f64> for Mat4d {
+    type Output = Mat4d;
+
+    fn div(self, right: f64) -> Mat4d {
+        Mat4d {
+            rows: [
+                self[0] / right,
+                self[1] / right,
+                self[2] / right,
+                self[3] / right,
+            ],
+        }
+    }
+}
+
+impl core::ops::Mul<Vec4<f64>> for Mat4d {
+    type Output = Vec4<f64>;
+
+    fn mul(self, vec: Vec4<f64>) -> Vec4<f64> {
+        (
+            self[0].dot(vec),
+            self[1].dot(vec),
+            self[2].dot(vec),
+            self[3].dot(vec),
+        )
+            .into()
+    }
+}
+
+impl core::ops::Mul<Mat4d> for Mat4d {
+    type Output = Mat4d;
+
+    fn mul(self, right: Mat4d) -> Mat4d {
+        let mut result: Mat4d = 0.0.into();
+
+        result[0][0]
+            = self[0][0] * right[0][0]
+            + self[1][0] * right[0][1]
+            + self[2][0] * right[0][2]
+            + self[3][0] * right[0][3];
+        result[0][1]
+            = self[0][1] * right[0][0]
+            + self[1][1] * right[0][1]
+            + self[2][1] * right[0][2]
+            + self[3][1] * right[0][3];
+        result[0][2]
+            = self[0][2] * right[0][0]
+            + self[1][2] * right[0][1]
+            + self[2][2] * right[0][2]
+            + self[3][2] * right[0][3];
+        result[0][3]
+            = self[0][3] * right[0][0]
+            + self[1][3] * right[0][1]
+            + self[2][3] * right[0][2]
+            + self[3][3] * right[0][3];
+
+        result[1][0]
+            = self[0][0] * right[1][0]
+            + self[1][0] * right[1][1]
+            + self[2][0] * right[1][2]
+            + self[3][0] * right[1][3];
+        result[1][1]
+            = self[0][1] * right[1][0]
+            + self[1][1] * right[1][1]
+            + self[2][1] * right[1][2]
+            + self[3][1] * right[1][3];
+        result[1][2]
+            = self[0][2] * right[1][0]
+            + self[1][2] * right[1][1]
+            + self[2][2] * right[1][2]
+            + self[3][2] * right[1][3];
+        result[1][3]
+            = self[0][3] * right[1][0]
+            + self[1][3] * right[1][1]
+            + self[2][3] * right[1][2]
+            + self[3][3] * right[1][3];
+
+        result[2][0]
+            = self[0][0] * right[2][0]
+            + self[1][0] * right[2][1]
+            + self[2][0] * right[2][2]
+            + self[3][0] * right[2][3];
+        result[2][1]
+            = self[0][1] * right[2][0]
+            + self[1][1] * right[2][1]
+            + self[2][1] * right[2][2]
+            + self[3][1] * right[2][3];
+        result[2][2]
+            = self[0][2] * right[2][0]
+            + self[1][2] * right[2][1]
+            + self[2][2] * right[2][2]
+            + self[3][2] * right[2][3];
+        result[2][3]
+            = self[0][3] * right[2][0]
+            + self[1][3] * right[2][1]
+            + self[2][3] * right[2][2]
+            + self[3][3] * right[2][3];
+
+        result[3][0]
+            = self[0][0] * right[3][0]
+            + self[1][0] * right[3][1]
+            + self[2][0] * right[3][2]
+            + self[3][0] * right[3][3];
+        result[3][1]
+            = self[0][1] * right[3][0]
+            + self[1][1] * right[3][1]
+            + self[2][1] * right[3][2]
+            + self[3][1] * right[3][3];
+        result[3][2]
+            = self[0][2] * right[3][0]
+            + self[1][2] * right[3][1]
+            + self[2][2] * right[3][2]
+            + self[3][2] * right[3][3];
+        result[3][3]
+            = self[0][3] * right[3][0]
+            + self[1][3] * right[3][1]
+            + self[2][3] * right[3][2]
+            + self[3][3] * right[3][3];
+
+        result
+    }
+}
+
+impl core::ops::MulAssign<Mat4d> for Mat4d {
+    fn mul_assign(&mut self, right: Mat4d) {
+        *self = *self * right;
+    }
+}
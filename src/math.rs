@@ -0,0 +1,104 @@
+//! Floating-point functions backed by `libm`, used in place of the inherent `std` methods when
+//! the crate is built without the standard library.
+
+use libm;
+
+pub(crate) trait Float {
+    fn sqrt(self) -> Self;
+    fn sin(self) -> Self;
+    fn cos(self) -> Self;
+    fn tan(self) -> Self;
+    // Not called by the crate itself yet, but kept so that `acos` resolves without `std` like the
+    // other functions do.
+    #[allow(dead_code)]
+    fn acos(self) -> Self;
+    fn atan2(self, other: Self) -> Self;
+    fn exp(self) -> Self;
+    fn ln(self) -> Self;
+    fn powf(self, n: Self) -> Self;
+    fn round(self) -> Self;
+}
+
+impl Float for f32 {
+    fn sqrt(self) -> f32 {
+        libm::sqrtf(self)
+    }
+
+    fn sin(self) -> f32 {
+        libm::sinf(self)
+    }
+
+    fn cos(self) -> f32 {
+        libm::cosf(self)
+    }
+
+    fn tan(self) -> f32 {
+        libm::tanf(self)
+    }
+
+    fn acos(self) -> f32 {
+        libm::acosf(self)
+    }
+
+    fn atan2(self, other: f32) -> f32 {
+        libm::atan2f(self, other)
+    }
+
+    fn exp(self) -> f32 {
+        libm::expf(self)
+    }
+
+    fn ln(self) -> f32 {
+        libm::logf(self)
+    }
+
+    fn powf(self, n: f32) -> f32 {
+        libm::powf(self, n)
+    }
+
+    fn round(self) -> f32 {
+        libm::roundf(self)
+    }
+}
+
+impl Float for f64 {
+    fn sqrt(self) -> f64 {
+        libm::sqrt(self)
+    }
+
+    fn sin(self) -> f64 {
+        libm::sin(self)
+    }
+
+    fn cos(self) -> f64 {
+        libm::cos(self)
+    }
+
+    fn tan(self) -> f64 {
+        libm::tan(self)
+    }
+
+    fn acos(self) -> f64 {
+        libm::acos(self)
+    }
+
+    fn atan2(self, other: f64) -> f64 {
+        libm::atan2(self, other)
+    }
+
+    fn exp(self) -> f64 {
+        libm::exp(self)
+    }
+
+    fn ln(self) -> f64 {
+        libm::log(self)
+    }
+
+    fn powf(self, n: f64) -> f64 {
+        libm::pow(self, n)
+    }
+
+    fn round(self) -> f64 {
+        libm::round(self)
+    }
+}
@@ -0,0 +1,167 @@
+//! Conversions between the types of this crate and the `mint` crate, enabled through the `mint`
+//! feature.
+//!
+//! `Mat4` and `Mat4d` store their columns in `rows`, so `m[i]` is the i-th column of the matrix,
+//! both as laid out in memory and as combined when multiplying the matrix by a vector.
+//! `mint::ColumnMatrix4` stores the same columns in its `x`, `y`, `z` and `w` fields, so the
+//! conversions copy `m[0]` into `x`, `m[1]` into `y` and so on, keeping the memory layout and the
+//! transformation the matrix represents unchanged.
+
+use mat4::Mat4;
+use mat4d::Mat4d;
+use mint;
+use quat::Quat;
+use quatd::Quatd;
+use vec2::Vec2;
+use vec3::Vec3;
+use vec4::Vec4;
+
+impl<T> From<mint::Vector2<T>> for Vec2<T> {
+    fn from(vec: mint::Vector2<T>) -> Vec2<T> {
+        Vec2 { x: vec.x, y: vec.y }
+    }
+}
+
+impl<T> From<Vec2<T>> for mint::Vector2<T> {
+    fn from(vec: Vec2<T>) -> mint::Vector2<T> {
+        mint::Vector2 { x: vec.x, y: vec.y }
+    }
+}
+
+impl<T> From<mint::Vector3<T>> for Vec3<T> {
+    fn from(vec: mint::Vector3<T>) -> Vec3<T> {
+        Vec3 {
+            x: vec.x,
+            y: vec.y,
+            z: vec.z,
+        }
+    }
+}
+
+impl<T> From<Vec3<T>> for mint::Vector3<T> {
+    fn from(vec: Vec3<T>) -> mint::Vector3<T> {
+        mint::Vector3 {
+            x: vec.x,
+            y: vec.y,
+            z: vec.z,
+        }
+    }
+}
+
+impl<T> From<mint::Vector4<T>> for Vec4<T> {
+    fn from(vec: mint::Vector4<T>) -> Vec4<T> {
+        Vec4 {
+            x: vec.x,
+            y: vec.y,
+            z: vec.z,
+            w: vec.w,
+        }
+    }
+}
+
+impl<T> From<Vec4<T>> for mint::Vector4<T> {
+    fn from(vec: Vec4<T>) -> mint::Vector4<T> {
+        mint::Vector4 {
+            x: vec.x,
+            y: vec.y,
+            z: vec.z,
+            w: vec.w,
+        }
+    }
+}
+
+impl From<mint::ColumnMatrix4<f32>> for Mat4 {
+    fn from(matrix: mint::ColumnMatrix4<f32>) -> Mat4 {
+        Mat4 {
+            rows: [
+                matrix.x.into(),
+                matrix.y.into(),
+                matrix.z.into(),
+                matrix.w.into(),
+            ],
+        }
+    }
+}
+
+impl From<Mat4> for mint::ColumnMatrix4<f32> {
+    fn from(matrix: Mat4) -> mint::ColumnMatrix4<f32> {
+        mint::ColumnMatrix4 {
+            x: matrix[0].into(),
+            y: matrix[1].into(),
+            z: matrix[2].into(),
+            w: matrix[3].into(),
+        }
+    }
+}
+
+impl From<mint::ColumnMatrix4<f64>> for Mat4d {
+    fn from(matrix: mint::ColumnMatrix4<f64>) -> Mat4d {
+        Mat4d {
+            rows: [
+                matrix.x.into(),
+                matrix.y.into(),
+                matrix.z.into(),
+                matrix.w.into(),
+            ],
+        }
+    }
+}
+
+impl From<Mat4d> for mint::ColumnMatrix4<f64> {
+    fn from(matrix: Mat4d) -> mint::ColumnMatrix4<f64> {
+        mint::ColumnMatrix4 {
+            x: matrix[0].into(),
+            y: matrix[1].into(),
+            z: matrix[2].into(),
+            w: matrix[3].into(),
+        }
+    }
+}
+
+impl From<mint::Quaternion<f32>> for Quat {
+    fn from(quat: mint::Quaternion<f32>) -> Quat {
+        Quat {
+            x: quat.v.x,
+            y: quat.v.y,
+            z: quat.v.z,
+            w: quat.s,
+        }
+    }
+}
+
+impl From<Quat> for mint::Quaternion<f32> {
+    fn from(quat: Quat) -> mint::Quaternion<f32> {
+        mint::Quaternion {
+            v: mint::Vector3 {
+                x: quat.x,
+                y: quat.y,
+                z: quat.z,
+            },
+            s: quat.w,
+        }
+    }
+}
+
+impl From<mint::Quaternion<f64>> for Quatd {
+    fn from(quat: mint::Quaternion<f64>) -> Quatd {
+        Quatd {
+            x: quat.v.x,
+            y: quat.v.y,
+            z: quat.v.z,
+            w: quat.s,
+        }
+    }
+}
+
+impl From<Quatd> for mint::Quaternion<f64> {
+    fn from(quat: Quatd) -> mint::Quaternion<f64> {
+        mint::Quaternion {
+            v: mint::Vector3 {
+                x: quat.x,
+                y: quat.y,
+                z: quat.z,
+            },
+            s: quat.w,
+        }
+    }
+}
@@ -0,0 +1,181 @@
+use quat::Quat;
+use quatd::Quatd;
+use vec2::Vec2;
+use vec3::Vec3;
+use vec4::Vec4;
+
+/// A type with a Euclidean length that can be scaled to unit length, implemented for the
+/// floating-point vectors and the quaternions so that generic code can normalize any of them.
+///
+/// The inherent methods of the implementing types are kept, and are the ones picked by method
+/// calls unless the trait is used through a generic bound.
+///
+/// # Examples
+///
+/// ```
+/// use gamemath::{Normalize, Quat, Vec4};
+///
+/// fn unit<T: Normalize>(value: T) -> T {
+///     value.normalized()
+/// }
+///
+/// let v = unit(Vec4::new(2.0_f32, 0.0, 0.0, 0.0));
+/// let q = unit(Quat::from((0.0, 0.0, 0.0, 2.0)));
+///
+/// assert_eq!(v, Vec4::new(1.0, 0.0, 0.0, 0.0));
+/// assert_eq!(q, Quat::identity());
+/// assert_eq!(Normalize::length(&v), 1.0);
+/// assert_eq!(Normalize::length_squared(&q), 1.0);
+///
+/// let mut q = Quat::from((0.0, 3.0, 0.0, 4.0));
+///
+/// Normalize::normalize(&mut q);
+///
+/// assert_eq!(q, (0.0, 0.6, 0.0, 0.8).into());
+/// ```
+pub trait Normalize: Sized {
+    /// The scalar type of the length.
+    type Scalar;
+
+    /// Calculates the squared length/magnitude.
+    fn length_squared(&self) -> Self::Scalar;
+
+    /// Calculates the length/magnitude.
+    fn length(&self) -> Self::Scalar;
+
+    /// Returns a normalized copy, with the same direction and a length of 1.
+    fn normalized(&self) -> Self;
+
+    /// Normalizes the value in place.
+    fn normalize(&mut self) {
+        *self = self.normalized();
+    }
+}
+
+impl Normalize for Vec2<f32> {
+    type Scalar = f32;
+
+    fn length_squared(&self) -> f32 {
+        Vec2::<f32>::length_squared(self)
+    }
+
+    fn length(&self) -> f32 {
+        Vec2::<f32>::length(*self)
+    }
+
+    fn normalized(&self) -> Vec2<f32> {
+        Vec2::<f32>::normalized(*self)
+    }
+}
+
+impl Normalize for Vec2<f64> {
+    type Scalar = f64;
+
+    fn length_squared(&self) -> f64 {
+        Vec2::<f64>::length_squared(self)
+    }
+
+    fn length(&self) -> f64 {
+        Vec2::<f64>::length(self)
+    }
+
+    fn normalized(&self) -> Vec2<f64> {
+        Vec2::<f64>::normalized(self)
+    }
+}
+
+impl Normalize for Vec3<f32> {
+    type Scalar = f32;
+
+    fn length_squared(&self) -> f32 {
+        Vec3::<f32>::length_squared(self)
+    }
+
+    fn length(&self) -> f32 {
+        Vec3::<f32>::length(self)
+    }
+
+    fn normalized(&self) -> Vec3<f32> {
+        Vec3::<f32>::normalized(self)
+    }
+}
+
+impl Normalize for Vec3<f64> {
+    type Scalar = f64;
+
+    fn length_squared(&self) -> f64 {
+        Vec3::<f64>::length_squared(self)
+    }
+
+    fn length(&self) -> f64 {
+        Vec3::<f64>::length(self)
+    }
+
+    fn normalized(&self) -> Vec3<f64> {
+        Vec3::<f64>::normalized(self)
+    }
+}
+
+impl Normalize for Vec4<f32> {
+    type Scalar = f32;
+
+    fn length_squared(&self) -> f32 {
+        Vec4::<f32>::length_squared(self)
+    }
+
+    fn length(&self) -> f32 {
+        Vec4::<f32>::length(self)
+    }
+
+    fn normalized(&self) -> Vec4<f32> {
+        Vec4::<f32>::normalized(self)
+    }
+}
+
+impl Normalize for Vec4<f64> {
+    type Scalar = f64;
+
+    fn length_squared(&self) -> f64 {
+        Vec4::<f64>::length_squared(self)
+    }
+
+    fn length(&self) -> f64 {
+        Vec4::<f64>::length(self)
+    }
+
+    fn normalized(&self) -> Vec4<f64> {
+        Vec4::<f64>::normalized(self)
+    }
+}
+
+impl Normalize for Quat {
+    type Scalar = f32;
+
+    fn length_squared(&self) -> f32 {
+        Quat::length_squared(self)
+    }
+
+    fn length(&self) -> f32 {
+        Quat::length(self)
+    }
+
+    fn normalized(&self) -> Quat {
+        Quat::normalized(self)
+    }
+}
+
+impl Normalize for Quatd {
+    type Scalar = f64;
+
+    fn length_squared(&self) -> f64 {
+        Quatd::length_squared(self)
+    }
+
+    fn length(&self) -> f64 {
+        Quatd::length(self)
+    }
+
+    fn normalized(&self) -> Quatd {
+        Quatd::normalized(self)
+    }
+}
@@ -0,0 +1,130 @@
+use vec3::Vec3;
+
+/// A plane in 3D space, represented by a normal and a distance from the origin.
+///
+/// A point `p` lies on the plane when `normal.dot(p) + distance == 0.0`, and the plane faces in
+/// the direction of its normal.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Plane {
+    /// The normal of the plane.
+    pub normal: Vec3<f32>,
+    /// The signed distance from the plane to the origin, measured along the normal.
+    pub distance: f32,
+}
+
+impl Plane {
+    /// Constructs a new `Plane` from a normal and a distance value, as they are.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gamemath::{Plane, Vec3};
+    ///
+    /// let p = Plane::new(Vec3::new(0.0, 0.0, 1.0), -2.0);
+    ///
+    /// assert_eq!(p.normal, Vec3::new(0.0, 0.0, 1.0));
+    /// assert_eq!(p.distance, -2.0);
+    /// ```
+    pub fn new(normal: Vec3<f32>, distance: f32) -> Plane {
+        Plane { normal, distance }
+    }
+
+    /// Constructs a `Plane` passing through a point, facing in the direction of a normal.
+    /// The normal is normalized.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gamemath::{Plane, Vec3};
+    ///
+    /// let p = Plane::from_point_normal(Vec3::new(1.0, 1.0, 2.0), Vec3::new(0.0, 0.0, 5.0));
+    ///
+    /// assert_eq!(p, Plane::new(Vec3::new(0.0, 0.0, 1.0), -2.0));
+    /// ```
+    pub fn from_point_normal(point: Vec3<f32>, normal: Vec3<f32>) -> Plane {
+        let normal = normal.normalized();
+
+        Plane {
+            normal,
+            distance: -normal.dot(point),
+        }
+    }
+
+    /// Constructs a `Plane` passing through three points. The plane faces the side from which
+    /// the points appear in counter-clockwise order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gamemath::{Plane, Vec3};
+    ///
+    /// let p = Plane::from_three_points(Vec3::new(0.0, 0.0, 3.0),
+    ///                                  Vec3::new(1.0, 0.0, 3.0),
+    ///                                  Vec3::new(0.0, 1.0, 3.0));
+    ///
+    /// assert_eq!(p, Plane::new(Vec3::new(0.0, 0.0, 1.0), -3.0));
+    /// ```
+    pub fn from_three_points(a: Vec3<f32>, b: Vec3<f32>, c: Vec3<f32>) -> Plane {
+        Plane::from_point_normal(a, (b - a).cross(c - a))
+    }
+
+    /// Calculates the signed distance from the calling `Plane` to a point. The distance is
+    /// positive for points on the side the plane is facing, and negative for points behind it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gamemath::{Plane, Vec3};
+    ///
+    /// let p = Plane::from_point_normal(Vec3::new(0.0, 0.0, 0.0), Vec3::new(0.0, 0.0, 1.0));
+    ///
+    /// assert_eq!(p.signed_distance(Vec3::new(1.0, 2.0, 3.0)), 3.0);
+    /// assert_eq!(p.signed_distance(Vec3::new(1.0, 2.0, -3.0)), -3.0);
+    /// assert_eq!(p.signed_distance(Vec3::new(1.0, 2.0, 0.0)), 0.0);
+    /// ```
+    pub fn signed_distance(&self, point: Vec3<f32>) -> f32 {
+        self.normal.dot(point) + self.distance
+    }
+
+    /// Calculates and returns a `Plane` representing the same plane as the calling `Plane`, but
+    /// with a unit length normal, so that `signed_distance` returns real distances.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gamemath::{Plane, Vec3};
+    ///
+    /// let p = Plane::new(Vec3::new(0.0, 0.0, 2.0), -4.0);
+    ///
+    /// assert_eq!(p.normalized(), Plane::new(Vec3::new(0.0, 0.0, 1.0), -2.0));
+    /// ```
+    pub fn normalized(&self) -> Plane {
+        let mut length = self.normal.length();
+
+        if length == 0.0 {
+            length = 1.0;
+        }
+
+        Plane {
+            normal: self.normal / length,
+            distance: self.distance / length,
+        }
+    }
+
+    /// Normalizes the calling `Plane` so that its normal is of unit length.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gamemath::{Plane, Vec3};
+    ///
+    /// let mut p = Plane::new(Vec3::new(0.0, 3.0, 0.0), 6.0);
+    ///
+    /// p.normalize();
+    ///
+    /// assert_eq!(p, Plane::new(Vec3::new(0.0, 1.0, 0.0), 2.0));
+    /// ```
+    pub fn normalize(&mut self) {
+        *self = self.normalized();
+    }
+}
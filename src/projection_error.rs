@@ -0,0 +1,48 @@
+use core::fmt;
+
+/// An error describing why a projection matrix could not be constructed from a set of
+/// parameters, returned by `try_frustum` and `try_perspective` on `Mat4` and `Mat4d`.
+///
+/// # Examples
+///
+/// ```
+/// use gamemath::{Mat4, ProjectionError};
+///
+/// let error = Mat4::try_perspective(55.0, 16.0 / 9.0, 0.0, 100.0).unwrap_err();
+///
+/// assert_eq!(error, ProjectionError::NonPositiveNear);
+/// assert_eq!(error.to_string(), "the near plane distance must be positive");
+/// ```
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum ProjectionError {
+    /// The left and right planes coincide, or either of them is NaN.
+    ZeroWidth,
+    /// The top and bottom planes coincide, or either of them is NaN.
+    ZeroHeight,
+    /// The near plane distance is zero, negative or NaN.
+    NonPositiveNear,
+    /// The far plane is not further away than the near plane.
+    FarNotBeyondNear,
+    /// The field of view is not within the range `(0, 180)` degrees.
+    InvalidFov,
+    /// The aspect ratio is zero, negative or NaN.
+    NonPositiveAspect,
+}
+
+impl fmt::Display for ProjectionError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let description = match *self {
+            ProjectionError::ZeroWidth => "the left and right planes must not coincide",
+            ProjectionError::ZeroHeight => "the top and bottom planes must not coincide",
+            ProjectionError::NonPositiveNear => "the near plane distance must be positive",
+            ProjectionError::FarNotBeyondNear => "the far plane must be beyond the near plane",
+            ProjectionError::InvalidFov => "the field of view must be between 0 and 180 degrees",
+            ProjectionError::NonPositiveAspect => "the aspect ratio must be positive",
+        };
+
+        f.write_str(description)
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ProjectionError {}
@@ -1,5 +1,8 @@
+use core::ops::{Add, AddAssign, Div, Index, IndexMut, Mul, MulAssign, Neg, Sub, SubAssign};
+#[cfg(all(not(feature = "std"), not(test)))]
+use math::Float;
+use mat3::Mat3;
 use mat4::Mat4;
-use std::ops::{Add, AddAssign, Mul, MulAssign};
 use vec3::Vec3;
 use vec4::Vec4;
 
@@ -89,6 +92,33 @@ impl Quat {
         *self *= Quat::rotation(radians, axis);
     }
 
+    /// Extracts the axis and the angle in radians of the rotation represented by the calling
+    /// `Quat`, which is expected to be normalized. The angle is in the range `[0, 2π]`. A `Quat`
+    /// without rotation returns the x axis and an angle of zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gamemath::{Quat, Vec3};
+    ///
+    /// let axis = Vec3::new(1.0_f32, 2.0_f32, 3.0_f32).normalized();
+    /// let (a, radians) = Quat::rotation(1.2, axis).to_axis_angle();
+    ///
+    /// assert!((a - axis).length() < 0.000001);
+    /// assert!((radians - 1.2).abs() < 0.000001);
+    /// assert_eq!(Quat::identity().to_axis_angle(), (Vec3::new(1.0, 0.0, 0.0), 0.0));
+    /// ```
+    pub fn to_axis_angle(&self) -> (Vec3<f32>, f32) {
+        let axis = Vec3::new(self.x, self.y, self.z);
+        let sin_half_angle = axis.length();
+
+        if sin_half_angle < f32::EPSILON {
+            return (Vec3::new(1.0, 0.0, 0.0), 0.0);
+        }
+
+        (axis / sin_half_angle, 2.0 * sin_half_angle.atan2(self.w))
+    }
+
     /// Calculates the squared length/magnitude/norm of a `Quat`.
     /// This saves an expensive square root calculation compared to calculating the actual length,
     /// and comparing two squared lengths can therefore often be cheaper than, and yield the same
@@ -161,9 +191,309 @@ impl Quat {
         *self = self.normalized();
     }
 
+    /// Returns `true` if every component of the `Quat` is neither infinite nor NaN.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gamemath::Quat;
+    ///
+    /// assert!(Quat::identity().is_finite());
+    /// assert!(!Quat::from((0.0, f32::NAN, 0.0, 1.0)).is_finite());
+    /// ```
+    pub fn is_finite(&self) -> bool {
+        self.x.is_finite() && self.y.is_finite() && self.z.is_finite() && self.w.is_finite()
+    }
+
+    /// Returns `true` if any component of the `Quat` is NaN.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gamemath::Quat;
+    ///
+    /// assert!(Quat::from((0.0, f32::NAN, 0.0, 1.0)).is_nan());
+    /// assert!(!Quat::identity().is_nan());
+    /// ```
+    pub fn is_nan(&self) -> bool {
+        self.x.is_nan() || self.y.is_nan() || self.z.is_nan() || self.w.is_nan()
+    }
+
+    /// Returns `true` if every component of the calling `Quat` object is within an epsilon of
+    /// the corresponding component of another `Quat`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gamemath::Quat;
+    ///
+    /// let a: Quat = (0.1, 0.2, 0.3, 0.9).into();
+    /// let b: Quat = (0.1, 0.2, 0.3000001, 0.9).into();
+    ///
+    /// assert!(a.approx_eq(b, 0.000001));
+    /// assert!(!a.approx_eq((0.1, 0.2, 0.31, 0.9).into(), 0.000001));
+    /// ```
+    pub fn approx_eq(&self, other: Quat, epsilon: f32) -> bool {
+        (self.x - other.x).abs() <= epsilon
+            && (self.y - other.y).abs() <= epsilon
+            && (self.z - other.z).abs() <= epsilon
+            && (self.w - other.w).abs() <= epsilon
+    }
+
+    /// Calculates and returns the natural logarithm of the calling `Quat` object.
+    /// For a unit quaternion this is a pure quaternion holding the rotation axis scaled by half
+    /// the rotation angle.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gamemath::{Quat, Vec3};
+    ///
+    /// let q = Quat::rotation(1.0, Vec3::new(0.0, 1.0, 0.0));
+    /// let l = q.ln();
+    ///
+    /// assert!((l.y - 0.5).abs() < 1e-6);
+    /// assert!(l.w.abs() < 1e-6);
+    /// assert_eq!(Quat::identity().ln(), (0.0, 0.0, 0.0, 0.0).into());
+    /// ```
+    pub fn ln(&self) -> Quat {
+        let length = self.length();
+        let vector_length = (self.x * self.x + self.y * self.y + self.z * self.z).sqrt();
+
+        let s = if vector_length > f32::EPSILON {
+            vector_length.atan2(self.w) / vector_length
+        } else {
+            0.0
+        };
+
+        Quat {
+            x: self.x * s,
+            y: self.y * s,
+            z: self.z * s,
+            w: length.ln(),
+        }
+    }
+
+    /// Calculates and returns the exponential of the calling `Quat` object, the inverse of
+    /// `Quat::ln`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gamemath::{Quat, Vec3};
+    ///
+    /// let q = Quat::rotation(1.0, Vec3::new(0.0, 1.0, 0.0));
+    /// let e = q.ln().exp();
+    ///
+    /// assert!((e - q).length() < 1e-6);
+    /// assert_eq!(Quat::from((0.0, 0.0, 0.0, 0.0)).exp(), Quat::identity());
+    /// ```
+    pub fn exp(&self) -> Quat {
+        let angle = (self.x * self.x + self.y * self.y + self.z * self.z).sqrt();
+        let scale = self.w.exp();
+
+        let s = if angle > f32::EPSILON {
+            scale * angle.sin() / angle
+        } else {
+            scale
+        };
+
+        Quat {
+            x: self.x * s,
+            y: self.y * s,
+            z: self.z * s,
+            w: scale * angle.cos(),
+        }
+    }
+
+    /// Raises the calling `Quat` object to a power, calculated as `(q.ln() * t).exp()`.
+    /// For a unit quaternion this scales the angle of the rotation it represents by `t`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gamemath::{Quat, Vec3};
+    ///
+    /// let q = Quat::rotation(1.2, Vec3::new(1.0, 2.0, 3.0));
+    ///
+    /// assert!((q.pow(0.5).pow(2.0) - q).length() < 1e-6);
+    /// assert!((q.pow(1.0) - q).length() < 1e-6);
+    /// assert!((q.pow(0.5) - Quat::rotation(0.6, Vec3::new(1.0, 2.0, 3.0))).length() < 1e-6);
+    /// ```
+    pub fn pow(&self, t: f32) -> Quat {
+        (self.ln() * t).exp()
+    }
+
+    /// Spherically interpolates between the calling `Quat` object and a target `Quat` by a
+    /// factor, always taking the shortest path between the two rotations.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gamemath::{Quat, Vec3};
+    ///
+    /// let a = Quat::rotation(0.0, Vec3::new(0.0, 0.0, 1.0));
+    /// let b = Quat::rotation(2.0, Vec3::new(0.0, 0.0, 1.0));
+    /// let c = Quat::rotation(0.5, Vec3::new(0.0, 0.0, 1.0));
+    ///
+    /// assert!((a.slerp(b, 0.25) - c).length() < 1e-6);
+    /// assert!((a.slerp(b, 1.0) - b).length() < 1e-6);
+    /// ```
+    pub fn slerp(&self, target: Quat, factor: f32) -> Quat {
+        let mut target = target;
+        let mut cos_angle =
+            self.x * target.x + self.y * target.y + self.z * target.z + self.w * target.w;
+
+        if cos_angle < 0.0 {
+            target = target * -1.0;
+            cos_angle = -cos_angle;
+        }
+
+        let sin_angle = (1.0 - cos_angle * cos_angle).max(0.0).sqrt();
+
+        if sin_angle < f32::EPSILON {
+            return (*self + (target - *self) * factor).normalized();
+        }
+
+        let angle = sin_angle.atan2(cos_angle);
+        let a = ((1.0 - factor) * angle).sin() / sin_angle;
+        let b = (factor * angle).sin() / sin_angle;
+
+        *self * a + target * b
+    }
+
+    /// Spherically interpolates between the calling `Quat` object and a target `Quat` like
+    /// `slerp`, but clamps the factor into the range `[0, 1]` and returns the normalized endpoints
+    /// exactly at the bounds, instead of values that may have drifted slightly.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gamemath::{Quat, Vec3};
+    ///
+    /// let a = Quat::rotation(0.3, Vec3::new(1.0, 0.0, 0.0));
+    /// let b = Quat::rotation(1.7, Vec3::new(0.0, 1.0, 1.0));
+    ///
+    /// assert_eq!(a.slerp_clamped(b, 0.0), a.normalized());
+    /// assert_eq!(a.slerp_clamped(b, 1.0), b.normalized());
+    /// assert_eq!(a.slerp_clamped(b, -0.5), a.normalized());
+    /// assert_eq!(a.slerp_clamped(b, 1.5), b.normalized());
+    /// assert_eq!(a.slerp_clamped(b, 0.5), a.slerp(b, 0.5));
+    /// ```
+    pub fn slerp_clamped(&self, target: Quat, factor: f32) -> Quat {
+        if factor <= 0.0 {
+            self.normalized()
+        } else if factor >= 1.0 {
+            target.normalized()
+        } else {
+            self.slerp(target, factor)
+        }
+    }
+
+    /// Calculates the intermediate control quaternion for `current` in a sequence of unit
+    /// quaternions `previous`, `current` and `next`, for use with `Quat::squad`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gamemath::{Quat, Vec3};
+    ///
+    /// let axis = Vec3::new(0.0, 1.0, 0.0);
+    /// let s = Quat::squad_control(Quat::rotation(0.0, axis),
+    ///                             Quat::rotation(0.5, axis),
+    ///                             Quat::rotation(1.0, axis));
+    ///
+    /// assert!((s - Quat::rotation(0.5, axis)).length() < 1e-6);
+    /// ```
+    pub fn squad_control(previous: Quat, current: Quat, next: Quat) -> Quat {
+        let inverse = Quat {
+            x: -current.x,
+            y: -current.y,
+            z: -current.z,
+            w: current.w,
+        };
+
+        let to_previous = (previous * inverse).ln();
+        let to_next = (next * inverse).ln();
+
+        ((to_previous + to_next) * -0.25).exp() * current
+    }
+
+    /// Interpolates along a spherical quadrangle from `q0` to `q3` by a factor, using `q1` and
+    /// `q2` as inner control quaternions. The curve passes through `q0` at a factor of `0.0` and
+    /// `q3` at a factor of `1.0`, while `q1` and `q2` shape the tangents at either end.
+    ///
+    /// To build a smooth spline through a sequence of keyframes, interpolate between each pair
+    /// of keys `k[i]` and `k[i + 1]` with `Quat::squad_control(k[i - 1], k[i], k[i + 1])` and
+    /// `Quat::squad_control(k[i], k[i + 1], k[i + 2])` as the inner control quaternions.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gamemath::{Quat, Vec3};
+    ///
+    /// let a = Quat::rotation(0.3, Vec3::new(1.0, 0.0, 0.0));
+    /// let b = Quat::rotation(1.7, Vec3::new(0.0, 1.0, 1.0));
+    ///
+    /// for i in 0..=10 {
+    ///     let t = i as f32 / 10.0;
+    ///
+    ///     assert!((Quat::squad(a, a, b, b, t) - a.slerp(b, t)).length() < 1e-6);
+    /// }
+    /// ```
+    pub fn squad(q0: Quat, q1: Quat, q2: Quat, q3: Quat, factor: f32) -> Quat {
+        let outer = q0.slerp(q3, factor);
+        let inner = q1.slerp(q2, factor);
+
+        outer.slerp(inner, 2.0 * factor * (1.0 - factor))
+    }
+
+    /// Blends a slice of weighted quaternions into a single unit `Quat`, by accumulating their
+    /// weighted components and normalizing the result. Each quaternion is flipped onto the same
+    /// hemisphere as the first one before accumulating, so that equivalent rotations don't
+    /// cancel out. An empty slice results in an identity quaternion.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gamemath::{Quat, Vec3};
+    ///
+    /// let q = Quat::rotation(1.0, Vec3::new(1.0, 2.0, 3.0));
+    /// let a = Quat::rotation(0.0, Vec3::new(0.0, 1.0, 0.0));
+    /// let b = Quat::rotation(1.0, Vec3::new(0.0, 1.0, 0.0));
+    ///
+    /// assert!(Quat::blend(&[(q, 0.5), (q, 0.5)]).approx_eq(q, 0.000001));
+    /// assert!(Quat::blend(&[(q, 0.5), (q * -1.0, 0.5)]).approx_eq(q, 0.000001));
+    /// assert!(Quat::blend(&[(a, 0.5), (b, 0.5)]).approx_eq(a.slerp(b, 0.5), 0.000001));
+    /// assert_eq!(Quat::blend(&[]), Quat::identity());
+    /// ```
+    pub fn blend(quats: &[(Quat, f32)]) -> Quat {
+        let first = match quats.first() {
+            Some(&(quat, _)) => quat,
+            None => return Quat::identity(),
+        };
+
+        let mut result: Quat = 0.0.into();
+
+        for &(quat, weight) in quats {
+            let dot = first.x * quat.x + first.y * quat.y + first.z * quat.z + first.w * quat.w;
+
+            if dot < 0.0 {
+                result += quat * -weight;
+            } else {
+                result += quat * weight;
+            }
+        }
+
+        result.normalized()
+    }
+
     /// Calculates and returns a `Mat4` object representing the rotation of the calling `Quat`
     /// object.
     ///
+    /// The resulting matrix follows the same layout as `Mat4::rotation`, so a quaternion and a
+    /// matrix constructed from the same angle and axis represent the same rotation.
+    ///
     /// # Examples
     ///
     /// ```
@@ -171,10 +501,24 @@ impl Quat {
     ///
     /// let q = Quat::rotation(1.0, Vec3::new(1.0, 2.0, 3.0));
     ///
-    /// assert_eq!(q.extract_matrix(), (( 0.5731379,  0.74034876, -0.35127854, 0.0),
-    ///                                 (-0.6090066,  0.67164457,  0.42190588, 0.0),
-    ///                                 ( 0.5482918, -0.027879298, 0.8358222,  0.0),
-    ///                                 ( 0.0,        0.0,         0.0,        1.0)).into());
+    /// let m: Mat4 = (( 0.5731379,  0.74034876, -0.35127854, 0.0),
+    ///                (-0.6090066,  0.67164457,  0.42190588, 0.0),
+    ///                ( 0.5482918, -0.027879298, 0.8358222,  0.0),
+    ///                ( 0.0,        0.0,         0.0,        1.0)).into();
+    ///
+    /// assert!(q.extract_matrix().approx_eq(m, 0.000001));
+    ///
+    /// let pairs = [(1.0, Vec3::new(1.0, 2.0, 3.0)),
+    ///              (0.5, Vec3::new(0.0, 0.0, 1.0)),
+    ///              (-2.0, Vec3::new(1.0, 0.0, 0.0)),
+    ///              (3.0, Vec3::new(-4.0, 1.0, 0.5))];
+    ///
+    /// for &(radians, axis) in pairs.iter() {
+    ///     let a = Quat::rotation(radians, axis).extract_matrix();
+    ///     let b = Mat4::rotation(radians, axis);
+    ///
+    ///     assert!(a.approx_eq(b, 0.000001));
+    /// }
     /// ```
     pub fn extract_matrix(&self) -> Mat4 {
         let mut result = Mat4::identity();
@@ -207,6 +551,102 @@ impl Quat {
 
         result
     }
+
+    /// Constructs a unit `Quat` from a `Mat3` holding a pure rotation, laid out like the upper
+    /// 3x3 part of the matrices returned by `Quat::extract_matrix` and `Mat4::rotation`.
+    /// The returned quaternion always has a non-negative `w` component.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gamemath::{Mat3, Quat, Vec3};
+    ///
+    /// let pairs = [(1.0, Vec3::new(1.0, 2.0, 3.0)),
+    ///              (0.5, Vec3::new(0.0, 0.0, 1.0)),
+    ///              (-2.0, Vec3::new(1.0, 0.0, 0.0)),
+    ///              (3.0, Vec3::new(-4.0, 1.0, 0.5))];
+    ///
+    /// for &(radians, axis) in pairs.iter() {
+    ///     let q = Quat::rotation(radians, axis);
+    ///     let m = q.extract_matrix();
+    ///     let r: Mat3 = ((m[0][0], m[0][1], m[0][2]),
+    ///                    (m[1][0], m[1][1], m[1][2]),
+    ///                    (m[2][0], m[2][1], m[2][2])).into();
+    ///
+    ///     assert!(Quat::from_mat3(r).approx_eq(q, 0.000001));
+    ///     assert!(Quat::from_mat3(r).extract_matrix().approx_eq(m, 0.000001));
+    /// }
+    /// ```
+    pub fn from_mat3(matrix: Mat3) -> Quat {
+        let m = matrix;
+        let trace = m[0][0] + m[1][1] + m[2][2];
+
+        let result = if trace > 0.0 {
+            let s = (trace + 1.0).sqrt() * 2.0;
+
+            Quat {
+                x: (m[1][2] - m[2][1]) / s,
+                y: (m[2][0] - m[0][2]) / s,
+                z: (m[0][1] - m[1][0]) / s,
+                w: 0.25 * s,
+            }
+        } else if m[0][0] > m[1][1] && m[0][0] > m[2][2] {
+            let s = (1.0 + m[0][0] - m[1][1] - m[2][2]).sqrt() * 2.0;
+
+            Quat {
+                x: 0.25 * s,
+                y: (m[1][0] + m[0][1]) / s,
+                z: (m[2][0] + m[0][2]) / s,
+                w: (m[1][2] - m[2][1]) / s,
+            }
+        } else if m[1][1] > m[2][2] {
+            let s = (1.0 + m[1][1] - m[0][0] - m[2][2]).sqrt() * 2.0;
+
+            Quat {
+                x: (m[1][0] + m[0][1]) / s,
+                y: 0.25 * s,
+                z: (m[2][1] + m[1][2]) / s,
+                w: (m[2][0] - m[0][2]) / s,
+            }
+        } else {
+            let s = (1.0 + m[2][2] - m[0][0] - m[1][1]).sqrt() * 2.0;
+
+            Quat {
+                x: (m[2][0] + m[0][2]) / s,
+                y: (m[2][1] + m[1][2]) / s,
+                z: 0.25 * s,
+                w: (m[0][1] - m[1][0]) / s,
+            }
+        };
+
+        if result.w < 0.0 {
+            result * -1.0
+        } else {
+            result
+        }
+    }
+
+    /// Rotates a `Vec3<f32>` by the calling `Quat`, which is expected to be normalized. This is
+    /// equivalent to multiplying the vector by the rotation matrix of the quaternion, without
+    /// having to construct the matrix.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gamemath::{Quat, Vec3};
+    ///
+    /// let q = Quat::rotation(1.0, Vec3::new(1.0, 2.0, 3.0));
+    /// let v = Vec3::new(4.0, -5.0, 6.0);
+    /// let expected = q.extract_matrix().transform_vector(v);
+    ///
+    /// assert!((q.rotate_vector(v) - expected).length() < 1e-5);
+    /// ```
+    pub fn rotate_vector(&self, vector: Vec3<f32>) -> Vec3<f32> {
+        let axis = Vec3::new(self.x, self.y, self.z);
+        let t = axis.cross(vector) * 2.0;
+
+        vector + t * self.w + axis.cross(t)
+    }
 }
 
 impl Default for Quat {
@@ -264,6 +704,54 @@ impl From<[f32; 4]> for Quat {
     }
 }
 
+impl From<Mat3> for Quat {
+    fn from(matrix: Mat3) -> Quat {
+        Quat::from_mat3(matrix)
+    }
+}
+
+/// Indexes the components of a `Quat` in the order x, y, z and w, the same way as a `Vec4`.
+///
+/// # Examples
+///
+/// ```
+/// use gamemath::Quat;
+///
+/// let mut q: Quat = (1.0, 2.0, 3.0, 4.0).into();
+///
+/// assert_eq!(q[0], 1.0);
+/// assert_eq!(q[3], q.w);
+///
+/// q[3] = 5.0;
+///
+/// assert_eq!(q.w, 5.0);
+/// ```
+impl Index<usize> for Quat {
+    type Output = f32;
+
+    fn index(&self, index: usize) -> &f32 {
+        match index {
+            0 => &self.x,
+            1 => &self.y,
+            2 => &self.z,
+            3 => &self.w,
+            _ => panic!("Quat index out of range!"),
+        }
+    }
+}
+
+impl IndexMut<usize> for Quat {
+    fn index_mut(&mut self, index: usize) -> &mut f32 {
+        match index {
+            0 => &mut self.x,
+            1 => &mut self.y,
+            2 => &mut self.z,
+            3 => &mut self.w,
+            _ => panic!("Quat index out of range!"),
+        }
+    }
+}
+
 impl Mul<Quat> for Quat {
     type Output = Quat;
 
@@ -283,6 +771,26 @@ impl MulAssign<Quat> for Quat {
     }
 }
 
+/// Rotates a `Vec3<f32>` by a `Quat`, the same way as `Quat::rotate_vector`.
+///
+/// # Examples
+///
+/// ```
+/// use gamemath::{Quat, Vec3};
+///
+/// let q = Quat::rotation(core::f32::consts::FRAC_PI_2, Vec3::new(0.0, 0.0, 1.0));
+/// let v = q * Vec3::new(1.0, 0.0, 0.0);
+///
+/// assert!((v - Vec3::new(0.0, 1.0, 0.0)).length() < 1e-6);
+/// ```
+impl Mul<Vec3<f32>> for Quat {
+    type Output = Vec3<f32>;
+
+    fn mul(self, right: Vec3<f32>) -> Vec3<f32> {
+        self.rotate_vector(right)
+    }
+}
+
 impl Add<Quat> for Quat {
     type Output = Quat;
 
@@ -301,3 +809,120 @@ impl AddAssign<Quat> for Quat {
         *self = *self + right;
     }
 }
+
+/// Subtracts the components of one `Quat` from another, component-wise.
+///
+/// # Examples
+///
+/// ```
+/// use gamemath::Quat;
+///
+/// let a: Quat = (1.0, 2.0, 3.0, 4.0).into();
+/// let b: Quat = (0.5, 0.5, 1.0, 1.0).into();
+///
+/// assert_eq!(a - b, (0.5, 1.5, 2.0, 3.0).into());
+/// ```
+impl Sub<Quat> for Quat {
+    type Output = Quat;
+
+    fn sub(self, right: Quat) -> Quat {
+        Quat {
+            x: self.x - right.x,
+            y: self.y - right.y,
+            z: self.z - right.z,
+            w: self.w - right.w,
+        }
+    }
+}
+
+/// Subtracts the components of one `Quat` from another in place, component-wise.
+///
+/// # Examples
+///
+/// ```
+/// use gamemath::Quat;
+///
+/// let mut q: Quat = (1.0, 2.0, 3.0, 4.0).into();
+///
+/// q -= (0.5, 0.5, 1.0, 1.0).into();
+///
+/// assert_eq!(q, (0.5, 1.5, 2.0, 3.0).into());
+/// ```
+impl SubAssign<Quat> for Quat {
+    fn sub_assign(&mut self, right: Quat) {
+        *self = *self - right;
+    }
+}
+
+/// Negates every component of a `Quat`. The negated quaternion represents the same rotation.
+///
+/// # Examples
+///
+/// ```
+/// use gamemath::Quat;
+///
+/// let q: Quat = (1.0, -2.0, 3.0, 4.0).into();
+///
+/// assert_eq!(-q, (-1.0, 2.0, -3.0, -4.0).into());
+/// assert_eq!(-Quat::identity(), (0.0, 0.0, 0.0, -1.0).into());
+/// ```
+impl Neg for Quat {
+    type Output = Quat;
+
+    fn neg(self) -> Quat {
+        Quat {
+            x: -self.x,
+            y: -self.y,
+            z: -self.z,
+            w: -self.w,
+        }
+    }
+}
+
+/// Multiplies every component of a `Quat` by a scalar value.
+///
+/// # Examples
+///
+/// ```
+/// use gamemath::Quat;
+///
+/// let q: Quat = (1.0, 2.0, 3.0, 4.0).into();
+///
+/// assert_eq!(q * 0.5, (0.5, 1.0, 1.5, 2.0).into());
+/// ```
+impl Mul<f32> for Quat {
+    type Output = Quat;
+
+    fn mul(self, right: f32) -> Quat {
+        Quat {
+            x: self.x * right,
+            y: self.y * right,
+            z: self.z * right,
+            w: self.w * right,
+        }
+    }
+}
+
+/// Divides every component of a `Quat` by a scalar value.
+///
+/// # Examples
+///
+/// ```
+/// use gamemath::Quat;
+///
+/// let q: Quat = (1.0, 2.0, 3.0, 4.0).into();
+///
+/// assert_eq!(q / 2.0, (0.5, 1.0, 1.5, 2.0).into());
+/// ```
+impl Div<f32> for Quat {
+    type Output = Quat;
+
+    fn div(self, right: f32) -> Quat {
+        Quat {
+            x: self.x / right,
+            y: self.y / right,
+            z: self.z / right,
+            w: self.w / right,
+        }
+    }
+}
@@ -0,0 +1,929 @@
+use core::ops::{Add, AddAssign, Div, Index, IndexMut, Mul, MulAssign, Neg, Sub, SubAssign};
+use mat3d::Mat3d;
+use mat4d::Mat4d;
+#[cfg(all(not(feature = "std"), not(test)))]
+use math::Float;
+use vec3::Vec3;
+use vec4::Vec4;
+
+/// A quaternion data type of `f64` components, mirroring `Quat` for computations that need double
+/// precision.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Quatd {
+    /// The X/first component of the quaternion.
+    pub x: f64,
+    /// The Y/second component of the quaternion.
+    pub y: f64,
+    /// The Z/third component of the quaternion.
+    pub z: f64,
+    /// The W/fourth component of the quaternion.
+    pub w: f64,
+}
+
+impl Quatd {
+    /// Constructs an identity quaternion.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gamemath::Quatd;
+    ///
+    /// let q = Quatd::identity();
+    ///
+    /// assert_eq!(q, (0.0, 0.0, 0.0, 1.0).into());
+    /// ```
+    pub fn identity() -> Quatd {
+        Self::default()
+    }
+
+    /// Constructs a rotation quaternion from an angle and an axis.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gamemath::{Vec3, Quatd};
+    ///
+    /// let q = Quatd::rotation(1.0, Vec3::new(1.0, 2.0, 3.0));
+    ///
+    /// assert_eq!(q, (0.12813186485189226, 0.2562637297037845, 0.3843955945556768, 0.8775825618903728).into());
+    /// ```
+    pub fn rotation(radians: f64, axis: Vec3<f64>) -> Quatd {
+        let a = axis.normalized();
+        let r = radians / 2.0;
+        let s = r.sin();
+
+        Quatd {
+            x: a.x * s,
+            y: a.y * s,
+            z: a.z * s,
+            w: r.cos(),
+        }
+    }
+
+    /// Calculate and returns a quaternion representing the calling object rotated by an angle
+    /// around an axis.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gamemath::{Vec3, Quatd};
+    ///
+    /// let q = Quatd::identity();
+    ///
+    /// assert_eq!(q.rotated(1.0, Vec3::new(1.0, 2.0, 3.0)), (0.12813186485189226, 0.2562637297037845, 0.3843955945556768, 0.8775825618903728).into());
+    /// ```
+    pub fn rotated(&self, radians: f64, axis: Vec3<f64>) -> Quatd {
+        *self * Quatd::rotation(radians, axis)
+    }
+
+    /// Applies a rotation around and axis by an angle on the calling `Quatd` object.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gamemath::{Vec3, Quatd};
+    ///
+    /// let mut q = Quatd::identity();
+    ///
+    /// q.rotate(1.0, Vec3::new(1.0, 2.0, 3.0));
+    ///
+    /// assert_eq!(q, (0.12813186485189226, 0.2562637297037845, 0.3843955945556768, 0.8775825618903728).into());
+    /// ```
+    pub fn rotate(&mut self, radians: f64, axis: Vec3<f64>) {
+        *self *= Quatd::rotation(radians, axis);
+    }
+
+    /// Extracts the axis and the angle in radians of the rotation represented by the calling
+    /// `Quatd`, which is expected to be normalized. The angle is in the range `[0, 2π]`. A `Quatd`
+    /// without rotation returns the x axis and an angle of zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gamemath::{Quatd, Vec3};
+    ///
+    /// let axis = Vec3::new(1.0_f64, 2.0_f64, 3.0_f64).normalized();
+    /// let (a, radians) = Quatd::rotation(1.2, axis).to_axis_angle();
+    ///
+    /// assert!((a - axis).length() < 0.000001);
+    /// assert!((radians - 1.2).abs() < 0.000001);
+    /// assert_eq!(Quatd::identity().to_axis_angle(), (Vec3::new(1.0, 0.0, 0.0), 0.0));
+    /// ```
+    pub fn to_axis_angle(&self) -> (Vec3<f64>, f64) {
+        let axis = Vec3::new(self.x, self.y, self.z);
+        let sin_half_angle = axis.length();
+
+        if sin_half_angle < f64::EPSILON {
+            return (Vec3::new(1.0, 0.0, 0.0), 0.0);
+        }
+
+        (axis / sin_half_angle, 2.0 * sin_half_angle.atan2(self.w))
+    }
+
+    /// Calculates the squared length/magnitude/norm of a `Quatd`.
+    /// This saves an expensive square root calculation compared to calculating the actual length,
+    /// and comparing two squared lengths can therefore often be cheaper than, and yield the same
+    /// result as, computing two real lengths.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gamemath::Quatd;
+    ///
+    /// let q: Quatd = (1.0, 2.0, 3.0, 4.0).into();
+    ///
+    /// assert_eq!(q.length_squared(), 30.0);
+    pub fn length_squared(&self) -> f64 {
+        self.x * self.x + self.y * self.y + self.z * self.z + self.w * self.w
+    }
+
+    /// Calculates the real length/magnitude/norm of a `Quatd`.
+    /// This results in an expensive square root calculation, and you might want to consider using
+    /// a squared length instead when possible.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gamemath::Quatd;
+    ///
+    /// let q: Quatd = (1.0, 4.0, 4.0, 16.0).into();
+    ///
+    /// assert_eq!(q.length(), 17.0);
+    pub fn length(&self) -> f64 {
+        self.length_squared().sqrt()
+    }
+
+    /// Calculates and returns the unit quaternion representation of a `Quatd`.
+    /// This results in an an expensive square root calculation.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gamemath::Quatd;
+    ///
+    /// let q: Quatd = (1.0, 2.0, 2.0, 4.0).into();
+    ///
+    /// assert_eq!(q.normalized(), (0.2, 0.4, 0.4, 0.8).into());
+    pub fn normalized(&self) -> Quatd {
+        let f = 1.0 / self.length();
+
+        Quatd {
+            x: self.x * f,
+            y: self.y * f,
+            z: self.z * f,
+            w: self.w * f,
+        }
+    }
+
+    /// Normalizes a `Quatd` into its unit quaternion representation.
+    /// This results in an an expensive square root calculation.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gamemath::Quatd;
+    ///
+    /// let mut q: Quatd = (1.0, 2.0, 2.0, 4.0).into();
+    ///
+    /// q.normalize();
+    ///
+    /// assert_eq!(q, (0.2, 0.4, 0.4, 0.8).into());
+    pub fn normalize(&mut self) {
+        *self = self.normalized();
+    }
+
+    /// Returns `true` if every component of the `Quatd` is neither infinite nor NaN.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gamemath::Quatd;
+    ///
+    /// assert!(Quatd::identity().is_finite());
+    /// assert!(!Quatd::from((0.0, f64::NAN, 0.0, 1.0)).is_finite());
+    /// ```
+    pub fn is_finite(&self) -> bool {
+        self.x.is_finite() && self.y.is_finite() && self.z.is_finite() && self.w.is_finite()
+    }
+
+    /// Returns `true` if any component of the `Quatd` is NaN.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gamemath::Quatd;
+    ///
+    /// assert!(Quatd::from((0.0, f64::NAN, 0.0, 1.0)).is_nan());
+    /// assert!(!Quatd::identity().is_nan());
+    /// ```
+    pub fn is_nan(&self) -> bool {
+        self.x.is_nan() || self.y.is_nan() || self.z.is_nan() || self.w.is_nan()
+    }
+
+    /// Returns `true` if every component of the calling `Quatd` object is within an epsilon of
+    /// the corresponding component of another `Quatd`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gamemath::Quatd;
+    ///
+    /// let a: Quatd = (0.1, 0.2, 0.3, 0.9).into();
+    /// let b: Quatd = (0.1, 0.2, 0.3000001, 0.9).into();
+    ///
+    /// assert!(a.approx_eq(b, 0.000001));
+    /// assert!(!a.approx_eq((0.1, 0.2, 0.31, 0.9).into(), 0.000001));
+    /// ```
+    pub fn approx_eq(&self, other: Quatd, epsilon: f64) -> bool {
+        (self.x - other.x).abs() <= epsilon
+            && (self.y - other.y).abs() <= epsilon
+            && (self.z - other.z).abs() <= epsilon
+            && (self.w - other.w).abs() <= epsilon
+    }
+
+    /// Calculates and returns the natural logarithm of the calling `Quatd` object.
+    /// For a unit quaternion this is a pure quaternion holding the rotation axis scaled by half
+    /// the rotation angle.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gamemath::{Quatd, Vec3};
+    ///
+    /// let q = Quatd::rotation(1.0, Vec3::new(0.0, 1.0, 0.0));
+    /// let l = q.ln();
+    ///
+    /// assert!((l.y - 0.5).abs() < 1e-6);
+    /// assert!(l.w.abs() < 1e-6);
+    /// assert_eq!(Quatd::identity().ln(), (0.0, 0.0, 0.0, 0.0).into());
+    /// ```
+    pub fn ln(&self) -> Quatd {
+        let length = self.length();
+        let vector_length = (self.x * self.x + self.y * self.y + self.z * self.z).sqrt();
+
+        let s = if vector_length > f64::EPSILON {
+            vector_length.atan2(self.w) / vector_length
+        } else {
+            0.0
+        };
+
+        Quatd {
+            x: self.x * s,
+            y: self.y * s,
+            z: self.z * s,
+            w: length.ln(),
+        }
+    }
+
+    /// Calculates and returns the exponential of the calling `Quatd` object, the inverse of
+    /// `Quatd::ln`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gamemath::{Quatd, Vec3};
+    ///
+    /// let q = Quatd::rotation(1.0, Vec3::new(0.0, 1.0, 0.0));
+    /// let e = q.ln().exp();
+    ///
+    /// assert!((e - q).length() < 1e-6);
+    /// assert_eq!(Quatd::from((0.0, 0.0, 0.0, 0.0)).exp(), Quatd::identity());
+    /// ```
+    pub fn exp(&self) -> Quatd {
+        let angle = (self.x * self.x + self.y * self.y + self.z * self.z).sqrt();
+        let scale = self.w.exp();
+
+        let s = if angle > f64::EPSILON {
+            scale * angle.sin() / angle
+        } else {
+            scale
+        };
+
+        Quatd {
+            x: self.x * s,
+            y: self.y * s,
+            z: self.z * s,
+            w: scale * angle.cos(),
+        }
+    }
+
+    /// Raises the calling `Quatd` object to a power, calculated as `(q.ln() * t).exp()`.
+    /// For a unit quaternion this scales the angle of the rotation it represents by `t`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gamemath::{Quatd, Vec3};
+    ///
+    /// let q = Quatd::rotation(1.2, Vec3::new(1.0, 2.0, 3.0));
+    ///
+    /// assert!((q.pow(0.5).pow(2.0) - q).length() < 1e-6);
+    /// assert!((q.pow(1.0) - q).length() < 1e-6);
+    /// assert!((q.pow(0.5) - Quatd::rotation(0.6, Vec3::new(1.0, 2.0, 3.0))).length() < 1e-6);
+    /// ```
+    pub fn pow(&self, t: f64) -> Quatd {
+        (self.ln() * t).exp()
+    }
+
+    /// Spherically interpolates between the calling `Quatd` object and a target `Quatd` by a
+    /// factor, always taking the shortest path between the two rotations.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gamemath::{Quatd, Vec3};
+    ///
+    /// let a = Quatd::rotation(0.0, Vec3::new(0.0, 0.0, 1.0));
+    /// let b = Quatd::rotation(2.0, Vec3::new(0.0, 0.0, 1.0));
+    /// let c = Quatd::rotation(0.5, Vec3::new(0.0, 0.0, 1.0));
+    ///
+    /// assert!((a.slerp(b, 0.25) - c).length() < 1e-6);
+    /// assert!((a.slerp(b, 1.0) - b).length() < 1e-6);
+    /// ```
+    pub fn slerp(&self, target: Quatd, factor: f64) -> Quatd {
+        let mut target = target;
+        let mut cos_angle =
+            self.x * target.x + self.y * target.y + self.z * target.z + self.w * target.w;
+
+        if cos_angle < 0.0 {
+            target = target * -1.0;
+            cos_angle = -cos_angle;
+        }
+
+        let sin_angle = (1.0 - cos_angle * cos_angle).max(0.0).sqrt();
+
+        if sin_angle < f64::EPSILON {
+            return (*self + (target - *self) * factor).normalized();
+        }
+
+        let angle = sin_angle.atan2(cos_angle);
+        let a = ((1.0 - factor) * angle).sin() / sin_angle;
+        let b = (factor * angle).sin() / sin_angle;
+
+        *self * a + target * b
+    }
+
+    /// Spherically interpolates between the calling `Quatd` object and a target `Quatd` like
+    /// `slerp`, but clamps the factor into the range `[0, 1]` and returns the normalized endpoints
+    /// exactly at the bounds, instead of values that may have drifted slightly.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gamemath::{Quatd, Vec3};
+    ///
+    /// let a = Quatd::rotation(0.3, Vec3::new(1.0, 0.0, 0.0));
+    /// let b = Quatd::rotation(1.7, Vec3::new(0.0, 1.0, 1.0));
+    ///
+    /// assert_eq!(a.slerp_clamped(b, 0.0), a.normalized());
+    /// assert_eq!(a.slerp_clamped(b, 1.0), b.normalized());
+    /// assert_eq!(a.slerp_clamped(b, -0.5), a.normalized());
+    /// assert_eq!(a.slerp_clamped(b, 1.5), b.normalized());
+    /// assert_eq!(a.slerp_clamped(b, 0.5), a.slerp(b, 0.5));
+    /// ```
+    pub fn slerp_clamped(&self, target: Quatd, factor: f64) -> Quatd {
+        if factor <= 0.0 {
+            self.normalized()
+        } else if factor >= 1.0 {
+            target.normalized()
+        } else {
+            self.slerp(target, factor)
+        }
+    }
+
+    /// Calculates the intermediate control quaternion for `current` in a sequence of unit
+    /// quaternions `previous`, `current` and `next`, for use with `Quatd::squad`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gamemath::{Quatd, Vec3};
+    ///
+    /// let axis = Vec3::new(0.0, 1.0, 0.0);
+    /// let s = Quatd::squad_control(Quatd::rotation(0.0, axis),
+    ///                              Quatd::rotation(0.5, axis),
+    ///                              Quatd::rotation(1.0, axis));
+    ///
+    /// assert!((s - Quatd::rotation(0.5, axis)).length() < 1e-6);
+    /// ```
+    pub fn squad_control(previous: Quatd, current: Quatd, next: Quatd) -> Quatd {
+        let inverse = Quatd {
+            x: -current.x,
+            y: -current.y,
+            z: -current.z,
+            w: current.w,
+        };
+
+        let to_previous = (previous * inverse).ln();
+        let to_next = (next * inverse).ln();
+
+        ((to_previous + to_next) * -0.25).exp() * current
+    }
+
+    /// Interpolates along a spherical quadrangle from `q0` to `q3` by a factor, using `q1` and
+    /// `q2` as inner control quaternions. The curve passes through `q0` at a factor of `0.0` and
+    /// `q3` at a factor of `1.0`, while `q1` and `q2` shape the tangents at either end.
+    ///
+    /// To build a smooth spline through a sequence of keyframes, interpolate between each pair
+    /// of keys `k[i]` and `k[i + 1]` with `Quatd::squad_control(k[i - 1], k[i], k[i + 1])` and
+    /// `Quatd::squad_control(k[i], k[i + 1], k[i + 2])` as the inner control quaternions.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gamemath::{Quatd, Vec3};
+    ///
+    /// let a = Quatd::rotation(0.3, Vec3::new(1.0, 0.0, 0.0));
+    /// let b = Quatd::rotation(1.7, Vec3::new(0.0, 1.0, 1.0));
+    ///
+    /// for i in 0..=10 {
+    ///     let t = i as f64 / 10.0;
+    ///
+    ///     assert!((Quatd::squad(a, a, b, b, t) - a.slerp(b, t)).length() < 1e-6);
+    /// }
+    /// ```
+    pub fn squad(q0: Quatd, q1: Quatd, q2: Quatd, q3: Quatd, factor: f64) -> Quatd {
+        let outer = q0.slerp(q3, factor);
+        let inner = q1.slerp(q2, factor);
+
+        outer.slerp(inner, 2.0 * factor * (1.0 - factor))
+    }
+
+    /// Blends a slice of weighted quaternions into a single unit `Quatd`, by accumulating their
+    /// weighted components and normalizing the result. Each quaternion is flipped onto the same
+    /// hemisphere as the first one before accumulating, so that equivalent rotations don't
+    /// cancel out. An empty slice results in an identity quaternion.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gamemath::{Quatd, Vec3};
+    ///
+    /// let q = Quatd::rotation(1.0, Vec3::new(1.0, 2.0, 3.0));
+    /// let a = Quatd::rotation(0.0, Vec3::new(0.0, 1.0, 0.0));
+    /// let b = Quatd::rotation(1.0, Vec3::new(0.0, 1.0, 0.0));
+    ///
+    /// assert!(Quatd::blend(&[(q, 0.5), (q, 0.5)]).approx_eq(q, 0.000001));
+    /// assert!(Quatd::blend(&[(q, 0.5), (q * -1.0, 0.5)]).approx_eq(q, 0.000001));
+    /// assert!(Quatd::blend(&[(a, 0.5), (b, 0.5)]).approx_eq(a.slerp(b, 0.5), 0.000001));
+    /// assert_eq!(Quatd::blend(&[]), Quatd::identity());
+    /// ```
+    pub fn blend(quats: &[(Quatd, f64)]) -> Quatd {
+        let first = match quats.first() {
+            Some(&(quat, _)) => quat,
+            None => return Quatd::identity(),
+        };
+
+        let mut result: Quatd = 0.0.into();
+
+        for &(quat, weight) in quats {
+            let dot = first.x * quat.x + first.y * quat.y + first.z * quat.z + first.w * quat.w;
+
+            if dot < 0.0 {
+                result += quat * -weight;
+            } else {
+                result += quat * weight;
+            }
+        }
+
+        result.normalized()
+    }
+
+    /// Calculates and returns a `Mat4d` object representing the rotation of the calling `Quatd`
+    /// object.
+    ///
+    /// The resulting matrix follows the same layout as `Mat4d::rotation`, so a quaternion and a
+    /// matrix constructed from the same angle and axis represent the same rotation.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gamemath::{Vec3, Mat4d, Quatd};
+    ///
+    /// let q = Quatd::rotation(1.0, Vec3::new(1.0, 2.0, 3.0));
+    ///
+    /// let m: Mat4d = (( 0.573137855448987,   0.740348840460782,    -0.35127851212351696,  0.0),
+    ///                 (-0.6090066421373933,  0.6716445041915284,    0.4219058779181122,   0.0),
+    ///                 ( 0.5482918096085999, -0.027879282947946255,  0.8358222520957642,   0.0),
+    ///                 ( 0.0,                 0.0,                   0.0,                  1.0)).into();
+    ///
+    /// assert!(q.extract_matrix().approx_eq(m, 0.000001));
+    ///
+    /// let pairs = [(1.0, Vec3::new(1.0, 2.0, 3.0)),
+    ///              (0.5, Vec3::new(0.0, 0.0, 1.0)),
+    ///              (-2.0, Vec3::new(1.0, 0.0, 0.0)),
+    ///              (3.0, Vec3::new(-4.0, 1.0, 0.5))];
+    ///
+    /// for &(radians, axis) in pairs.iter() {
+    ///     let a = Quatd::rotation(radians, axis).extract_matrix();
+    ///     let b = Mat4d::rotation(radians, axis);
+    ///
+    ///     assert!(a.approx_eq(b, 0.000001));
+    /// }
+    /// ```
+    pub fn extract_matrix(&self) -> Mat4d {
+        let mut result = Mat4d::identity();
+        let x = self.x;
+        let y = self.y;
+        let z = self.z;
+        let w = self.w;
+        let x2 = x + x;
+        let y2 = y + y;
+        let z2 = z + z;
+        let xx = x * x2;
+        let yx = y * x2;
+        let yy = y * y2;
+        let zx = z * x2;
+        let zy = z * y2;
+        let zz = z * z2;
+        let wx = w * x2;
+        let wy = w * y2;
+        let wz = w * z2;
+
+        result[0][0] = 1.0 - yy - zz;
+        result[0][1] = yx + wz;
+        result[0][2] = zx - wy;
+        result[1][0] = yx - wz;
+        result[1][1] = 1.0 - xx - zz;
+        result[1][2] = zy + wx;
+        result[2][0] = zx + wy;
+        result[2][1] = zy - wx;
+        result[2][2] = 1.0 - xx - yy;
+
+        result
+    }
+
+    /// Constructs a unit `Quatd` from a `Mat3d` holding a pure rotation, laid out like the upper
+    /// 3x3 part of the matrices returned by `Quatd::extract_matrix` and `Mat4d::rotation`.
+    /// The returned quaternion always has a non-negative `w` component.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gamemath::{Mat3d, Quatd, Vec3};
+    ///
+    /// let pairs = [(1.0, Vec3::new(1.0, 2.0, 3.0)),
+    ///              (0.5, Vec3::new(0.0, 0.0, 1.0)),
+    ///              (-2.0, Vec3::new(1.0, 0.0, 0.0)),
+    ///              (3.0, Vec3::new(-4.0, 1.0, 0.5))];
+    ///
+    /// for &(radians, axis) in pairs.iter() {
+    ///     let q = Quatd::rotation(radians, axis);
+    ///     let m = q.extract_matrix();
+    ///     let r: Mat3d = ((m[0][0], m[0][1], m[0][2]),
+    ///                     (m[1][0], m[1][1], m[1][2]),
+    ///                     (m[2][0], m[2][1], m[2][2])).into();
+    ///
+    ///     assert!(Quatd::from_mat3(r).approx_eq(q, 0.000001));
+    ///     assert!(Quatd::from_mat3(r).extract_matrix().approx_eq(m, 0.000001));
+    /// }
+    /// ```
+    pub fn from_mat3(matrix: Mat3d) -> Quatd {
+        let m = matrix;
+        let trace = m[0][0] + m[1][1] + m[2][2];
+
+        let result = if trace > 0.0 {
+            let s = (trace + 1.0).sqrt() * 2.0;
+
+            Quatd {
+                x: (m[1][2] - m[2][1]) / s,
+                y: (m[2][0] - m[0][2]) / s,
+                z: (m[0][1] - m[1][0]) / s,
+                w: 0.25 * s,
+            }
+        } else if m[0][0] > m[1][1] && m[0][0] > m[2][2] {
+            let s = (1.0 + m[0][0] - m[1][1] - m[2][2]).sqrt() * 2.0;
+
+            Quatd {
+                x: 0.25 * s,
+                y: (m[1][0] + m[0][1]) / s,
+                z: (m[2][0] + m[0][2]) / s,
+                w: (m[1][2] - m[2][1]) / s,
+            }
+        } else if m[1][1] > m[2][2] {
+            let s = (1.0 + m[1][1] - m[0][0] - m[2][2]).sqrt() * 2.0;
+
+            Quatd {
+                x: (m[1][0] + m[0][1]) / s,
+                y: 0.25 * s,
+                z: (m[2][1] + m[1][2]) / s,
+                w: (m[2][0] - m[0][2]) / s,
+            }
+        } else {
+            let s = (1.0 + m[2][2] - m[0][0] - m[1][1]).sqrt() * 2.0;
+
+            Quatd {
+                x: (m[2][0] + m[0][2]) / s,
+                y: (m[2][1] + m[1][2]) / s,
+                z: 0.25 * s,
+                w: (m[0][1] - m[1][0]) / s,
+            }
+        };
+
+        if result.w < 0.0 {
+            result * -1.0
+        } else {
+            result
+        }
+    }
+
+    /// Rotates a `Vec3<f64>` by the calling `Quatd`, which is expected to be normalized. This is
+    /// equivalent to multiplying the vector by the rotation matrix of the quaternion, without
+    /// having to construct the matrix.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gamemath::{Quatd, Vec3};
+    ///
+    /// let q = Quatd::rotation(1.0, Vec3::new(1.0, 2.0, 3.0));
+    /// let v = Vec3::new(4.0, -5.0, 6.0);
+    /// let expected = q.extract_matrix().transform_vector(v);
+    ///
+    /// assert!((q.rotate_vector(v) - expected).length() < 1e-5);
+    /// ```
+    pub fn rotate_vector(&self, vector: Vec3<f64>) -> Vec3<f64> {
+        let axis = Vec3::new(self.x, self.y, self.z);
+        let t = axis.cross(vector) * 2.0;
+
+        vector + t * self.w + axis.cross(t)
+    }
+}
+
+impl Default for Quatd {
+    fn default() -> Quatd {
+        Quatd {
+            x: 0.0,
+            y: 0.0,
+            z: 0.0,
+            w: 1.0,
+        }
+    }
+}
+
+impl From<f64> for Quatd {
+    fn from(value: f64) -> Quatd {
+        Quatd {
+            x: 0.0,
+            y: 0.0,
+            z: 0.0,
+            w: value,
+        }
+    }
+}
+
+impl From<Vec4<f64>> for Quatd {
+    fn from(vec: Vec4<f64>) -> Quatd {
+        Quatd {
+            x: vec.x,
+            y: vec.y,
+            z: vec.z,
+            w: vec.w,
+        }
+    }
+}
+
+impl From<(f64, f64, f64, f64)> for Quatd {
+    fn from(tuple: (f64, f64, f64, f64)) -> Quatd {
+        Quatd {
+            x: tuple.0,
+            y: tuple.1,
+            z: tuple.2,
+            w: tuple.3,
+        }
+    }
+}
+
+impl From<[f64; 4]> for Quatd {
+    fn from(slice: [f64; 4]) -> Quatd {
+        Quatd {
+            x: slice[0],
+            y: slice[1],
+            z: slice[2],
+            w: slice[3],
+        }
+    }
+}
+
+impl From<Mat3d> for Quatd {
+    fn from(matrix: Mat3d) -> Quatd {
+        Quatd::from_mat3(matrix)
+    }
+}
+
+/// Indexes the components of a `Quatd` in the order x, y, z and w, the same way as a `Vec4`.
+///
+/// # Examples
+///
+/// ```
+/// use gamemath::Quatd;
+///
+/// let mut q: Quatd = (1.0, 2.0, 3.0, 4.0).into();
+///
+/// assert_eq!(q[0], 1.0);
+/// assert_eq!(q[3], q.w);
+///
+/// q[3] = 5.0;
+///
+/// assert_eq!(q.w, 5.0);
+/// ```
+impl Index<usize> for Quatd {
+    type Output = f64;
+
+    fn index(&self, index: usize) -> &f64 {
+        match index {
+            0 => &self.x,
+            1 => &self.y,
+            2 => &self.z,
+            3 => &self.w,
+            _ => panic!("Quatd index out of range!"),
+        }
+    }
+}
+
+impl IndexMut<usize> for Quatd {
+    fn index_mut(&mut self, index: usize) -> &mut f64 {
+        match index {
+            0 => &mut self.x,
+            1 => &mut self.y,
+            2 => &mut self.z,
+            3 => &mut self.w,
+            _ => panic!("Quatd index out of range!"),
+        }
+    }
+}
+
+impl Mul<Quatd> for Quatd {
+    type Output = Quatd;
+
+    fn mul(self, right: Quatd) -> Quatd {
+        Quatd {
+            x: (right.w * self.x) + (right.x * self.w) + (right.y * self.z) - (right.z * self.y),
+            y: (right.w * self.y) + (right.y * self.w) + (right.z * self.x) - (right.x * self.z),
+            z: (right.w * self.z) + (right.z * self.w) + (right.x * self.y) - (right.y * self.x),
+            w: (right.w * self.w) - (right.x * self.x) - (right.y * self.y) - (right.z * self.z),
+        }
+    }
+}
+
+impl MulAssign<Quatd> for Quatd {
+    fn mul_assign(&mut self, right: Quatd) {
+        *self = *self * right;
+    }
+}
+
+/// Rotates a `Vec3<f64>` by a `Quatd`, the same way as `Quatd::rotate_vector`.
+///
+/// # Examples
+///
+/// ```
+/// use gamemath::{Quatd, Vec3};
+///
+/// let q = Quatd::rotation(core::f64::consts::FRAC_PI_2, Vec3::new(0.0, 0.0, 1.0));
+/// let v = q * Vec3::new(1.0, 0.0, 0.0);
+///
+/// assert!((v - Vec3::new(0.0, 1.0, 0.0)).length() < 1e-6);
+/// ```
+impl Mul<Vec3<f64>> for Quatd {
+    type Output = Vec3<f64>;
+
+    fn mul(self, right: Vec3<f64>) -> Vec3<f64> {
+        self.rotate_vector(right)
+    }
+}
+
+impl Add<Quatd> for Quatd {
+    type Output = Quatd;
+
+    fn add(self, right: Quatd) -> Quatd {
+        Quatd {
+            x: self.x + right.x,
+            y: self.y + right.y,
+            z: self.z + right.z,
+            w: self.w + right.w,
+        }
+    }
+}
+
+impl AddAssign<Quatd> for Quatd {
+    fn add_assign(&mut self, right: Quatd) {
+        *self = *self + right;
+    }
+}
+
+/// Subtracts the components of one `Quatd` from another, component-wise.
+///
+/// # Examples
+///
+/// ```
+/// use gamemath::Quatd;
+///
+/// let a: Quatd = (1.0, 2.0, 3.0, 4.0).into();
+/// let b: Quatd = (0.5, 0.5, 1.0, 1.0).into();
+///
+/// assert_eq!(a - b, (0.5, 1.5, 2.0, 3.0).into());
+/// ```
+impl Sub<Quatd> for Quatd {
+    type Output = Quatd;
+
+    fn sub(self, right: Quatd) -> Quatd {
+        Quatd {
+            x: self.x - right.x,
+            y: self.y - right.y,
+            z: self.z - right.z,
+            w: self.w - right.w,
+        }
+    }
+}
+
+/// Subtracts the components of one `Quatd` from another in place, component-wise.
+///
+/// # Examples
+///
+/// ```
+/// use gamemath::Quatd;
+///
+/// let mut q: Quatd = (1.0, 2.0, 3.0, 4.0).into();
+///
+/// q -= (0.5, 0.5, 1.0, 1.0).into();
+///
+/// assert_eq!(q, (0.5, 1.5, 2.0, 3.0).into());
+/// ```
+impl SubAssign<Quatd> for Quatd {
+    fn sub_assign(&mut self, right: Quatd) {
+        *self = *self - right;
+    }
+}
+
+/// Negates every component of a `Quatd`. The negated quaternion represents the same rotation.
+///
+/// # Examples
+///
+/// ```
+/// use gamemath::Quatd;
+///
+/// let q: Quatd = (1.0, -2.0, 3.0, 4.0).into();
+///
+/// assert_eq!(-q, (-1.0, 2.0, -3.0, -4.0).into());
+/// assert_eq!(-Quatd::identity(), (0.0, 0.0, 0.0, -1.0).into());
+/// ```
+impl Neg for Quatd {
+    type Output = Quatd;
+
+    fn neg(self) -> Quatd {
+        Quatd {
+            x: -self.x,
+            y: -self.y,
+            z: -self.z,
+            w: -self.w,
+        }
+    }
+}
+
+/// Multiplies every component of a `Quatd` by a scalar value.
+///
+/// # Examples
+///
+/// ```
+/// use gamemath::Quatd;
+///
+/// let q: Quatd = (1.0, 2.0, 3.0, 4.0).into();
+///
+/// assert_eq!(q * 0.5, (0.5, 1.0, 1.5, 2.0).into());
+/// ```
+impl Mul<f64> for Quatd {
+    type Output = Quatd;
+
+    fn mul(self, right: f64) -> Quatd {
+        Quatd {
+            x: self.x * right,
+            y: self.y * right,
+            z: self.z * right,
+            w: self.w * right,
+        }
+    }
+}
+
+/// Divides every component of a `Quatd` by a scalar value.
+///
+/// # Examples
+///
+/// ```
+/// use gamemath::Quatd;
+///
+/// let q: Quatd = (1.0, 2.0, 3.0, 4.0).into();
+///
+/// assert_eq!(q / 2.0, (0.5, 1.0, 1.5, 2.0).into());
+/// ```
+impl Div<f64> for Quatd {
+    type Output = Quatd;
+
+    fn div(self, right: f64) -> Quatd {
+        Quatd {
+            x: self.x / right,
+            y: self.y / right,
+            z: self.z / right,
+            w: self.w / right,
+        }
+    }
+}
@@ -0,0 +1,121 @@
+//! Implementations of the `rand` crate's sampling traits, enabled through the `rand` feature.
+
+use core::f32::consts::PI;
+#[cfg(all(not(feature = "std"), not(test)))]
+use math::Float;
+use quat::Quat;
+use rand::distributions::{Distribution, Standard};
+use rand::Rng;
+use vec2::Vec2;
+use vec3::Vec3;
+use vec4::Vec4;
+
+/// A distribution sampling uniformly distributed unit `Vec3<f32>`s, which represent directions
+/// pointing anywhere on the unit sphere.
+///
+/// # Examples
+///
+/// ```
+/// # extern crate gamemath;
+/// # extern crate rand;
+/// use gamemath::{UnitSphere, Vec3};
+/// use rand::Rng;
+/// # use rand::rngs::mock::StepRng;
+///
+/// # let mut rng = StepRng::new(0, 0x9e37_79b9_7f4a_7c15);
+/// let v: Vec3<f32> = rng.sample(UnitSphere);
+///
+/// assert!((v.length() - 1.0).abs() < 0.00001);
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct UnitSphere;
+
+/// A distribution sampling uniformly distributed unit `Quat`s, which represent rotations with
+/// no bias towards any particular axis or angle.
+///
+/// # Examples
+///
+/// ```
+/// # extern crate gamemath;
+/// # extern crate rand;
+/// use gamemath::{Quat, UnitQuaternion};
+/// use rand::Rng;
+/// # use rand::rngs::mock::StepRng;
+///
+/// # let mut rng = StepRng::new(0, 0x9e37_79b9_7f4a_7c15);
+/// let q: Quat = rng.sample(UnitQuaternion);
+///
+/// assert!((q.length() - 1.0).abs() < 0.00001);
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct UnitQuaternion;
+
+impl<T> Distribution<Vec2<T>> for Standard
+where
+    Standard: Distribution<T>,
+{
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Vec2<T> {
+        Vec2 {
+            x: self.sample(rng),
+            y: self.sample(rng),
+        }
+    }
+}
+
+impl<T> Distribution<Vec3<T>> for Standard
+where
+    Standard: Distribution<T>,
+{
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Vec3<T> {
+        Vec3 {
+            x: self.sample(rng),
+            y: self.sample(rng),
+            z: self.sample(rng),
+        }
+    }
+}
+
+impl<T> Distribution<Vec4<T>> for Standard
+where
+    Standard: Distribution<T>,
+{
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Vec4<T> {
+        Vec4 {
+            x: self.sample(rng),
+            y: self.sample(rng),
+            z: self.sample(rng),
+            w: self.sample(rng),
+        }
+    }
+}
+
+impl Distribution<Vec3<f32>> for UnitSphere {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Vec3<f32> {
+        let z: f32 = rng.gen_range(-1.0..=1.0);
+        let angle: f32 = rng.gen_range(0.0..2.0 * PI);
+        let radius = (1.0 - z * z).max(0.0).sqrt();
+
+        Vec3 {
+            x: radius * angle.cos(),
+            y: radius * angle.sin(),
+            z,
+        }
+    }
+}
+
+impl Distribution<Quat> for UnitQuaternion {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Quat {
+        let u: f32 = rng.gen();
+        let a: f32 = rng.gen_range(0.0..2.0 * PI);
+        let b: f32 = rng.gen_range(0.0..2.0 * PI);
+        let s = (1.0 - u).sqrt();
+        let t = u.sqrt();
+
+        Quat {
+            x: s * a.sin(),
+            y: s * a.cos(),
+            z: t * b.sin(),
+            w: t * b.cos(),
+        }
+    }
+}
@@ -0,0 +1,85 @@
+use plane::Plane;
+use vec3::Vec3;
+
+/// A ray in 3D space, represented by an origin and a unit length direction. Useful for picking
+/// and raytracing.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Ray {
+    /// The point from which the ray starts.
+    pub origin: Vec3<f32>,
+    /// The direction in which the ray travels.
+    pub direction: Vec3<f32>,
+}
+
+impl Ray {
+    /// Constructs a new `Ray` from an origin and a direction. The direction is normalized.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gamemath::{Ray, Vec3};
+    ///
+    /// let r = Ray::new(Vec3::new(1.0, 2.0, 3.0), Vec3::new(0.0, 0.0, -5.0));
+    ///
+    /// assert_eq!(r.origin, Vec3::new(1.0, 2.0, 3.0));
+    /// assert_eq!(r.direction, Vec3::new(0.0, 0.0, -1.0));
+    /// ```
+    pub fn new(origin: Vec3<f32>, direction: Vec3<f32>) -> Ray {
+        Ray {
+            origin,
+            direction: direction.normalized(),
+        }
+    }
+
+    /// Calculates and returns the point along the calling `Ray` at a distance `t` from its
+    /// origin.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gamemath::{Ray, Vec3};
+    ///
+    /// let r = Ray::new(Vec3::new(1.0, 2.0, 3.0), Vec3::new(0.0, 1.0, 0.0));
+    ///
+    /// assert_eq!(r.at(0.0), Vec3::new(1.0, 2.0, 3.0));
+    /// assert_eq!(r.at(2.5), Vec3::new(1.0, 4.5, 3.0));
+    /// ```
+    pub fn at(&self, t: f32) -> Vec3<f32> {
+        self.origin + self.direction * t
+    }
+
+    /// Calculates the distance `t` along the calling `Ray` at which it intersects a `Plane`.
+    /// Returns `None` if the ray is parallel to the plane, or if the plane lies behind the
+    /// origin of the ray.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gamemath::{Plane, Ray, Vec3};
+    ///
+    /// let p = Plane::from_point_normal(Vec3::new(0.0, 0.0, 0.0), Vec3::new(0.0, 0.0, 1.0));
+    /// let hit = Ray::new(Vec3::new(1.0, 1.0, 5.0), Vec3::new(0.0, 0.0, -1.0));
+    /// let away = Ray::new(Vec3::new(1.0, 1.0, 5.0), Vec3::new(0.0, 0.0, 1.0));
+    /// let parallel = Ray::new(Vec3::new(1.0, 1.0, 5.0), Vec3::new(1.0, 0.0, 0.0));
+    ///
+    /// assert_eq!(hit.intersect_plane(&p), Some(5.0));
+    /// assert_eq!(hit.at(5.0), Vec3::new(1.0, 1.0, 0.0));
+    /// assert_eq!(away.intersect_plane(&p), None);
+    /// assert_eq!(parallel.intersect_plane(&p), None);
+    /// ```
+    pub fn intersect_plane(&self, plane: &Plane) -> Option<f32> {
+        let denominator = plane.normal.dot(self.direction);
+
+        if denominator == 0.0 {
+            return None;
+        }
+
+        let t = -plane.signed_distance(self.origin) / denominator;
+
+        if t >= 0.0 {
+            Some(t)
+        } else {
+            None
+        }
+    }
+}
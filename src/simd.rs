@@ -0,0 +1,66 @@
+//! SSE implementations of the hot `Mat4` operations, enabled through the `simd` feature on
+//! x86_64 targets, where SSE is always available.
+//!
+//! The operations are performed in the same order as their scalar counterparts, so the results
+//! are identical.
+
+use core::arch::x86_64::{__m128, _mm_add_ps, _mm_mul_ps, _mm_set1_ps, _mm_set_ps, _mm_storeu_ps};
+use core::ops::Mul;
+use mat4::Mat4;
+use vec4::Vec4;
+
+fn load(vec: Vec4<f32>) -> __m128 {
+    unsafe { _mm_set_ps(vec.w, vec.z, vec.y, vec.x) }
+}
+
+fn store(value: __m128) -> Vec4<f32> {
+    let mut result = [0.0; 4];
+
+    unsafe { _mm_storeu_ps(result.as_mut_ptr(), value) };
+
+    result.into()
+}
+
+fn combine(rows: &[__m128; 4], vec: Vec4<f32>) -> __m128 {
+    unsafe {
+        let mut result = _mm_mul_ps(rows[0], _mm_set1_ps(vec.x));
+
+        result = _mm_add_ps(result, _mm_mul_ps(rows[1], _mm_set1_ps(vec.y)));
+        result = _mm_add_ps(result, _mm_mul_ps(rows[2], _mm_set1_ps(vec.z)));
+        _mm_add_ps(result, _mm_mul_ps(rows[3], _mm_set1_ps(vec.w)))
+    }
+}
+
+fn load_rows(matrix: &Mat4) -> [__m128; 4] {
+    [
+        load(matrix[0]),
+        load(matrix[1]),
+        load(matrix[2]),
+        load(matrix[3]),
+    ]
+}
+
+impl Mul<Vec4<f32>> for Mat4 {
+    type Output = Vec4<f32>;
+
+    fn mul(self, vec: Vec4<f32>) -> Vec4<f32> {
+        store(combine(&load_rows(&self.transposed()), vec))
+    }
+}
+
+impl Mul<Mat4> for Mat4 {
+    type Output = Mat4;
+
+    fn mul(self, right: Mat4) -> Mat4 {
+        let rows = load_rows(&self);
+
+        Mat4 {
+            rows: [
+                store(combine(&rows, right[0])),
+                store(combine(&rows, right[1])),
+                store(combine(&rows, right[2])),
+                store(combine(&rows, right[3])),
+            ],
+        }
+    }
+}
@@ -0,0 +1,200 @@
+use core::ops::Mul;
+use mat4::Mat4;
+use quat::Quat;
+use vec3::Vec3;
+
+/// A 3D transformation split into a translation, a rotation and a scale, applied to points in
+/// the order scale, rotation and then translation.
+///
+/// Composing transforms is exact as long as every scale involved is uniform. A non-uniform
+/// scale followed by a rotation introduces a shear, which a `Transform` can not represent, in
+/// which case composing the `Mat4` representations should be preferred.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Transform {
+    /// The translation of the transform.
+    pub translation: Vec3<f32>,
+    /// The rotation of the transform.
+    pub rotation: Quat,
+    /// The scale of the transform.
+    pub scale: Vec3<f32>,
+}
+
+impl Transform {
+    /// Constructs a new `Transform` from a translation, a rotation and a scale.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gamemath::{Quat, Transform, Vec3};
+    ///
+    /// let t = Transform::new(Vec3::new(1.0, 2.0, 3.0), Quat::identity(), Vec3::new(2.0, 2.0, 2.0));
+    ///
+    /// assert_eq!(t.translation, Vec3::new(1.0, 2.0, 3.0));
+    /// assert_eq!(t.rotation, Quat::identity());
+    /// assert_eq!(t.scale, Vec3::new(2.0, 2.0, 2.0));
+    /// ```
+    pub fn new(translation: Vec3<f32>, rotation: Quat, scale: Vec3<f32>) -> Transform {
+        Transform {
+            translation,
+            rotation,
+            scale,
+        }
+    }
+
+    /// Constructs an identity `Transform`, with no translation, no rotation and a scale of one.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gamemath::{Mat4, Transform};
+    ///
+    /// assert_eq!(Transform::identity().to_matrix(), Mat4::identity());
+    /// ```
+    pub fn identity() -> Transform {
+        Self::default()
+    }
+
+    /// Calculates and returns a `Mat4` object representing the calling `Transform`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gamemath::{Mat4, Quat, Transform, Vec3};
+    ///
+    /// let t = Transform::new(Vec3::new(1.0, 2.0, 3.0),
+    ///                        Quat::rotation(1.0, Vec3::new(0.0, 1.0, 0.0)),
+    ///                        Vec3::new(1.0, 2.0, 3.0));
+    ///
+    /// let m = Mat4::identity()
+    ///     .translated(Vec3::new(1.0, 2.0, 3.0))
+    ///     .rotated(1.0, Vec3::new(0.0, 1.0, 0.0))
+    ///     .scaled(Vec3::new(1.0, 2.0, 3.0));
+    ///
+    /// assert!(t.to_matrix().approx_eq(m, 0.000001));
+    /// ```
+    pub fn to_matrix(&self) -> Mat4 {
+        Mat4::from_trs(self.translation, self.rotation, self.scale)
+    }
+
+    /// Calculates and returns the inverse of the calling `Transform`, which undoes it.
+    ///
+    /// The result is only exact when the scale is uniform. Undoing a non-uniform scale after
+    /// undoing the rotation requires a shear, which a `Transform` can not represent, so in that
+    /// case `to_matrix().inverted()` should be used instead.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gamemath::{Quat, Transform, Vec3};
+    ///
+    /// let t = Transform::new(Vec3::new(1.0, 2.0, 3.0),
+    ///                        Quat::rotation(1.0, Vec3::new(1.0, 1.0, 0.0)),
+    ///                        Vec3::new(2.0, 2.0, 2.0));
+    ///
+    /// let p = Vec3::new(4.0, -5.0, 6.0);
+    ///
+    /// assert!((t.inverse().transform_point(t.transform_point(p)) - p).length() < 0.00001);
+    /// assert!((t * t.inverse()).to_matrix().is_identity(0.00001));
+    ///
+    /// let non_uniform = Transform::new(Vec3::new(1.0, 2.0, 3.0),
+    ///                                  Quat::rotation(1.0, Vec3::new(0.0, 0.0, 1.0)),
+    ///                                  Vec3::new(1.0, 2.0, 3.0));
+    /// let q = non_uniform.transform_point(p);
+    ///
+    /// assert!((non_uniform.to_matrix().inverted().transform_point(q) - p).length() < 0.00001);
+    /// assert!((non_uniform.inverse().transform_point(q) - p).length() > 0.1);
+    /// ```
+    pub fn inverse(&self) -> Transform {
+        let rotation = Quat::from((
+            -self.rotation.x,
+            -self.rotation.y,
+            -self.rotation.z,
+            self.rotation.w,
+        ));
+
+        let scale = Vec3::new(1.0 / self.scale.x, 1.0 / self.scale.y, 1.0 / self.scale.z);
+        let translation = rotation
+            .extract_matrix()
+            .transform_vector(-self.translation);
+
+        Transform {
+            translation: Vec3::new(
+                translation.x * scale.x,
+                translation.y * scale.y,
+                translation.z * scale.z,
+            ),
+            rotation,
+            scale,
+        }
+    }
+
+    /// Transforms a point by the calling `Transform`, applying its scale, rotation and
+    /// translation in that order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gamemath::{Quat, Transform, Vec3};
+    ///
+    /// let t = Transform::new(Vec3::new(1.0, 2.0, 3.0),
+    ///                        Quat::rotation(1.0, Vec3::new(0.0, 0.0, 1.0)),
+    ///                        Vec3::new(1.0, 2.0, 3.0));
+    ///
+    /// let p = Vec3::new(4.0, -5.0, 6.0);
+    ///
+    /// assert!((t.transform_point(p) - t.to_matrix().transform_point(p)).length() < 0.00001);
+    /// ```
+    pub fn transform_point(&self, point: Vec3<f32>) -> Vec3<f32> {
+        let scaled = Vec3::new(
+            point.x * self.scale.x,
+            point.y * self.scale.y,
+            point.z * self.scale.z,
+        );
+
+        self.rotation.extract_matrix().transform_vector(scaled) + self.translation
+    }
+}
+
+impl Default for Transform {
+    fn default() -> Transform {
+        Transform {
+            translation: Vec3::new(0.0, 0.0, 0.0),
+            rotation: Quat::identity(),
+            scale: Vec3::new(1.0, 1.0, 1.0),
+        }
+    }
+}
+
+/// Composes two `Transform`s, so that the resulting transform applies the right transform
+/// first and then the left one, just like multiplying their matrices.
+///
+/// # Examples
+///
+/// ```
+/// use gamemath::{Quat, Transform, Vec3};
+///
+/// let a = Transform::new(Vec3::new(1.0, 2.0, 3.0),
+///                        Quat::rotation(1.0, Vec3::new(0.0, 1.0, 0.0)),
+///                        Vec3::new(2.0, 2.0, 2.0));
+///
+/// let b = Transform::new(Vec3::new(-3.0, 0.5, 1.0),
+///                        Quat::rotation(-0.5, Vec3::new(1.0, 0.0, 1.0)),
+///                        Vec3::new(1.0, 2.0, 3.0));
+///
+/// assert!((a * b).to_matrix().approx_eq(a.to_matrix() * b.to_matrix(), 0.00001));
+/// ```
+impl Mul<Transform> for Transform {
+    type Output = Transform;
+
+    fn mul(self, right: Transform) -> Transform {
+        Transform {
+            translation: self.transform_point(right.translation),
+            rotation: right.rotation * self.rotation,
+            scale: Vec3::new(
+                self.scale.x * right.scale.x,
+                self.scale.y * right.scale.y,
+                self.scale.z * right.scale.z,
+            ),
+        }
+    }
+}
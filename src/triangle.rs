@@ -0,0 +1,54 @@
+use vec2::Vec2;
+use vec3::Vec3;
+
+/// Calculates the barycentric coordinates of a point relative to the triangle `a`, `b`, `c`,
+/// returned as the weights of `a`, `b` and `c` in the x, y and z components. The weights sum to
+/// one, and are all non-negative for points inside the triangle.
+///
+/// The weights are not finite for a degenerate triangle, which has no area.
+///
+/// # Examples
+///
+/// ```
+/// use gamemath::{barycentric, Vec2, Vec3};
+///
+/// let a = Vec2::new(0.0, 0.0);
+/// let b = Vec2::new(3.0, 0.0);
+/// let c = Vec2::new(0.0, 3.0);
+///
+/// let centroid = barycentric(Vec2::new(1.0, 1.0), a, b, c);
+///
+/// assert!((centroid - Vec3::new(1.0, 1.0, 1.0) / 3.0).length() < 0.000001);
+/// assert_eq!(barycentric(b, a, b, c), Vec3::new(0.0, 1.0, 0.0));
+/// assert!(barycentric(Vec2::new(3.0, 3.0), a, b, c).x < 0.0);
+/// ```
+pub fn barycentric(p: Vec2<f32>, a: Vec2<f32>, b: Vec2<f32>, c: Vec2<f32>) -> Vec3<f32> {
+    let area = (b - a).wedge(c - a);
+    let u = (b - p).wedge(c - p) / area;
+    let v = (c - p).wedge(a - p) / area;
+
+    Vec3::new(u, v, 1.0 - u - v)
+}
+
+/// Checks if a point lies inside the triangle `a`, `b`, `c`, or on one of its edges, using its
+/// barycentric coordinates. The winding order of the triangle does not matter, and a degenerate
+/// triangle contains no points.
+///
+/// # Examples
+///
+/// ```
+/// use gamemath::{point_in_triangle, Vec2};
+///
+/// let a = Vec2::new(0.0, 0.0);
+/// let b = Vec2::new(3.0, 0.0);
+/// let c = Vec2::new(0.0, 3.0);
+///
+/// assert!(point_in_triangle(Vec2::new(1.0, 1.0), a, b, c));
+/// assert!(point_in_triangle(Vec2::new(1.0, 1.0), a, c, b));
+/// assert!(point_in_triangle(Vec2::new(1.5, 0.0), a, b, c));
+/// assert!(!point_in_triangle(Vec2::new(2.0, 2.0), a, b, c));
+/// assert!(!point_in_triangle(Vec2::new(1.0, 1.0), a, b, b));
+/// ```
+pub fn point_in_triangle(p: Vec2<f32>, a: Vec2<f32>, b: Vec2<f32>, c: Vec2<f32>) -> bool {
+    barycentric(p, a, b, c).all(|weight| weight >= 0.0)
+}
@@ -1,11 +1,37 @@
-use std::fmt::Debug;
-use std::ops::{Add, AddAssign, Index, IndexMut, Mul, MulAssign, Neg, Sub, SubAssign};
+use core::array::TryFromSliceError;
+use core::cmp::Ordering;
+use core::convert::TryFrom;
+use core::fmt::Debug;
+use core::ops::{
+    Add, AddAssign, Div, DivAssign, Index, IndexMut, Mul, MulAssign, Neg, Sub, SubAssign,
+};
+#[cfg(all(not(feature = "std"), not(test)))]
+use math::Float;
 use vec3::Vec3;
 use vec4::Vec4;
 
 /// A two-component Euclidean vector useful for linear algebra computation in game development
 /// and 3D rendering.
-#[derive(Clone, Copy, Debug, PartialEq)]
+///
+/// Vectors of integer components also implement `Eq` and `Hash`, which makes them usable as
+/// keys in hashed collections.
+///
+/// # Examples
+///
+/// ```
+/// use gamemath::Vec2;
+/// use std::collections::HashMap;
+///
+/// let mut tiles = HashMap::new();
+///
+/// tiles.insert(Vec2::new(1, 2), "grass");
+/// tiles.insert(Vec2::new(-4, 7), "water");
+///
+/// assert_eq!(tiles.get(&Vec2::new(1, 2)), Some(&"grass"));
+/// assert_eq!(tiles.get(&Vec2::new(-4, 7)), Some(&"water"));
+/// assert_eq!(tiles.get(&Vec2::new(0, 0)), None);
+/// ```
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
 pub struct Vec2<T> {
     /// The X/first component of the vector.
     pub x: T,
@@ -15,15 +41,7 @@ pub struct Vec2<T> {
 
 impl<T> Vec2<T>
 where
-    T: Copy
-        + Debug
-        + PartialEq
-        + Default
-        + Mul<Output = T>
-        + Add<Output = T>
-        + Sub<Output = T>
-        + Neg<Output = T>
-        + PartialOrd,
+    T: Copy + Debug + PartialEq + Default + Mul<Output = T> + Add<Output = T> + Sub<Output = T>,
 {
     /// Constructs a new `Vec2<T>` from two initial values.
     ///
@@ -54,6 +72,7 @@ where
     ///
     /// assert_eq!(v1.dot(v2), 11.0);
     /// assert_eq!(v2.dot(v1), 11.0);
+    /// assert_eq!(Vec2::new(1_u32, 2).dot(Vec2::new(3, 4)), 11);
     /// ```
     pub fn dot(&self, right: Vec2<T>) -> T {
         self.x * right.x + self.y * right.y
@@ -96,6 +115,150 @@ where
         self.x * self.x + self.y * self.y
     }
 
+    /// Calculates and returns the sum of all components of a `Vec2<T>`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gamemath::Vec2;
+    ///
+    /// let v = Vec2::new(1.0, 2.0);
+    ///
+    /// assert_eq!(v.horizontal_sum(), 3.0);
+    /// ```
+    pub fn horizontal_sum(&self) -> T {
+        self.x + self.y
+    }
+
+    /// Calculates and returns the product of all components of a `Vec2<T>`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gamemath::Vec2;
+    ///
+    /// let v = Vec2::new(1.0, 2.0);
+    ///
+    /// assert_eq!(v.horizontal_product(), 2.0);
+    /// ```
+    pub fn horizontal_product(&self) -> T {
+        self.x * self.y
+    }
+
+    /// Calculates the wedge product of two `Vec2<T>`s, also known as the 2D cross product. It
+    /// equals the signed area of the parallelogram spanned by the two vectors, and is positive if
+    /// the argument object is counter-clockwise from the calling object.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gamemath::Vec2;
+    ///
+    /// let v1 = Vec2::new(1.0, 0.0);
+    /// let v2 = Vec2::new(0.0, 2.0);
+    ///
+    /// assert_eq!(v1.wedge(v2), 2.0);
+    /// assert_eq!(v2.wedge(v1), -2.0);
+    /// assert_eq!(Vec2::new(3, 4).wedge(Vec2::new(6, 8)), 0);
+    /// ```
+    pub fn wedge(&self, right: Vec2<T>) -> T {
+        self.x * right.y - self.y * right.x
+    }
+
+    /// Constructs a `Vec3<T>` from the calling `Vec2<T>` and an appended z component.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gamemath::{Vec2, Vec3};
+    ///
+    /// assert_eq!(Vec2::new(1, 2).extend(3), Vec3::new(1, 2, 3));
+    /// ```
+    pub fn extend(&self, z: T) -> Vec3<T> {
+        Vec3 {
+            x: self.x,
+            y: self.y,
+            z,
+        }
+    }
+
+    /// Constructs a `Vec2<U>` by applying a function to each component of the calling `Vec2<T>`.
+    /// This can be used for converting between component types.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gamemath::Vec2;
+    ///
+    /// let v = Vec2::new(1.9, 2.1);
+    ///
+    /// assert_eq!(v.map(|c| c as i32), Vec2::new(1, 2));
+    /// ```
+    pub fn map<U, F: Fn(T) -> U>(&self, f: F) -> Vec2<U> {
+        Vec2 {
+            x: f(self.x),
+            y: f(self.y),
+        }
+    }
+
+    /// Returns a reference to the component at an index of the calling `Vec2<T>`, or `None` if
+    /// the index is out of range, instead of panicking like indexing does.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gamemath::Vec2;
+    ///
+    /// let v = Vec2::new(1, 2);
+    ///
+    /// assert_eq!(v.get(1), Some(&2));
+    /// assert_eq!(v.get(5), None);
+    /// ```
+    pub fn get(&self, index: usize) -> Option<&T> {
+        match index {
+            0 => Some(&self.x),
+            1 => Some(&self.y),
+            _ => None,
+        }
+    }
+
+    /// Checks if a predicate holds for every component of the calling `Vec2<T>`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gamemath::Vec2;
+    ///
+    /// let v = Vec2::new(1, 2);
+    ///
+    /// assert!(v.all(|c| c > 0));
+    /// assert!(!v.all(|c| c > 1));
+    /// ```
+    pub fn all<F: Fn(T) -> bool>(&self, f: F) -> bool {
+        f(self.x) && f(self.y)
+    }
+
+    /// Checks if a predicate holds for at least one component of the calling `Vec2<T>`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gamemath::Vec2;
+    ///
+    /// let v = Vec2::new(1, 2);
+    ///
+    /// assert!(v.any(|c| c > 1));
+    /// assert!(!v.any(|c| c < 0));
+    /// ```
+    pub fn any<F: Fn(T) -> bool>(&self, f: F) -> bool {
+        f(self.x) || f(self.y)
+    }
+}
+
+impl<T> Vec2<T>
+where
+    T: Copy + Default + Add<Output = T> + Sub<Output = T> + Neg<Output = T> + PartialOrd,
+{
     /// Calculates and returns the manhattan distance between the two points pointed to by two
     /// `Vec2<T>` objects.
     ///
@@ -108,6 +271,8 @@ where
     /// let v2 = Vec2::new(2.0, 4.0);
     ///
     /// assert_eq!(v1.manhattan_distance(v2), 3.0);
+    /// assert_eq!(Vec2::new(3, -1).manhattan_distance(Vec2::new(-2, 4)), 10);
+    /// ```
     pub fn manhattan_distance(&self, right: Vec2<T>) -> T {
         let mut a = self.x - right.x;
         let mut b = self.y - right.y;
@@ -122,6 +287,42 @@ where
 
         a + b
     }
+
+    /// Calculates and returns the chebyshev distance between the two points pointed to by two
+    /// `Vec2<T>` objects, which is the largest absolute difference along any axis.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gamemath::Vec2;
+    ///
+    /// let v1 = Vec2::new(1.0, 2.0);
+    /// let v2 = Vec2::new(-1.0, 2.5);
+    ///
+    /// assert_eq!(v1.chebyshev_distance(v2), 2.0);
+    /// assert_eq!(Vec2::new(0, 0).chebyshev_distance(Vec2::new(3, 5)), 5);
+    /// ```
+    pub fn chebyshev_distance(&self, right: Vec2<T>) -> T {
+        let mut distance = T::default();
+
+        let x = self.x - right.x;
+
+        if x > distance {
+            distance = x;
+        } else if -x > distance {
+            distance = -x;
+        }
+
+        let y = self.y - right.y;
+
+        if y > distance {
+            distance = y;
+        } else if -y > distance {
+            distance = -y;
+        }
+
+        distance
+    }
 }
 
 impl Vec2<f32> {
@@ -181,6 +382,180 @@ impl Vec2<f32> {
     pub fn normalize(&mut self) {
         *self = self.normalized();
     }
+
+    /// Calculates and returns a `Vec2<f32>` representing the calling `Vec2<f32>` rotated
+    /// counter-clockwise by a radians value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gamemath::Vec2;
+    /// use std::f32::consts::PI;
+    ///
+    /// let v = Vec2::new(1.0_f32, 0.0_f32).rotated(PI / 2.0);
+    ///
+    /// assert!((v.x - 0.0).abs() < 0.000001);
+    /// assert!((v.y - 1.0).abs() < 0.000001);
+    /// ```
+    pub fn rotated(&self, radians: f32) -> Vec2<f32> {
+        let sin = radians.sin();
+        let cos = radians.cos();
+
+        Vec2 {
+            x: self.x * cos - self.y * sin,
+            y: self.x * sin + self.y * cos,
+        }
+    }
+
+    /// Rotates the calling `Vec2<f32>` counter-clockwise by a radians value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gamemath::Vec2;
+    /// use std::f32::consts::PI;
+    ///
+    /// let mut v = Vec2::new(0.0_f32, 2.0_f32);
+    ///
+    /// v.rotate(PI);
+    ///
+    /// assert!((v.x - 0.0).abs() < 0.000001);
+    /// assert!((v.y + 2.0).abs() < 0.000001);
+    /// ```
+    pub fn rotate(&mut self, radians: f32) {
+        *self = self.rotated(radians);
+    }
+
+    /// Constructs a unit length `Vec2<f32>` pointing in the direction of a radians value,
+    /// measured counter-clockwise from the positive X axis.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gamemath::Vec2;
+    ///
+    /// assert_eq!(Vec2::from_angle(0.0), Vec2::new(1.0_f32, 0.0_f32));
+    /// ```
+    pub fn from_angle(radians: f32) -> Vec2<f32> {
+        Vec2 {
+            x: radians.cos(),
+            y: radians.sin(),
+        }
+    }
+
+    /// Calculates and returns the angle of the calling `Vec2<f32>` in radians, measured
+    /// counter-clockwise from the positive X axis, in the range `[-π, π]`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gamemath::Vec2;
+    /// use std::f32::consts::PI;
+    ///
+    /// assert_eq!(Vec2::new(0.0_f32, 1.0_f32).angle(), PI / 2.0);
+    /// assert_eq!(Vec2::new(-1.0_f32, 0.0_f32).angle(), PI);
+    /// ```
+    pub fn angle(&self) -> f32 {
+        self.y.atan2(self.x)
+    }
+
+    /// Calculates the signed area of a polygon from its corners using the shoelace formula. The
+    /// area is positive if the corners are in counter-clockwise order and negative if they are in
+    /// clockwise order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gamemath::Vec2;
+    ///
+    /// let mut square = [Vec2::new(0.0_f32, 0.0_f32),
+    ///                   Vec2::new(1.0_f32, 0.0_f32),
+    ///                   Vec2::new(1.0_f32, 1.0_f32),
+    ///                   Vec2::new(0.0_f32, 1.0_f32)];
+    ///
+    /// assert_eq!(Vec2::signed_area(&square), 1.0);
+    ///
+    /// square.reverse();
+    ///
+    /// assert_eq!(Vec2::signed_area(&square), -1.0);
+    /// assert_eq!(Vec2::signed_area(&square[..2]), 0.0);
+    /// ```
+    pub fn signed_area(points: &[Vec2<f32>]) -> f32 {
+        let mut area = 0.0;
+
+        for (i, point) in points.iter().enumerate() {
+            area += point.wedge(points[(i + 1) % points.len()]);
+        }
+
+        area * 0.5
+    }
+
+    /// Returns `true` if every component of the `Vec2<f32>` is neither infinite nor NaN.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gamemath::Vec2;
+    ///
+    /// assert!(Vec2::new(1.0_f32, 1.0_f32).is_finite());
+    /// assert!(!Vec2::new(1.0_f32, f32::NAN).is_finite());
+    /// assert!(!Vec2::new(1.0_f32, f32::INFINITY).is_finite());
+    /// ```
+    pub fn is_finite(&self) -> bool {
+        self.x.is_finite() && self.y.is_finite()
+    }
+
+    /// Returns `true` if any component of the `Vec2<f32>` is NaN.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gamemath::Vec2;
+    ///
+    /// assert!(Vec2::new(1.0_f32, f32::NAN).is_nan());
+    /// assert!(!Vec2::new(1.0_f32, 1.0_f32).is_nan());
+    /// ```
+    pub fn is_nan(&self) -> bool {
+        self.x.is_nan() || self.y.is_nan()
+    }
+
+    /// Linearly interpolates between the calling `Vec2<f32>` and a target `Vec2<f32>` by a
+    /// factor. The factor is not clamped, so factors outside of `[0, 1]` extrapolate beyond the
+    /// two vectors.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gamemath::Vec2;
+    ///
+    /// let v1 = Vec2::new(0.0_f32, 0.0_f32);
+    /// let v2 = Vec2::new(2.0_f32, 2.0_f32);
+    ///
+    /// assert_eq!(v1.lerp(v2, 0.5), Vec2::new(1.0_f32, 1.0_f32));
+    /// assert_eq!(v1.lerp(v2, 2.0), Vec2::new(4.0_f32, 4.0_f32));
+    /// ```
+    pub fn lerp(&self, target: Vec2<f32>, factor: f32) -> Vec2<f32> {
+        *self + (target - *self) * factor
+    }
+
+    /// Linearly interpolates between the calling `Vec2<f32>` and a target `Vec2<f32>` by a
+    /// factor clamped into the range `[0, 1]`, so the result never passes either vector.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gamemath::Vec2;
+    ///
+    /// let v1 = Vec2::new(0.0_f32, 0.0_f32);
+    /// let v2 = Vec2::new(2.0_f32, 2.0_f32);
+    ///
+    /// assert_eq!(v1.lerp_clamped(v2, 0.5), Vec2::new(1.0_f32, 1.0_f32));
+    /// assert_eq!(v1.lerp_clamped(v2, 2.0), v2);
+    /// assert_eq!(v1.lerp_clamped(v2, -1.0), v1);
+    /// ```
+    pub fn lerp_clamped(&self, target: Vec2<f32>, factor: f32) -> Vec2<f32> {
+        self.lerp(target, factor.clamp(0.0, 1.0))
+    }
 }
 
 impl Vec2<f64> {
@@ -240,6 +615,73 @@ impl Vec2<f64> {
     pub fn normalize(&mut self) {
         *self = self.normalized();
     }
+
+    /// Returns `true` if every component of the `Vec2<f64>` is neither infinite nor NaN.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gamemath::Vec2;
+    ///
+    /// assert!(Vec2::new(1.0_f64, 1.0_f64).is_finite());
+    /// assert!(!Vec2::new(1.0_f64, f64::NAN).is_finite());
+    /// assert!(!Vec2::new(1.0_f64, f64::INFINITY).is_finite());
+    /// ```
+    pub fn is_finite(&self) -> bool {
+        self.x.is_finite() && self.y.is_finite()
+    }
+
+    /// Returns `true` if any component of the `Vec2<f64>` is NaN.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gamemath::Vec2;
+    ///
+    /// assert!(Vec2::new(1.0_f64, f64::NAN).is_nan());
+    /// assert!(!Vec2::new(1.0_f64, 1.0_f64).is_nan());
+    /// ```
+    pub fn is_nan(&self) -> bool {
+        self.x.is_nan() || self.y.is_nan()
+    }
+
+    /// Linearly interpolates between the calling `Vec2<f64>` and a target `Vec2<f64>` by a
+    /// factor. The factor is not clamped, so factors outside of `[0, 1]` extrapolate beyond the
+    /// two vectors.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gamemath::Vec2;
+    ///
+    /// let v1 = Vec2::new(0.0_f64, 0.0_f64);
+    /// let v2 = Vec2::new(2.0_f64, 2.0_f64);
+    ///
+    /// assert_eq!(v1.lerp(v2, 0.5), Vec2::new(1.0_f64, 1.0_f64));
+    /// assert_eq!(v1.lerp(v2, 2.0), Vec2::new(4.0_f64, 4.0_f64));
+    /// ```
+    pub fn lerp(&self, target: Vec2<f64>, factor: f64) -> Vec2<f64> {
+        *self + (target - *self) * factor
+    }
+
+    /// Linearly interpolates between the calling `Vec2<f64>` and a target `Vec2<f64>` by a
+    /// factor clamped into the range `[0, 1]`, so the result never passes either vector.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gamemath::Vec2;
+    ///
+    /// let v1 = Vec2::new(0.0_f64, 0.0_f64);
+    /// let v2 = Vec2::new(2.0_f64, 2.0_f64);
+    ///
+    /// assert_eq!(v1.lerp_clamped(v2, 0.5), Vec2::new(1.0_f64, 1.0_f64));
+    /// assert_eq!(v1.lerp_clamped(v2, 2.0), v2);
+    /// assert_eq!(v1.lerp_clamped(v2, -1.0), v1);
+    /// ```
+    pub fn lerp_clamped(&self, target: Vec2<f64>, factor: f64) -> Vec2<f64> {
+        self.lerp(target, factor.clamp(0.0, 1.0))
+    }
 }
 
 impl<T> Default for Vec2<T>
@@ -272,6 +714,28 @@ impl<T: Copy> From<[T; 2]> for Vec2<T> {
     }
 }
 
+/// Converts a slice into a `Vec2<T>`, failing if the length of the slice is not exactly 2.
+///
+/// # Examples
+///
+/// ```
+/// use gamemath::Vec2;
+/// use std::convert::TryFrom;
+///
+/// let values = [1.0_f32, 2.0];
+///
+/// assert_eq!(Vec2::try_from(&values[..]).unwrap(), Vec2::new(1.0, 2.0));
+/// assert!(Vec2::<f32>::try_from(&values[1..]).is_err());
+/// assert!(Vec2::<f32>::try_from(&[0.0_f32; 5][..]).is_err());
+/// ```
+impl<'a, T: Copy> TryFrom<&'a [T]> for Vec2<T> {
+    type Error = TryFromSliceError;
+
+    fn try_from(slice: &'a [T]) -> Result<Vec2<T>, TryFromSliceError> {
+        <[T; 2]>::try_from(slice).map(Vec2::from)
+    }
+}
+
 impl<T> From<Vec3<T>> for Vec2<T> {
     fn from(vector: Vec3<T>) -> Vec2<T> {
         Vec2 {
@@ -365,6 +829,46 @@ impl<T: Mul<Output = T> + Copy> Mul<T> for Vec2<T> {
     }
 }
 
+/// Multiplies every component of a `Vec2<f32>` by a scalar value on the left.
+///
+/// # Examples
+///
+/// ```
+/// use gamemath::Vec2;
+///
+/// assert_eq!(2.0 * Vec2::new(1.0_f32, 2.0_f32), Vec2::new(2.0_f32, 4.0_f32));
+/// ```
+impl Mul<Vec2<f32>> for f32 {
+    type Output = Vec2<f32>;
+
+    fn mul(self, right: Vec2<f32>) -> Vec2<f32> {
+        Vec2 {
+            x: self * right.x,
+            y: self * right.y,
+        }
+    }
+}
+
+/// Multiplies every component of a `Vec2<f64>` by a scalar value on the left.
+///
+/// # Examples
+///
+/// ```
+/// use gamemath::Vec2;
+///
+/// assert_eq!(2.0 * Vec2::new(1.0_f64, 2.0_f64), Vec2::new(2.0_f64, 4.0_f64));
+/// ```
+impl Mul<Vec2<f64>> for f64 {
+    type Output = Vec2<f64>;
+
+    fn mul(self, right: Vec2<f64>) -> Vec2<f64> {
+        Vec2 {
+            x: self * right.x,
+            y: self * right.y,
+        }
+    }
+}
+
 impl<T: MulAssign + Copy> MulAssign<T> for Vec2<T> {
     fn mul_assign(&mut self, right: T) {
         self.x *= right;
@@ -372,6 +876,46 @@ impl<T: MulAssign + Copy> MulAssign<T> for Vec2<T> {
     }
 }
 
+/// Divides every component of a `Vec2<T>` by a scalar value.
+///
+/// # Examples
+///
+/// ```
+/// use gamemath::Vec2;
+///
+/// assert_eq!(Vec2::new(2.0, 4.0) / 2.0, Vec2::new(1.0, 2.0));
+/// ```
+impl<T: Div<Output = T> + Copy> Div<T> for Vec2<T> {
+    type Output = Vec2<T>;
+
+    fn div(self, right: T) -> Vec2<T> {
+        Vec2 {
+            x: self.x / right,
+            y: self.y / right,
+        }
+    }
+}
+
+/// Divides every component of a `Vec2<T>` by a scalar value in place.
+///
+/// # Examples
+///
+/// ```
+/// use gamemath::Vec2;
+///
+/// let mut v = Vec2::new(2.0, 4.0);
+///
+/// v /= 2.0;
+///
+/// assert_eq!(v, Vec2::new(1.0, 2.0));
+/// ```
+impl<T: DivAssign + Copy> DivAssign<T> for Vec2<T> {
+    fn div_assign(&mut self, right: T) {
+        self.x /= right;
+        self.y /= right;
+    }
+}
+
 impl<T: Neg<Output = T>> Neg for Vec2<T> {
     type Output = Vec2<T>;
 
@@ -382,3 +926,35 @@ impl<T: Neg<Output = T>> Neg for Vec2<T> {
         }
     }
 }
+
+/// Orders `Vec2<T>`s of totally ordered components lexicographically, comparing the x
+/// and then the y component. Vectors of floating point components are left unordered.
+///
+/// # Examples
+///
+/// ```
+/// use gamemath::Vec2;
+///
+/// let mut v = vec![Vec2::new(1, 2),
+///                  Vec2::new(0, 5),
+///                  Vec2::new(1, -3),
+///                  Vec2::new(0, 1)];
+///
+/// v.sort();
+///
+/// assert_eq!(v, vec![Vec2::new(0, 1),
+///                    Vec2::new(0, 5),
+///                    Vec2::new(1, -3),
+///                    Vec2::new(1, 2)]);
+/// ```
+impl<T: Ord> Ord for Vec2<T> {
+    fn cmp(&self, other: &Vec2<T>) -> Ordering {
+        self.x.cmp(&other.x).then_with(|| self.y.cmp(&other.y))
+    }
+}
+
+impl<T: Ord> PartialOrd for Vec2<T> {
+    fn partial_cmp(&self, other: &Vec2<T>) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
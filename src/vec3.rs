@@ -1,11 +1,41 @@
-use std::fmt::Debug;
-use std::ops::{Add, AddAssign, Index, IndexMut, Mul, MulAssign, Neg, Sub, SubAssign};
+use axis::Axis;
+use core::array::TryFromSliceError;
+use core::cmp::Ordering;
+use core::convert::TryFrom;
+use core::fmt::Debug;
+use core::ops::{
+    Add, AddAssign, Div, DivAssign, Index, IndexMut, Mul, MulAssign, Neg, Sub, SubAssign,
+};
+#[cfg(all(not(feature = "std"), not(test)))]
+use math::Float;
+use plane::Plane;
+use quat::Quat;
+use quatd::Quatd;
 use vec2::Vec2;
 use vec4::Vec4;
 
 /// A three-component Euclidean vector useful for linear algebra computation in game development
 /// and 3D rendering.
-#[derive(Clone, Copy, Debug, PartialEq)]
+///
+/// Vectors of integer components also implement `Eq` and `Hash`, which makes them usable as
+/// keys in hashed collections.
+///
+/// # Examples
+///
+/// ```
+/// use gamemath::Vec3;
+/// use std::collections::HashMap;
+///
+/// let mut tiles = HashMap::new();
+///
+/// tiles.insert(Vec3::new(1, 2, 3), "grass");
+/// tiles.insert(Vec3::new(-4, 7, 0), "water");
+///
+/// assert_eq!(tiles.get(&Vec3::new(1, 2, 3)), Some(&"grass"));
+/// assert_eq!(tiles.get(&Vec3::new(-4, 7, 0)), Some(&"water"));
+/// assert_eq!(tiles.get(&Vec3::new(0, 0, 0)), None);
+/// ```
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
 pub struct Vec3<T> {
     /// The X/first component of the vector.
     pub x: T,
@@ -17,16 +47,7 @@ pub struct Vec3<T> {
 
 impl<T> Vec3<T>
 where
-    T: Copy
-        + Debug
-        + PartialEq
-        + Default
-        + Sub<Output = T>
-        + Mul<Output = T>
-        + Add<Output = T>
-        + Sub<Output = T>
-        + Neg<Output = T>
-        + PartialOrd,
+    T: Copy + Debug + PartialEq + Default + Sub<Output = T> + Mul<Output = T> + Add<Output = T>,
 {
     /// Constructs a new `Vec3<T>` from three initial values.
     ///
@@ -59,6 +80,7 @@ where
     ///
     /// assert_eq!(v1.dot(v2), 32.0);
     /// assert_eq!(v2.dot(v1), 32.0);
+    /// assert_eq!(Vec3::new(1_u32, 2, 3).dot(Vec3::new(4, 5, 6)), 32);
     /// ```
     pub fn dot(&self, right: Vec3<T>) -> T {
         self.x * right.x + self.y * right.y + self.z * right.z
@@ -79,6 +101,7 @@ where
     ///
     /// assert_eq!(v1.cross(v2), Vec3::new(-3.0, 6.0, -3.0));
     /// assert_eq!(v2.cross(v1), Vec3::new(3.0, -6.0, 3.0));
+    /// assert_eq!(Vec3::new(1_u32, 0, 0).cross(Vec3::new(0, 1, 0)), Vec3::new(0, 0, 1));
     /// ```
     pub fn cross(&self, right: Vec3<T>) -> Vec3<T> {
         Vec3 {
@@ -88,6 +111,29 @@ where
         }
     }
 
+    /// Calculates the scalar triple product `a · (b × c)` of three `Vec3<T>`s, where the calling
+    /// object is `a`.
+    ///
+    /// The result equals the determinant of the 3x3 matrix formed by the three vectors, which is
+    /// the signed volume of the parallelepiped they span. Its sign tells the orientation of the
+    /// three vectors.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gamemath::Vec3;
+    ///
+    /// let x = Vec3::new(1.0, 0.0, 0.0);
+    /// let y = Vec3::new(0.0, 1.0, 0.0);
+    /// let z = Vec3::new(0.0, 0.0, 1.0);
+    ///
+    /// assert_eq!(x.scalar_triple(y, z), 1.0);
+    /// assert_eq!(x.scalar_triple(z, y), -1.0);
+    /// ```
+    pub fn scalar_triple(&self, b: Vec3<T>, c: Vec3<T>) -> T {
+        self.dot(b.cross(c))
+    }
+
     /// Fills all components of the calling `Vec3<T>` with the provided value.
     ///
     /// # Examples
@@ -119,48 +165,928 @@ where
     /// ```
     /// use gamemath::Vec3;
     ///
-    /// let v = Vec3::new(1.0, 2.0, 3.0);
+    /// let v = Vec3::new(1.0, 2.0, 3.0);
+    ///
+    /// assert_eq!(v.length_squared(), 14.0);
+    pub fn length_squared(&self) -> T {
+        self.x * self.x + self.y * self.y + self.z * self.z
+    }
+
+    /// Calculates and returns the sum of all components of a `Vec3<T>`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gamemath::Vec3;
+    ///
+    /// let v = Vec3::new(1.0, 2.0, 3.0);
+    ///
+    /// assert_eq!(v.horizontal_sum(), 6.0);
+    /// ```
+    pub fn horizontal_sum(&self) -> T {
+        self.x + self.y + self.z
+    }
+
+    /// Calculates and returns the product of all components of a `Vec3<T>`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gamemath::Vec3;
+    ///
+    /// let v = Vec3::new(1.0, 2.0, 3.0);
+    ///
+    /// assert_eq!(v.horizontal_product(), 6.0);
+    /// ```
+    pub fn horizontal_product(&self) -> T {
+        self.x * self.y * self.z
+    }
+
+    /// Constructs a `Vec4<T>` from the calling `Vec3<T>` and an appended w component.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gamemath::{Vec3, Vec4};
+    ///
+    /// assert_eq!(Vec3::new(1, 2, 3).extend(4), Vec4::new(1, 2, 3, 4));
+    /// ```
+    pub fn extend(&self, w: T) -> Vec4<T> {
+        Vec4 {
+            x: self.x,
+            y: self.y,
+            z: self.z,
+            w,
+        }
+    }
+
+    /// Constructs a `Vec2<T>` from the calling `Vec3<T>`, dropping its z component.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gamemath::{Vec2, Vec3};
+    ///
+    /// assert_eq!(Vec3::new(1, 2, 3).truncate(), Vec2::new(1, 2));
+    /// ```
+    pub fn truncate(&self) -> Vec2<T> {
+        (*self).into()
+    }
+
+    /// Constructs a `Vec3<U>` by applying a function to each component of the calling `Vec3<T>`.
+    /// This can be used for converting between component types.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gamemath::Vec3;
+    ///
+    /// let v = Vec3::new(1.9, 2.1, 3.5);
+    ///
+    /// assert_eq!(v.map(|c| c as i32), Vec3::new(1, 2, 3));
+    /// ```
+    pub fn map<U, F: Fn(T) -> U>(&self, f: F) -> Vec3<U> {
+        Vec3 {
+            x: f(self.x),
+            y: f(self.y),
+            z: f(self.z),
+        }
+    }
+
+    /// Returns a reference to the component at an index of the calling `Vec3<T>`, or `None` if
+    /// the index is out of range, instead of panicking like indexing does.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gamemath::Vec3;
+    ///
+    /// let v = Vec3::new(1, 2, 3);
+    ///
+    /// assert_eq!(v.get(1), Some(&2));
+    /// assert_eq!(v.get(5), None);
+    /// ```
+    pub fn get(&self, index: usize) -> Option<&T> {
+        match index {
+            0 => Some(&self.x),
+            1 => Some(&self.y),
+            2 => Some(&self.z),
+            _ => None,
+        }
+    }
+
+    /// Checks if a predicate holds for every component of the calling `Vec3<T>`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gamemath::Vec3;
+    ///
+    /// let v = Vec3::new(1, 2, 3);
+    ///
+    /// assert!(v.all(|c| c > 0));
+    /// assert!(!v.all(|c| c > 2));
+    /// ```
+    pub fn all<F: Fn(T) -> bool>(&self, f: F) -> bool {
+        f(self.x) && f(self.y) && f(self.z)
+    }
+
+    /// Checks if a predicate holds for at least one component of the calling `Vec3<T>`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gamemath::Vec3;
+    ///
+    /// let v = Vec3::new(1, 2, 3);
+    ///
+    /// assert!(v.any(|c| c > 2));
+    /// assert!(!v.any(|c| c < 0));
+    /// assert!(Vec3::new(1.0, f32::NAN, 0.0).any(f32::is_nan));
+    /// ```
+    pub fn any<F: Fn(T) -> bool>(&self, f: F) -> bool {
+        f(self.x) || f(self.y) || f(self.z)
+    }
+}
+
+impl<T> Vec3<T>
+where
+    T: Copy + Default + Add<Output = T> + Sub<Output = T> + Neg<Output = T> + PartialOrd,
+{
+    /// Calculates and returns the manhattan distance between the two points pointed to by two
+    /// `Vec3<T>` objects.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gamemath::Vec3;
+    ///
+    /// let v1 = Vec3::new(1.0, 2.0, 3.0);
+    /// let v2 = Vec3::new(2.0, 4.0, 6.0);
+    ///
+    /// assert_eq!(v1.manhattan_distance(v2), 6.0);
+    /// ```
+    pub fn manhattan_distance(&self, right: Vec3<T>) -> T {
+        let mut a = self.x - right.x;
+        let mut b = self.y - right.y;
+        let mut c = self.z - right.z;
+
+        if a < T::default() {
+            a = -a;
+        }
+
+        if b < T::default() {
+            b = -b;
+        }
+
+        if c < T::default() {
+            c = -c;
+        }
+
+        a + b + c
+    }
+
+    /// Calculates and returns the chebyshev distance between the two points pointed to by two
+    /// `Vec3<T>` objects, which is the largest absolute difference along any axis.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gamemath::Vec3;
+    ///
+    /// let v1 = Vec3::new(1.0, 2.0, 3.0);
+    /// let v2 = Vec3::new(2.0, 4.0, 6.0);
+    ///
+    /// assert_eq!(v1.chebyshev_distance(v2), 3.0);
+    /// assert_eq!(Vec3::new(0, 0, 0).chebyshev_distance(Vec3::new(3, 5, -4)), 5);
+    /// ```
+    pub fn chebyshev_distance(&self, right: Vec3<T>) -> T {
+        let mut distance = T::default();
+
+        let x = self.x - right.x;
+
+        if x > distance {
+            distance = x;
+        } else if -x > distance {
+            distance = -x;
+        }
+
+        let y = self.y - right.y;
+
+        if y > distance {
+            distance = y;
+        } else if -y > distance {
+            distance = -y;
+        }
+
+        let z = self.z - right.z;
+
+        if z > distance {
+            distance = z;
+        } else if -z > distance {
+            distance = -z;
+        }
+
+        distance
+    }
+}
+
+impl<T: Copy + PartialOrd> Vec3<T> {
+    /// Returns the index of the largest component of the calling `Vec3<T>`, 0, 1 or 2 for the x, y
+    /// or z component. Ties resolve to the lowest index.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gamemath::Vec3;
+    ///
+    /// assert_eq!(Vec3::new(1, 5, 3).max_axis(), 1);
+    /// assert_eq!(Vec3::new(-1.0, -5.0, 3.0).max_axis(), 2);
+    /// assert_eq!(Vec3::new(2, 2, 2).max_axis(), 0);
+    /// ```
+    pub fn max_axis(&self) -> usize {
+        if self.x >= self.y && self.x >= self.z {
+            0
+        } else if self.y >= self.z {
+            1
+        } else {
+            2
+        }
+    }
+
+    /// Returns the index of the smallest component of the calling `Vec3<T>`, 0, 1 or 2 for the x,
+    /// y or z component. Ties resolve to the lowest index.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gamemath::Vec3;
+    ///
+    /// assert_eq!(Vec3::new(1, 5, 3).min_axis(), 0);
+    /// assert_eq!(Vec3::new(-1.0, -5.0, 3.0).min_axis(), 1);
+    /// assert_eq!(Vec3::new(2, 2, 2).min_axis(), 0);
+    /// ```
+    pub fn min_axis(&self) -> usize {
+        if self.x <= self.y && self.x <= self.z {
+            0
+        } else if self.y <= self.z {
+            1
+        } else {
+            2
+        }
+    }
+}
+
+impl Vec3<f32> {
+    /// Calculates the real length/magnitude/norm of a `Vec3<f32>`.
+    /// This results in an expensive square root calculation, and you might want to consider using
+    /// a squared length instead when possible.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gamemath::Vec3;
+    ///
+    /// let v = Vec3::new(1.0_f32, 4.0_f32, 8.0_f32);
+    ///
+    /// assert_eq!(v.length(), 9.0_f32);
+    pub fn length(&self) -> f32 {
+        self.length_squared().sqrt()
+    }
+
+    /// Calculates and returns the unit vector representation of a `Vec3<f32>`.
+    /// This results in an an expensive square root calculation.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gamemath::Vec3;
+    ///
+    /// let v = Vec3::new(9.0_f32, 12.0_f32, 20.0_f32);
+    ///
+    /// assert_eq!(v.normalized(), Vec3::new(0.36_f32, 0.48_f32, 0.8_f32));
+    pub fn normalized(&self) -> Vec3<f32> {
+        let mut length = self.length();
+
+        if length == 0.0 {
+            length = 1.0;
+        }
+
+        Vec3 {
+            x: self.x / length,
+            y: self.y / length,
+            z: self.z / length,
+        }
+    }
+
+    /// Normalizes a `Vec3<f32>` into its unit vector representation.
+    /// This results in an an expensive square root calculation.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gamemath::Vec3;
+    ///
+    /// let mut v = Vec3::new(9.0_f32, 12.0_f32, 20.0_f32);
+    ///
+    /// v.normalize();
+    ///
+    /// assert_eq!(v, Vec3::new(0.36_f32, 0.48_f32, 0.8_f32));
+    pub fn normalize(&mut self) {
+        *self = self.normalized();
+    }
+
+    /// Calculates and returns the unit vector representation of a `Vec3<f32>`, or a zero
+    /// vector if its length is too small to be normalized reliably.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gamemath::Vec3;
+    ///
+    /// let v = Vec3::new(9.0_f32, 12.0_f32, 20.0_f32);
+    ///
+    /// assert_eq!(v.normalize_or_zero(), Vec3::new(0.36_f32, 0.48_f32, 0.8_f32));
+    /// assert_eq!(Vec3::new(0.0_f32, 0.0_f32, 0.0_f32).normalize_or_zero(), Vec3::default());
+    /// assert_eq!(Vec3::new(1e-20_f32, 0.0_f32, 0.0_f32).normalize_or_zero(), Vec3::default());
+    /// ```
+    pub fn normalize_or_zero(&self) -> Vec3<f32> {
+        self.try_normalize().unwrap_or_default()
+    }
+
+    /// Calculates and returns the unit vector representation of a `Vec3<f32>`, or `None` if
+    /// its length is too small to be normalized reliably.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gamemath::Vec3;
+    ///
+    /// let v = Vec3::new(9.0_f32, 12.0_f32, 20.0_f32);
+    ///
+    /// assert_eq!(v.try_normalize(), Some(Vec3::new(0.36_f32, 0.48_f32, 0.8_f32)));
+    /// assert_eq!(Vec3::new(0.0_f32, 0.0_f32, 0.0_f32).try_normalize(), None);
+    /// assert_eq!(Vec3::new(1e-20_f32, 0.0_f32, 0.0_f32).try_normalize(), None);
+    /// ```
+    pub fn try_normalize(&self) -> Option<Vec3<f32>> {
+        let length = self.length();
+
+        if length < f32::EPSILON {
+            None
+        } else {
+            Some(Vec3 {
+                x: self.x / length,
+                y: self.y / length,
+                z: self.z / length,
+            })
+        }
+    }
+
+    /// Returns `true` if every component of the `Vec3<f32>` is neither infinite nor NaN.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gamemath::Vec3;
+    ///
+    /// assert!(Vec3::new(1.0_f32, 1.0_f32, 1.0_f32).is_finite());
+    /// assert!(!Vec3::new(1.0_f32, 1.0_f32, f32::NAN).is_finite());
+    /// assert!(!Vec3::new(1.0_f32, 1.0_f32, f32::INFINITY).is_finite());
+    /// ```
+    pub fn is_finite(&self) -> bool {
+        self.x.is_finite() && self.y.is_finite() && self.z.is_finite()
+    }
+
+    /// Returns `true` if any component of the `Vec3<f32>` is NaN.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gamemath::Vec3;
+    ///
+    /// assert!(Vec3::new(1.0_f32, 1.0_f32, f32::NAN).is_nan());
+    /// assert!(!Vec3::new(1.0_f32, 1.0_f32, 1.0_f32).is_nan());
+    /// ```
+    pub fn is_nan(&self) -> bool {
+        self.x.is_nan() || self.y.is_nan() || self.z.is_nan()
+    }
+
+    /// Calculates and returns the point halfway between the points pointed to by two
+    /// `Vec3<f32>` objects.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gamemath::Vec3;
+    ///
+    /// let v1 = Vec3::new(0.0_f32, 0.0_f32, 0.0_f32);
+    /// let v2 = Vec3::new(2.0_f32, 2.0_f32, 2.0_f32);
+    ///
+    /// assert_eq!(v1.midpoint(v2), Vec3::new(1.0_f32, 1.0_f32, 1.0_f32));
+    /// ```
+    pub fn midpoint(&self, other: Vec3<f32>) -> Vec3<f32> {
+        (*self + other) * 0.5
+    }
+
+    /// Calculates and returns the centroid, i.e. the average position, of a slice of points.
+    /// An empty slice results in a zero vector.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gamemath::Vec3;
+    ///
+    /// let points = [Vec3::new(0.0_f32, 0.0_f32, 0.0_f32),
+    ///               Vec3::new(3.0_f32, 0.0_f32, 0.0_f32),
+    ///               Vec3::new(0.0_f32, 6.0_f32, 3.0_f32)];
+    ///
+    /// assert_eq!(Vec3::centroid(&points), Vec3::new(1.0_f32, 2.0_f32, 1.0_f32));
+    /// assert_eq!(Vec3::<f32>::centroid(&[]), Vec3::new(0.0_f32, 0.0_f32, 0.0_f32));
+    /// ```
+    pub fn centroid(points: &[Vec3<f32>]) -> Vec3<f32> {
+        if points.is_empty() {
+            return Vec3::default();
+        }
+
+        let mut sum = Vec3::default();
+
+        for point in points {
+            sum += *point;
+        }
+
+        sum / points.len() as f32
+    }
+
+    /// Linearly interpolates between the calling `Vec3<f32>` and a target `Vec3<f32>` by a
+    /// factor. The factor is not clamped, so factors outside of `[0, 1]` extrapolate beyond the
+    /// two vectors.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gamemath::Vec3;
+    ///
+    /// let v1 = Vec3::new(0.0_f32, 0.0_f32, 0.0_f32);
+    /// let v2 = Vec3::new(2.0_f32, 2.0_f32, 2.0_f32);
+    ///
+    /// assert_eq!(v1.lerp(v2, 0.5), Vec3::new(1.0_f32, 1.0_f32, 1.0_f32));
+    /// assert_eq!(v1.lerp(v2, 2.0), Vec3::new(4.0_f32, 4.0_f32, 4.0_f32));
+    /// ```
+    pub fn lerp(&self, target: Vec3<f32>, factor: f32) -> Vec3<f32> {
+        *self + (target - *self) * factor
+    }
+
+    /// Linearly interpolates between the calling `Vec3<f32>` and a target `Vec3<f32>` by a
+    /// factor clamped into the range `[0, 1]`, so the result never passes either vector.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gamemath::Vec3;
+    ///
+    /// let v1 = Vec3::new(0.0_f32, 0.0_f32, 0.0_f32);
+    /// let v2 = Vec3::new(2.0_f32, 2.0_f32, 2.0_f32);
+    ///
+    /// assert_eq!(v1.lerp_clamped(v2, 0.5), Vec3::new(1.0_f32, 1.0_f32, 1.0_f32));
+    /// assert_eq!(v1.lerp_clamped(v2, 2.0), v2);
+    /// assert_eq!(v1.lerp_clamped(v2, -1.0), v1);
+    /// ```
+    pub fn lerp_clamped(&self, target: Vec3<f32>, factor: f32) -> Vec3<f32> {
+        self.lerp(target, factor.clamp(0.0, 1.0))
+    }
+
+    /// Calculates and returns the calling `Vec3<f32>` moved towards a target `Vec3<f32>` by at
+    /// most a maximum distance, without overshooting the target.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gamemath::Vec3;
+    ///
+    /// let v1 = Vec3::new(0.0_f32, 0.0_f32, 0.0_f32);
+    /// let v2 = Vec3::new(3.0_f32, 0.0_f32, 4.0_f32);
+    ///
+    /// assert_eq!(v1.move_towards(v2, 10.0), v2);
+    /// assert_eq!(v1.move_towards(v2, 2.5), Vec3::new(1.5_f32, 0.0_f32, 2.0_f32));
+    /// assert_eq!(v2.move_towards(v2, 1.0), v2);
+    /// ```
+    pub fn move_towards(&self, target: Vec3<f32>, max_delta: f32) -> Vec3<f32> {
+        let delta = target - *self;
+        let distance = delta.length();
+
+        if distance <= max_delta || distance == 0.0 {
+            target
+        } else {
+            *self + delta * (max_delta / distance)
+        }
+    }
+
+    /// Calculates two unit vectors that are perpendicular to the calling `Vec3<f32>` and to each
+    /// other, forming an orthonormal basis together with it. The calling `Vec3<f32>` is expected
+    /// to be normalized.
+    ///
+    /// This uses the method by Duff et al., which has no degenerate directions, unlike choosing a
+    /// helper axis to cross with.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gamemath::Vec3;
+    ///
+    /// let normals = [Vec3::new(0.0_f32, 0.0_f32, 1.0_f32),
+    ///                Vec3::new(0.0_f32, 0.0_f32, -1.0_f32),
+    ///                Vec3::new(1.0_f32, 0.0_f32, 0.0_f32),
+    ///                Vec3::new(1.0_f32, -2.0_f32, 3.0_f32).normalized(),
+    ///                Vec3::new(-0.3_f32, 0.1_f32, -2.0_f32).normalized()];
+    ///
+    /// for &n in normals.iter() {
+    ///     let (t, b) = n.orthonormal_basis();
+    ///
+    ///     assert!((t.length() - 1.0).abs() < 0.000001);
+    ///     assert!((b.length() - 1.0).abs() < 0.000001);
+    ///     assert!(n.dot(t).abs() < 0.000001);
+    ///     assert!(n.dot(b).abs() < 0.000001);
+    ///     assert!(t.dot(b).abs() < 0.000001);
+    ///     assert!((t.cross(b) - n).length() < 0.000001);
+    /// }
+    /// ```
+    pub fn orthonormal_basis(&self) -> (Vec3<f32>, Vec3<f32>) {
+        let sign = if self.z >= 0.0 { 1.0 } else { -1.0 };
+        let a = -1.0 / (sign + self.z);
+        let b = self.x * self.y * a;
+
+        (
+            Vec3::new(1.0 + sign * self.x * self.x * a, sign * b, -sign * self.x),
+            Vec3::new(b, sign + self.y * self.y * a, -self.y),
+        )
+    }
+
+    /// Calculates and returns the mirror image of the point pointed to by the calling
+    /// `Vec3<f32>` on the other side of a `Plane`. The normal of the plane does not need to be
+    /// normalized.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gamemath::{Plane, Vec3};
+    ///
+    /// let p = Plane::new(Vec3::new(0.0, 0.0, 1.0), 0.0);
+    ///
+    /// assert_eq!(Vec3::new(1.0_f32, 2.0_f32, 2.0_f32).reflect_across_plane(&p), Vec3::new(1.0, 2.0, -2.0));
+    ///
+    /// let p = Plane::new(Vec3::new(0.0, 2.0, 0.0), -2.0);
+    ///
+    /// assert_eq!(Vec3::new(3.0_f32, 0.0_f32, 0.0_f32).reflect_across_plane(&p), Vec3::new(3.0, 2.0, 0.0));
+    /// ```
+    pub fn reflect_across_plane(&self, plane: &Plane) -> Vec3<f32> {
+        let factor = 2.0 * plane.signed_distance(*self) / plane.normal.length_squared();
+
+        *self - plane.normal * factor
+    }
+
+    /// Calculates and returns a `Vec3<f32>` with each component of the calling `Vec3<f32>`
+    /// clamped into the range `[0, 1]`, which is useful for colors.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gamemath::Vec3;
+    ///
+    /// assert_eq!(Vec3::new(-0.5_f32, 0.5_f32, 1.5_f32).saturate(), Vec3::new(0.0_f32, 0.5_f32, 1.0_f32));
+    /// ```
+    pub fn saturate(&self) -> Vec3<f32> {
+        Vec3 {
+            x: self.x.clamp(0.0, 1.0),
+            y: self.y.clamp(0.0, 1.0),
+            z: self.z.clamp(0.0, 1.0),
+        }
+    }
+
+    /// Snaps the calling `Vec3<f32>` to a grid, rounding each component to the nearest multiple
+    /// of the corresponding component of `grid`. Axes with a grid size of zero are left untouched.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gamemath::Vec3;
+    ///
+    /// let v = Vec3::new(1.2_f32, 2.7_f32, 3.5_f32);
+    ///
+    /// assert_eq!(v.snap(Vec3::new(1.0, 1.0, 1.0)), Vec3::new(1.0, 3.0, 4.0));
+    /// assert_eq!(v.snap(Vec3::new(0.5, 2.0, 0.0)), Vec3::new(1.0, 2.0, 3.5));
+    /// ```
+    pub fn snap(&self, grid: Vec3<f32>) -> Vec3<f32> {
+        let snap_component = |value: f32, size: f32| {
+            if size == 0.0 {
+                value
+            } else {
+                (value / size).round() * size
+            }
+        };
+
+        Vec3 {
+            x: snap_component(self.x, grid.x),
+            y: snap_component(self.y, grid.y),
+            z: snap_component(self.z, grid.z),
+        }
+    }
+
+    /// Divides each component of the calling `Vec3<f32>` by the corresponding component of
+    /// another `Vec3<f32>`.
+    ///
+    /// Unlike regular IEEE 754 division, dividing by a zero component produces zero for that
+    /// component instead of an infinity or NaN, which keeps gameplay code from propagating
+    /// non-finite values.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gamemath::Vec3;
+    ///
+    /// let v = Vec3::new(4.0_f32, 9.0, 0.0);
+    ///
+    /// assert_eq!(v.div_componentwise(Vec3::new(2.0, 3.0, 0.0)), Vec3::new(2.0, 3.0, 0.0));
+    /// assert_eq!(v.div_componentwise(Vec3::new(0.0, -3.0, 1.0)), Vec3::new(0.0, -3.0, 0.0));
+    /// ```
+    pub fn div_componentwise(&self, right: Vec3<f32>) -> Vec3<f32> {
+        let div_component = |value: f32, divisor: f32| {
+            if divisor == 0.0 {
+                0.0
+            } else {
+                value / divisor
+            }
+        };
+
+        Vec3 {
+            x: div_component(self.x, right.x),
+            y: div_component(self.y, right.y),
+            z: div_component(self.z, right.z),
+        }
+    }
+
+    /// Calculates the reciprocal of each component of the calling `Vec3<f32>`. Division follows
+    /// the IEEE 754 rules, so a zero component produces an infinity of the same sign, which is
+    /// what ray-box slab tests expect from a precomputed inverse direction.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gamemath::Vec3;
+    ///
+    /// assert_eq!(Vec3::new(2.0_f32, 4.0, 8.0).recip(), Vec3::new(0.5, 0.25, 0.125));
+    /// assert_eq!(Vec3::new(0.0_f32, -0.0, 1.0).recip().x, std::f32::INFINITY);
+    /// assert_eq!(Vec3::new(0.0_f32, -0.0, 1.0).recip().y, std::f32::NEG_INFINITY);
+    /// ```
+    pub fn recip(&self) -> Vec3<f32> {
+        Vec3 {
+            x: 1.0 / self.x,
+            y: 1.0 / self.y,
+            z: 1.0 / self.z,
+        }
+    }
+
+    /// Calculates and returns the calling `Vec3<f32>` rotated by a radians value around an axis,
+    /// using Rodrigues' rotation formula directly instead of building a matrix or quaternion. The
+    /// axis does not need to be normalized, and the rotation is counter-clockwise when looking
+    /// down the axis towards the origin, matching `Quat::rotation`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gamemath::{Quat, Vec3};
+    /// use std::f32::consts::PI;
+    ///
+    /// let v = Vec3::new(1.0_f32, 0.0, 0.0);
+    /// let axis = Vec3::new(0.0, 0.0, 2.0);
+    /// let rotated = v.rotate_around(axis, PI / 2.0);
+    ///
+    /// assert!((rotated - Vec3::new(0.0, 1.0, 0.0)).length() < 0.000001);
+    ///
+    /// let v = Vec3::new(1.0_f32, -2.0, 3.0);
+    /// let axis = Vec3::new(1.0, 1.0, 0.5);
+    /// let expected = Quat::rotation(1.3, axis).rotate_vector(v);
+    ///
+    /// assert!((v.rotate_around(axis, 1.3) - expected).length() < 0.00001);
+    /// ```
+    pub fn rotate_around(&self, axis: Vec3<f32>, radians: f32) -> Vec3<f32> {
+        let k = axis.normalized();
+        let sin = radians.sin();
+        let cos = radians.cos();
+
+        *self * cos + k.cross(*self) * sin + k * (k.dot(*self) * (1.0 - cos))
+    }
+
+    /// Converts the calling `Vec3<f32>`, holding the red, green and blue channels of a color in
+    /// its x, y and z components, into hue, saturation and value.
+    ///
+    /// All channels are in the range `[0, 1]`, including the hue, which is measured in turns
+    /// rather than degrees, so `0.5` corresponds to 180 degrees.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gamemath::Vec3;
+    ///
+    /// let red = Vec3::new(1.0, 0.0, 0.0);
+    ///
+    /// assert_eq!(red.rgb_to_hsv(), Vec3::new(0.0, 1.0, 1.0));
+    /// assert_eq!(red.rgb_to_hsv().hsv_to_rgb(), red);
+    /// assert_eq!(Vec3::new(0.0, 0.5, 0.5).rgb_to_hsv(), Vec3::new(0.5, 1.0, 0.5));
+    /// ```
+    pub fn rgb_to_hsv(&self) -> Vec3<f32> {
+        let max = self.x.max(self.y).max(self.z);
+        let min = self.x.min(self.y).min(self.z);
+        let delta = max - min;
+
+        let mut hue = if delta == 0.0 {
+            0.0
+        } else if max == self.x {
+            (self.y - self.z) / delta
+        } else if max == self.y {
+            (self.z - self.x) / delta + 2.0
+        } else {
+            (self.x - self.y) / delta + 4.0
+        };
+
+        if hue < 0.0 {
+            hue += 6.0;
+        }
+
+        let saturation = if max > 0.0 { delta / max } else { 0.0 };
+
+        Vec3 {
+            x: hue / 6.0,
+            y: saturation,
+            z: max,
+        }
+    }
+
+    /// Converts the calling `Vec3<f32>`, holding the hue, saturation and value of a color in its
+    /// x, y and z components, into red, green and blue channels.
+    ///
+    /// The hue is measured in turns, wrapping around outside of the range `[0, 1]`, while the
+    /// saturation and value are expected to be in the range `[0, 1]`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gamemath::Vec3;
+    ///
+    /// assert_eq!(Vec3::new(0.0, 1.0, 1.0).hsv_to_rgb(), Vec3::new(1.0, 0.0, 0.0));
+    /// assert_eq!(Vec3::new(1.0, 1.0, 1.0).hsv_to_rgb(), Vec3::new(1.0, 0.0, 0.0));
+    /// assert_eq!(Vec3::new(0.5, 1.0, 0.5).hsv_to_rgb(), Vec3::new(0.0, 0.5, 0.5));
+    /// assert_eq!(Vec3::new(0.25, 0.0, 0.75).hsv_to_rgb(), Vec3::new(0.75, 0.75, 0.75));
+    /// ```
+    pub fn hsv_to_rgb(&self) -> Vec3<f32> {
+        let mut hue = self.x % 1.0;
+
+        if hue < 0.0 {
+            hue += 1.0;
+        }
+
+        let scaled_hue = hue * 6.0;
+        let sector = scaled_hue as u32;
+        let f = scaled_hue - sector as f32;
+
+        let v = self.z;
+        let p = v * (1.0 - self.y);
+        let q = v * (1.0 - self.y * f);
+        let t = v * (1.0 - self.y * (1.0 - f));
+
+        let (x, y, z) = match sector % 6 {
+            0 => (v, t, p),
+            1 => (q, v, p),
+            2 => (p, v, t),
+            3 => (p, q, v),
+            4 => (t, p, v),
+            _ => (v, p, q),
+        };
+
+        Vec3 { x, y, z }
+    }
+
+    /// Calculates and returns a `Vec3<f32>` with each component of the calling `Vec3<f32>`
+    /// raised to a power.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gamemath::Vec3;
+    ///
+    /// assert_eq!(Vec3::new(4.0, 9.0, 16.0).powf(0.5), Vec3::new(2.0, 3.0, 4.0));
+    /// assert_eq!(Vec3::new(1.0, 2.0, 3.0).powf(2.0), Vec3::new(1.0, 4.0, 9.0));
+    /// ```
+    pub fn powf(&self, exp: f32) -> Vec3<f32> {
+        Vec3 {
+            x: self.x.powf(exp),
+            y: self.y.powf(exp),
+            z: self.z.powf(exp),
+        }
+    }
+
+    /// Calculates and returns a `Vec3<f32>` with the exponential function, `e^c`, applied to
+    /// each component `c` of the calling `Vec3<f32>`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gamemath::Vec3;
+    ///
+    /// let v = Vec3::new(0.0, 1.0, -1.0).exp();
+    ///
+    /// assert_eq!(v.x, 1.0);
+    /// assert!((v.y - std::f32::consts::E).abs() < 0.000001);
+    /// assert!((v.z - 1.0 / std::f32::consts::E).abs() < 0.000001);
+    /// ```
+    pub fn exp(&self) -> Vec3<f32> {
+        Vec3 {
+            x: self.x.exp(),
+            y: self.y.exp(),
+            z: self.z.exp(),
+        }
+    }
+
+    /// Calculates and returns a `Vec3<f32>` with the natural logarithm applied to each component
+    /// of the calling `Vec3<f32>`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gamemath::Vec3;
+    ///
+    /// let v = Vec3::new(1.0, std::f32::consts::E, 0.5);
+    ///
+    /// assert!((v.ln() - Vec3::new(0.0, 1.0, -0.6931472)).length() < 0.000001);
+    /// assert!((v.ln().exp() - v).length() < 0.000001);
+    /// ```
+    pub fn ln(&self) -> Vec3<f32> {
+        Vec3 {
+            x: self.x.ln(),
+            y: self.y.ln(),
+            z: self.z.ln(),
+        }
+    }
+
+    /// Converts the calling `Vec3<f32>` into spherical coordinates, returned as a tuple of
+    /// `(radius, theta, phi)`.
+    ///
+    /// Theta is the polar angle in radians between the vector and the positive Z axis, in the
+    /// range `[0, PI]`, and phi is the azimuthal angle in radians of the vector projected onto
+    /// the XY plane, measured from the positive X axis towards the positive Y axis, in the range
+    /// `[-PI, PI]`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gamemath::Vec3;
+    /// use std::f32::consts::PI;
+    ///
+    /// let (radius, theta, phi) = Vec3::new(0.0, 2.0, 0.0).to_spherical();
+    ///
+    /// assert_eq!(radius, 2.0);
+    /// assert_eq!(theta, PI / 2.0);
+    /// assert_eq!(phi, PI / 2.0);
     ///
-    /// assert_eq!(v.length_squared(), 14.0);
-    pub fn length_squared(&self) -> T {
-        self.x * self.x + self.y * self.y + self.z * self.z
+    /// let v = Vec3::new(1.0_f32, -2.0, 3.0).normalized();
+    /// let (radius, theta, phi) = v.to_spherical();
+    ///
+    /// assert!((Vec3::from_spherical(radius, theta, phi) - v).length() < 0.000001);
+    /// ```
+    pub fn to_spherical(&self) -> (f32, f32, f32) {
+        let radius = self.length();
+        let theta = (self.x * self.x + self.y * self.y).sqrt().atan2(self.z);
+        let phi = self.y.atan2(self.x);
+
+        (radius, theta, phi)
     }
 
-    /// Calculates and returns the manhattan distance between the two points pointed to by two
-    /// `Vec3<T>` objects.
+    /// Constructs a `Vec3<f32>` from spherical coordinates, using the same angle conventions as
+    /// `to_spherical`.
     ///
     /// # Examples
     ///
     /// ```
     /// use gamemath::Vec3;
+    /// use std::f32::consts::PI;
     ///
-    /// let v1 = Vec3::new(1.0, 2.0, 3.0);
-    /// let v2 = Vec3::new(2.0, 4.0, 6.0);
+    /// let v = Vec3::from_spherical(2.0, PI / 2.0, PI);
     ///
-    /// assert_eq!(v1.manhattan_distance(v2), 6.0);
-    pub fn manhattan_distance(&self, right: Vec3<T>) -> T {
-        let mut a = self.x - right.x;
-        let mut b = self.y - right.y;
-        let mut c = self.z - right.z;
-
-        if a < T::default() {
-            a = -a;
-        }
-
-        if b < T::default() {
-            b = -b;
-        }
+    /// assert!((v - Vec3::new(-2.0, 0.0, 0.0)).length() < 0.000001);
+    /// assert_eq!(Vec3::from_spherical(3.0, 0.0, 1.0), Vec3::new(0.0, 0.0, 3.0));
+    /// ```
+    pub fn from_spherical(radius: f32, theta: f32, phi: f32) -> Vec3<f32> {
+        let sin_theta = theta.sin();
 
-        if c < T::default() {
-            c = -c;
+        Vec3 {
+            x: radius * sin_theta * phi.cos(),
+            y: radius * sin_theta * phi.sin(),
+            z: radius * theta.cos(),
         }
-
-        a + b + c
     }
 }
 
-impl Vec3<f32> {
-    /// Calculates the real length/magnitude/norm of a `Vec3<f32>`.
+impl Vec3<f64> {
+    /// Calculates the real length/magnitude/norm of a `Vec3<f64>`.
     /// This results in an expensive square root calculation, and you might want to consider using
     /// a squared length instead when possible.
     ///
@@ -169,14 +1095,14 @@ impl Vec3<f32> {
     /// ```
     /// use gamemath::Vec3;
     ///
-    /// let v = Vec3::new(1.0_f32, 4.0_f32, 8.0_f32);
+    /// let v = Vec3::new(1.0_f64, 4.0_f64, 8.0_f64);
     ///
-    /// assert_eq!(v.length(), 9.0_f32);
-    pub fn length(&self) -> f32 {
+    /// assert_eq!(v.length(), 9.0_f64);
+    pub fn length(&self) -> f64 {
         self.length_squared().sqrt()
     }
 
-    /// Calculates and returns the unit vector representation of a `Vec3<f32>`.
+    /// Calculates and returns the unit vector representation of a `Vec3<f64>`.
     /// This results in an an expensive square root calculation.
     ///
     /// # Examples
@@ -184,10 +1110,10 @@ impl Vec3<f32> {
     /// ```
     /// use gamemath::Vec3;
     ///
-    /// let v = Vec3::new(9.0_f32, 12.0_f32, 20.0_f32);
+    /// let v = Vec3::new(9.0_f64, 12.0_f64, 20.0_f64);
     ///
-    /// assert_eq!(v.normalized(), Vec3::new(0.36_f32, 0.48_f32, 0.8_f32));
-    pub fn normalized(&self) -> Vec3<f32> {
+    /// assert_eq!(v.normalized(), Vec3::new(0.36_f64, 0.48_f64, 0.8_f64));
+    pub fn normalized(&self) -> Vec3<f64> {
         let mut length = self.length();
 
         if length == 0.0 {
@@ -201,7 +1127,7 @@ impl Vec3<f32> {
         }
     }
 
-    /// Normalizes a `Vec3<f32>` into its unit vector representation.
+    /// Normalizes a `Vec3<f64>` into its unit vector representation.
     /// This results in an an expensive square root calculation.
     ///
     /// # Examples
@@ -209,35 +1135,35 @@ impl Vec3<f32> {
     /// ```
     /// use gamemath::Vec3;
     ///
-    /// let mut v = Vec3::new(9.0_f32, 12.0_f32, 20.0_f32);
+    /// let mut v = Vec3::new(9.0_f64, 12.0_f64, 20.0_f64);
     ///
     /// v.normalize();
     ///
-    /// assert_eq!(v, Vec3::new(0.36_f32, 0.48_f32, 0.8_f32));
+    /// assert_eq!(v, Vec3::new(0.36_f64, 0.48_f64, 0.8_f64));
     pub fn normalize(&mut self) {
         *self = self.normalized();
     }
-}
 
-impl Vec3<f64> {
-    /// Calculates the real length/magnitude/norm of a `Vec3<f64>`.
-    /// This results in an expensive square root calculation, and you might want to consider using
-    /// a squared length instead when possible.
+    /// Calculates and returns the unit vector representation of a `Vec3<f64>`, or a zero
+    /// vector if its length is too small to be normalized reliably.
     ///
     /// # Examples
     ///
     /// ```
     /// use gamemath::Vec3;
     ///
-    /// let v = Vec3::new(1.0_f64, 4.0_f64, 8.0_f64);
+    /// let v = Vec3::new(9.0_f64, 12.0_f64, 20.0_f64);
     ///
-    /// assert_eq!(v.length(), 9.0_f64);
-    pub fn length(&self) -> f64 {
-        self.length_squared().sqrt()
+    /// assert_eq!(v.normalize_or_zero(), Vec3::new(0.36_f64, 0.48_f64, 0.8_f64));
+    /// assert_eq!(Vec3::new(0.0_f64, 0.0_f64, 0.0_f64).normalize_or_zero(), Vec3::default());
+    /// assert_eq!(Vec3::new(1e-20_f64, 0.0_f64, 0.0_f64).normalize_or_zero(), Vec3::default());
+    /// ```
+    pub fn normalize_or_zero(&self) -> Vec3<f64> {
+        self.try_normalize().unwrap_or_default()
     }
 
-    /// Calculates and returns the unit vector representation of a `Vec3<f64>`.
-    /// This results in an an expensive square root calculation.
+    /// Calculates and returns the unit vector representation of a `Vec3<f64>`, or `None` if
+    /// its length is too small to be normalized reliably.
     ///
     /// # Examples
     ///
@@ -246,36 +1172,304 @@ impl Vec3<f64> {
     ///
     /// let v = Vec3::new(9.0_f64, 12.0_f64, 20.0_f64);
     ///
-    /// assert_eq!(v.normalized(), Vec3::new(0.36_f64, 0.48_f64, 0.8_f64));
-    pub fn normalized(&self) -> Vec3<f64> {
-        let mut length = self.length();
+    /// assert_eq!(v.try_normalize(), Some(Vec3::new(0.36_f64, 0.48_f64, 0.8_f64)));
+    /// assert_eq!(Vec3::new(0.0_f64, 0.0_f64, 0.0_f64).try_normalize(), None);
+    /// assert_eq!(Vec3::new(1e-20_f64, 0.0_f64, 0.0_f64).try_normalize(), None);
+    /// ```
+    pub fn try_normalize(&self) -> Option<Vec3<f64>> {
+        let length = self.length();
 
-        if length == 0.0 {
-            length = 1.0;
+        if length < f64::EPSILON {
+            None
+        } else {
+            Some(Vec3 {
+                x: self.x / length,
+                y: self.y / length,
+                z: self.z / length,
+            })
+        }
+    }
+
+    /// Returns `true` if every component of the `Vec3<f64>` is neither infinite nor NaN.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gamemath::Vec3;
+    ///
+    /// assert!(Vec3::new(1.0_f64, 1.0_f64, 1.0_f64).is_finite());
+    /// assert!(!Vec3::new(1.0_f64, 1.0_f64, f64::NAN).is_finite());
+    /// assert!(!Vec3::new(1.0_f64, 1.0_f64, f64::INFINITY).is_finite());
+    /// ```
+    pub fn is_finite(&self) -> bool {
+        self.x.is_finite() && self.y.is_finite() && self.z.is_finite()
+    }
+
+    /// Returns `true` if any component of the `Vec3<f64>` is NaN.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gamemath::Vec3;
+    ///
+    /// assert!(Vec3::new(1.0_f64, 1.0_f64, f64::NAN).is_nan());
+    /// assert!(!Vec3::new(1.0_f64, 1.0_f64, 1.0_f64).is_nan());
+    /// ```
+    pub fn is_nan(&self) -> bool {
+        self.x.is_nan() || self.y.is_nan() || self.z.is_nan()
+    }
+
+    /// Calculates and returns the point halfway between the points pointed to by two
+    /// `Vec3<f64>` objects.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gamemath::Vec3;
+    ///
+    /// let v1 = Vec3::new(0.0_f64, 0.0_f64, 0.0_f64);
+    /// let v2 = Vec3::new(2.0_f64, 2.0_f64, 2.0_f64);
+    ///
+    /// assert_eq!(v1.midpoint(v2), Vec3::new(1.0_f64, 1.0_f64, 1.0_f64));
+    /// ```
+    pub fn midpoint(&self, other: Vec3<f64>) -> Vec3<f64> {
+        (*self + other) * 0.5
+    }
+
+    /// Linearly interpolates between the calling `Vec3<f64>` and a target `Vec3<f64>` by a
+    /// factor. The factor is not clamped, so factors outside of `[0, 1]` extrapolate beyond the
+    /// two vectors.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gamemath::Vec3;
+    ///
+    /// let v1 = Vec3::new(0.0_f64, 0.0_f64, 0.0_f64);
+    /// let v2 = Vec3::new(2.0_f64, 2.0_f64, 2.0_f64);
+    ///
+    /// assert_eq!(v1.lerp(v2, 0.5), Vec3::new(1.0_f64, 1.0_f64, 1.0_f64));
+    /// assert_eq!(v1.lerp(v2, 2.0), Vec3::new(4.0_f64, 4.0_f64, 4.0_f64));
+    /// ```
+    pub fn lerp(&self, target: Vec3<f64>, factor: f64) -> Vec3<f64> {
+        *self + (target - *self) * factor
+    }
+
+    /// Linearly interpolates between the calling `Vec3<f64>` and a target `Vec3<f64>` by a
+    /// factor clamped into the range `[0, 1]`, so the result never passes either vector.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gamemath::Vec3;
+    ///
+    /// let v1 = Vec3::new(0.0_f64, 0.0_f64, 0.0_f64);
+    /// let v2 = Vec3::new(2.0_f64, 2.0_f64, 2.0_f64);
+    ///
+    /// assert_eq!(v1.lerp_clamped(v2, 0.5), Vec3::new(1.0_f64, 1.0_f64, 1.0_f64));
+    /// assert_eq!(v1.lerp_clamped(v2, 2.0), v2);
+    /// assert_eq!(v1.lerp_clamped(v2, -1.0), v1);
+    /// ```
+    pub fn lerp_clamped(&self, target: Vec3<f64>, factor: f64) -> Vec3<f64> {
+        self.lerp(target, factor.clamp(0.0, 1.0))
+    }
+
+    /// Calculates and returns the calling `Vec3<f64>` moved towards a target `Vec3<f64>` by at
+    /// most a maximum distance, without overshooting the target.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gamemath::Vec3;
+    ///
+    /// let v1 = Vec3::new(0.0_f64, 0.0_f64, 0.0_f64);
+    /// let v2 = Vec3::new(3.0_f64, 0.0_f64, 4.0_f64);
+    ///
+    /// assert_eq!(v1.move_towards(v2, 10.0), v2);
+    /// assert_eq!(v1.move_towards(v2, 2.5), Vec3::new(1.5_f64, 0.0_f64, 2.0_f64));
+    /// assert_eq!(v2.move_towards(v2, 1.0), v2);
+    /// ```
+    pub fn move_towards(&self, target: Vec3<f64>, max_delta: f64) -> Vec3<f64> {
+        let delta = target - *self;
+        let distance = delta.length();
+
+        if distance <= max_delta || distance == 0.0 {
+            target
+        } else {
+            *self + delta * (max_delta / distance)
         }
+    }
+
+    /// Calculates two unit vectors that are perpendicular to the calling `Vec3<f64>` and to each
+    /// other, forming an orthonormal basis together with it. The calling `Vec3<f64>` is expected
+    /// to be normalized.
+    ///
+    /// This uses the method by Duff et al., which has no degenerate directions, unlike choosing a
+    /// helper axis to cross with.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gamemath::Vec3;
+    ///
+    /// let normals = [Vec3::new(0.0_f64, 0.0_f64, 1.0_f64),
+    ///                Vec3::new(0.0_f64, 0.0_f64, -1.0_f64),
+    ///                Vec3::new(1.0_f64, 0.0_f64, 0.0_f64),
+    ///                Vec3::new(1.0_f64, -2.0_f64, 3.0_f64).normalized(),
+    ///                Vec3::new(-0.3_f64, 0.1_f64, -2.0_f64).normalized()];
+    ///
+    /// for &n in normals.iter() {
+    ///     let (t, b) = n.orthonormal_basis();
+    ///
+    ///     assert!((t.length() - 1.0).abs() < 0.000001);
+    ///     assert!((b.length() - 1.0).abs() < 0.000001);
+    ///     assert!(n.dot(t).abs() < 0.000001);
+    ///     assert!(n.dot(b).abs() < 0.000001);
+    ///     assert!(t.dot(b).abs() < 0.000001);
+    ///     assert!((t.cross(b) - n).length() < 0.000001);
+    /// }
+    /// ```
+    pub fn orthonormal_basis(&self) -> (Vec3<f64>, Vec3<f64>) {
+        let sign = if self.z >= 0.0 { 1.0 } else { -1.0 };
+        let a = -1.0 / (sign + self.z);
+        let b = self.x * self.y * a;
+
+        (
+            Vec3::new(1.0 + sign * self.x * self.x * a, sign * b, -sign * self.x),
+            Vec3::new(b, sign + self.y * self.y * a, -self.y),
+        )
+    }
 
+    /// Calculates and returns a `Vec3<f64>` with each component of the calling `Vec3<f64>`
+    /// clamped into the range `[0, 1]`, which is useful for colors.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gamemath::Vec3;
+    ///
+    /// assert_eq!(Vec3::new(-0.5_f64, 0.5_f64, 1.5_f64).saturate(), Vec3::new(0.0_f64, 0.5_f64, 1.0_f64));
+    /// ```
+    pub fn saturate(&self) -> Vec3<f64> {
         Vec3 {
-            x: self.x / length,
-            y: self.y / length,
-            z: self.z / length,
+            x: self.x.clamp(0.0, 1.0),
+            y: self.y.clamp(0.0, 1.0),
+            z: self.z.clamp(0.0, 1.0),
         }
     }
 
-    /// Normalizes a `Vec3<f64>` into its unit vector representation.
-    /// This results in an an expensive square root calculation.
+    /// Snaps the calling `Vec3<f64>` to a grid, rounding each component to the nearest multiple
+    /// of the corresponding component of `grid`. Axes with a grid size of zero are left untouched.
     ///
     /// # Examples
     ///
     /// ```
     /// use gamemath::Vec3;
     ///
-    /// let mut v = Vec3::new(9.0_f64, 12.0_f64, 20.0_f64);
+    /// let v = Vec3::new(1.2_f64, 2.7_f64, 3.5_f64);
     ///
-    /// v.normalize();
+    /// assert_eq!(v.snap(Vec3::new(1.0, 1.0, 1.0)), Vec3::new(1.0, 3.0, 4.0));
+    /// assert_eq!(v.snap(Vec3::new(0.5, 2.0, 0.0)), Vec3::new(1.0, 2.0, 3.5));
+    /// ```
+    pub fn snap(&self, grid: Vec3<f64>) -> Vec3<f64> {
+        let snap_component = |value: f64, size: f64| {
+            if size == 0.0 {
+                value
+            } else {
+                (value / size).round() * size
+            }
+        };
+
+        Vec3 {
+            x: snap_component(self.x, grid.x),
+            y: snap_component(self.y, grid.y),
+            z: snap_component(self.z, grid.z),
+        }
+    }
+
+    /// Divides each component of the calling `Vec3<f64>` by the corresponding component of
+    /// another `Vec3<f64>`.
     ///
-    /// assert_eq!(v, Vec3::new(0.36_f64, 0.48_f64, 0.8_f64));
-    pub fn normalize(&mut self) {
-        *self = self.normalized();
+    /// Unlike regular IEEE 754 division, dividing by a zero component produces zero for that
+    /// component instead of an infinity or NaN, which keeps gameplay code from propagating
+    /// non-finite values.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gamemath::Vec3;
+    ///
+    /// let v = Vec3::new(4.0_f64, 9.0, 0.0);
+    ///
+    /// assert_eq!(v.div_componentwise(Vec3::new(2.0, 3.0, 0.0)), Vec3::new(2.0, 3.0, 0.0));
+    /// assert_eq!(v.div_componentwise(Vec3::new(0.0, -3.0, 1.0)), Vec3::new(0.0, -3.0, 0.0));
+    /// ```
+    pub fn div_componentwise(&self, right: Vec3<f64>) -> Vec3<f64> {
+        let div_component = |value: f64, divisor: f64| {
+            if divisor == 0.0 {
+                0.0
+            } else {
+                value / divisor
+            }
+        };
+
+        Vec3 {
+            x: div_component(self.x, right.x),
+            y: div_component(self.y, right.y),
+            z: div_component(self.z, right.z),
+        }
+    }
+
+    /// Calculates the reciprocal of each component of the calling `Vec3<f64>`. Division follows
+    /// the IEEE 754 rules, so a zero component produces an infinity of the same sign, which is
+    /// what ray-box slab tests expect from a precomputed inverse direction.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gamemath::Vec3;
+    ///
+    /// assert_eq!(Vec3::new(2.0_f64, 4.0, 8.0).recip(), Vec3::new(0.5, 0.25, 0.125));
+    /// assert_eq!(Vec3::new(0.0_f64, -0.0, 1.0).recip().x, std::f64::INFINITY);
+    /// assert_eq!(Vec3::new(0.0_f64, -0.0, 1.0).recip().y, std::f64::NEG_INFINITY);
+    /// ```
+    pub fn recip(&self) -> Vec3<f64> {
+        Vec3 {
+            x: 1.0 / self.x,
+            y: 1.0 / self.y,
+            z: 1.0 / self.z,
+        }
+    }
+
+    /// Calculates and returns the calling `Vec3<f64>` rotated by a radians value around an axis,
+    /// using Rodrigues' rotation formula directly instead of building a matrix or quaternion. The
+    /// axis does not need to be normalized, and the rotation is counter-clockwise when looking
+    /// down the axis towards the origin, matching `Quatd::rotation`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gamemath::{Quatd, Vec3};
+    /// use std::f64::consts::PI;
+    ///
+    /// let v = Vec3::new(1.0_f64, 0.0, 0.0);
+    /// let axis = Vec3::new(0.0, 0.0, 2.0);
+    /// let rotated = v.rotate_around(axis, PI / 2.0);
+    ///
+    /// assert!((rotated - Vec3::new(0.0, 1.0, 0.0)).length() < 0.000001);
+    ///
+    /// let v = Vec3::new(1.0_f64, -2.0, 3.0);
+    /// let axis = Vec3::new(1.0, 1.0, 0.5);
+    /// let expected = Quatd::rotation(1.3, axis).rotate_vector(v);
+    ///
+    /// assert!((v.rotate_around(axis, 1.3) - expected).length() < 0.00001);
+    /// ```
+    pub fn rotate_around(&self, axis: Vec3<f64>, radians: f64) -> Vec3<f64> {
+        let k = axis.normalized();
+        let sin = radians.sin();
+        let cos = radians.cos();
+
+        *self * cos + k.cross(*self) * sin + k * (k.dot(*self) * (1.0 - cos))
     }
 }
 
@@ -309,6 +1503,28 @@ impl<T: Copy> From<[T; 3]> for Vec3<T> {
     }
 }
 
+/// Converts a slice into a `Vec3<T>`, failing if the length of the slice is not exactly 3.
+///
+/// # Examples
+///
+/// ```
+/// use gamemath::Vec3;
+/// use std::convert::TryFrom;
+///
+/// let values = [1.0_f32, 2.0, 3.0];
+///
+/// assert_eq!(Vec3::try_from(&values[..]).unwrap(), Vec3::new(1.0, 2.0, 3.0));
+/// assert!(Vec3::<f32>::try_from(&values[1..]).is_err());
+/// assert!(Vec3::<f32>::try_from(&[0.0_f32; 5][..]).is_err());
+/// ```
+impl<'a, T: Copy> TryFrom<&'a [T]> for Vec3<T> {
+    type Error = TryFromSliceError;
+
+    fn try_from(slice: &'a [T]) -> Result<Vec3<T>, TryFromSliceError> {
+        <[T; 3]>::try_from(slice).map(Vec3::from)
+    }
+}
+
 impl<T: Default> From<Vec2<T>> for Vec3<T> {
     fn from(vec: Vec2<T>) -> Vec3<T> {
         Vec3 {
@@ -319,6 +1535,37 @@ impl<T: Default> From<Vec2<T>> for Vec3<T> {
     }
 }
 
+/// Converts a `Quat` into a `Vec3<f32>` holding its vector part, the x, y and z components.
+///
+/// # Examples
+///
+/// ```
+/// use gamemath::{Quat, Vec3};
+///
+/// let q: Quat = (1.0, 2.0, 3.0, 4.0).into();
+///
+/// assert_eq!(Vec3::from(q), Vec3::new(1.0, 2.0, 3.0));
+/// ```
+impl From<Quat> for Vec3<f32> {
+    fn from(quat: Quat) -> Vec3<f32> {
+        Vec3 {
+            x: quat.x,
+            y: quat.y,
+            z: quat.z,
+        }
+    }
+}
+
+impl From<Quatd> for Vec3<f64> {
+    fn from(quat: Quatd) -> Vec3<f64> {
+        Vec3 {
+            x: quat.x,
+            y: quat.y,
+            z: quat.z,
+        }
+    }
+}
+
 impl<T> From<Vec4<T>> for Vec3<T> {
     fn from(vec: Vec4<T>) -> Vec3<T> {
         Vec3 {
@@ -363,6 +1610,28 @@ impl<T> IndexMut<usize> for Vec3<T> {
     }
 }
 
+impl<T> Index<Axis> for Vec3<T> {
+    type Output = T;
+
+    fn index(&self, axis: Axis) -> &T {
+        match axis {
+            Axis::X => &self.x,
+            Axis::Y => &self.y,
+            Axis::Z => &self.z,
+        }
+    }
+}
+
+impl<T> IndexMut<Axis> for Vec3<T> {
+    fn index_mut(&mut self, axis: Axis) -> &mut T {
+        match axis {
+            Axis::X => &mut self.x,
+            Axis::Y => &mut self.y,
+            Axis::Z => &mut self.z,
+        }
+    }
+}
+
 impl<T: Add<Output = T>> Add for Vec3<T> {
     type Output = Vec3<T>;
 
@@ -415,6 +1684,48 @@ impl<T: Mul<Output = T> + Copy> Mul<T> for Vec3<T> {
     }
 }
 
+/// Multiplies every component of a `Vec3<f32>` by a scalar value on the left.
+///
+/// # Examples
+///
+/// ```
+/// use gamemath::Vec3;
+///
+/// assert_eq!(2.0 * Vec3::new(1.0_f32, 2.0_f32, 3.0_f32), Vec3::new(2.0_f32, 4.0_f32, 6.0_f32));
+/// ```
+impl Mul<Vec3<f32>> for f32 {
+    type Output = Vec3<f32>;
+
+    fn mul(self, right: Vec3<f32>) -> Vec3<f32> {
+        Vec3 {
+            x: self * right.x,
+            y: self * right.y,
+            z: self * right.z,
+        }
+    }
+}
+
+/// Multiplies every component of a `Vec3<f64>` by a scalar value on the left.
+///
+/// # Examples
+///
+/// ```
+/// use gamemath::Vec3;
+///
+/// assert_eq!(2.0 * Vec3::new(1.0_f64, 2.0_f64, 3.0_f64), Vec3::new(2.0_f64, 4.0_f64, 6.0_f64));
+/// ```
+impl Mul<Vec3<f64>> for f64 {
+    type Output = Vec3<f64>;
+
+    fn mul(self, right: Vec3<f64>) -> Vec3<f64> {
+        Vec3 {
+            x: self * right.x,
+            y: self * right.y,
+            z: self * right.z,
+        }
+    }
+}
+
 impl<T: MulAssign + Copy> MulAssign<T> for Vec3<T> {
     fn mul_assign(&mut self, right: T) {
         self.x *= right;
@@ -423,6 +1734,48 @@ impl<T: MulAssign + Copy> MulAssign<T> for Vec3<T> {
     }
 }
 
+/// Divides every component of a `Vec3<T>` by a scalar value.
+///
+/// # Examples
+///
+/// ```
+/// use gamemath::Vec3;
+///
+/// assert_eq!(Vec3::new(2.0, 4.0, 6.0) / 2.0, Vec3::new(1.0, 2.0, 3.0));
+/// ```
+impl<T: Div<Output = T> + Copy> Div<T> for Vec3<T> {
+    type Output = Vec3<T>;
+
+    fn div(self, right: T) -> Vec3<T> {
+        Vec3 {
+            x: self.x / right,
+            y: self.y / right,
+            z: self.z / right,
+        }
+    }
+}
+
+/// Divides every component of a `Vec3<T>` by a scalar value in place.
+///
+/// # Examples
+///
+/// ```
+/// use gamemath::Vec3;
+///
+/// let mut v = Vec3::new(2.0, 4.0, 6.0);
+///
+/// v /= 2.0;
+///
+/// assert_eq!(v, Vec3::new(1.0, 2.0, 3.0));
+/// ```
+impl<T: DivAssign + Copy> DivAssign<T> for Vec3<T> {
+    fn div_assign(&mut self, right: T) {
+        self.x /= right;
+        self.y /= right;
+        self.z /= right;
+    }
+}
+
 impl<T: Neg<Output = T>> Neg for Vec3<T> {
     type Output = Vec3<T>;
 
@@ -434,3 +1787,38 @@ impl<T: Neg<Output = T>> Neg for Vec3<T> {
         }
     }
 }
+
+/// Orders `Vec3<T>`s of totally ordered components lexicographically, comparing the x, y
+/// and then the z component. Vectors of floating point components are left unordered.
+///
+/// # Examples
+///
+/// ```
+/// use gamemath::Vec3;
+///
+/// let mut v = vec![Vec3::new(1, 2, 0),
+///                  Vec3::new(0, 5, 3),
+///                  Vec3::new(1, 2, -3),
+///                  Vec3::new(0, 5, 1)];
+///
+/// v.sort();
+///
+/// assert_eq!(v, vec![Vec3::new(0, 5, 1),
+///                    Vec3::new(0, 5, 3),
+///                    Vec3::new(1, 2, -3),
+///                    Vec3::new(1, 2, 0)]);
+/// ```
+impl<T: Ord> Ord for Vec3<T> {
+    fn cmp(&self, other: &Vec3<T>) -> Ordering {
+        self.x
+            .cmp(&other.x)
+            .then_with(|| self.y.cmp(&other.y))
+            .then_with(|| self.z.cmp(&other.z))
+    }
+}
+
+impl<T: Ord> PartialOrd for Vec3<T> {
+    fn partial_cmp(&self, other: &Vec3<T>) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
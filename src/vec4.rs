@@ -1,12 +1,39 @@
+use core::array::TryFromSliceError;
+use core::cmp::Ordering;
+use core::convert::TryFrom;
+use core::fmt::Debug;
+use core::ops::{
+    Add, AddAssign, Div, DivAssign, Index, IndexMut, Mul, MulAssign, Neg, Sub, SubAssign,
+};
+#[cfg(all(not(feature = "std"), not(test)))]
+use math::Float;
 use quat::Quat;
-use std::fmt::Debug;
-use std::ops::{Add, AddAssign, Index, IndexMut, Mul, MulAssign, Neg, Sub, SubAssign};
+use quatd::Quatd;
 use vec2::Vec2;
 use vec3::Vec3;
 
 /// A four-component Euclidean vector useful for linear algebra computation in game development
 /// and 3D rendering.
-#[derive(Clone, Copy, Debug, PartialEq)]
+///
+/// Vectors of integer components also implement `Eq` and `Hash`, which makes them usable as
+/// keys in hashed collections.
+///
+/// # Examples
+///
+/// ```
+/// use gamemath::Vec4;
+/// use std::collections::HashMap;
+///
+/// let mut tiles = HashMap::new();
+///
+/// tiles.insert(Vec4::new(1, 2, 3, 4), "grass");
+/// tiles.insert(Vec4::new(-4, 7, 0, 1), "water");
+///
+/// assert_eq!(tiles.get(&Vec4::new(1, 2, 3, 4)), Some(&"grass"));
+/// assert_eq!(tiles.get(&Vec4::new(-4, 7, 0, 1)), Some(&"water"));
+/// assert_eq!(tiles.get(&Vec4::new(0, 0, 0, 0)), None);
+/// ```
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
 pub struct Vec4<T> {
     /// The X/first component of the vector.
     pub x: T,
@@ -20,16 +47,7 @@ pub struct Vec4<T> {
 
 impl<T> Vec4<T>
 where
-    T: Copy
-        + Debug
-        + PartialEq
-        + Default
-        + Sub<Output = T>
-        + Mul<Output = T>
-        + Add<Output = T>
-        + Sub<Output = T>
-        + Neg<Output = T>
-        + PartialOrd,
+    T: Copy + Debug + PartialEq + Default + Sub<Output = T> + Mul<Output = T> + Add<Output = T>,
 {
     /// Constructs a new `Vec4<T>` from three initial values.
     ///
@@ -63,6 +81,8 @@ where
     ///
     /// assert_eq!(v1.dot(v2), 70.0);
     /// assert_eq!(v2.dot(v1), 70.0);
+    /// assert_eq!(Vec4::new(1_i32, -2, 3, 4).dot(Vec4::new(5, 6, 7, 8)), 46);
+    /// assert_eq!(Vec4::new(1_u32, 2, 3, 4).length_squared(), 30);
     /// ```
     pub fn dot(&self, right: Vec4<T>) -> T {
         self.x * right.x + self.y * right.y + self.z * right.z + self.w * right.w
@@ -107,6 +127,156 @@ where
         self.x * self.x + self.y * self.y + self.z * self.z + self.w * self.w
     }
 
+    /// Calculates and returns the sum of all components of a `Vec4<T>`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gamemath::Vec4;
+    ///
+    /// let v = Vec4::new(1.0, 2.0, 3.0, 4.0);
+    ///
+    /// assert_eq!(v.horizontal_sum(), 10.0);
+    /// ```
+    pub fn horizontal_sum(&self) -> T {
+        self.x + self.y + self.z + self.w
+    }
+
+    /// Calculates and returns the product of all components of a `Vec4<T>`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gamemath::Vec4;
+    ///
+    /// let v = Vec4::new(1.0, 2.0, 3.0, 4.0);
+    ///
+    /// assert_eq!(v.horizontal_product(), 24.0);
+    /// ```
+    pub fn horizontal_product(&self) -> T {
+        self.x * self.y * self.z * self.w
+    }
+
+    /// Constructs a `Vec3<T>` from the calling `Vec4<T>`, dropping its w component.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gamemath::{Vec3, Vec4};
+    ///
+    /// assert_eq!(Vec4::new(1, 2, 3, 4).truncate(), Vec3::new(1, 2, 3));
+    /// ```
+    pub fn truncate(&self) -> Vec3<T> {
+        (*self).into()
+    }
+
+    /// Constructs a `Vec3<T>` from the x, y and z components of the calling `Vec4<T>`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gamemath::{Vec3, Vec4};
+    ///
+    /// assert_eq!(Vec4::new(1, 2, 3, 4).xyz(), Vec3::new(1, 2, 3));
+    /// ```
+    pub fn xyz(&self) -> Vec3<T> {
+        Vec3::new(self.x, self.y, self.z)
+    }
+
+    /// Constructs a `Vec2<T>` from the x and y components of the calling `Vec4<T>`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gamemath::{Vec2, Vec4};
+    ///
+    /// assert_eq!(Vec4::new(1, 2, 3, 4).xy(), Vec2::new(1, 2));
+    /// ```
+    pub fn xy(&self) -> Vec2<T> {
+        Vec2::new(self.x, self.y)
+    }
+
+    /// Constructs a `Vec4<U>` by applying a function to each component of the calling `Vec4<T>`.
+    /// This can be used for converting between component types.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gamemath::Vec4;
+    ///
+    /// let v = Vec4::new(1.9, 2.1, 3.5, -4.2);
+    ///
+    /// assert_eq!(v.map(|c| c as i32), Vec4::new(1, 2, 3, -4));
+    /// ```
+    pub fn map<U, F: Fn(T) -> U>(&self, f: F) -> Vec4<U> {
+        Vec4 {
+            x: f(self.x),
+            y: f(self.y),
+            z: f(self.z),
+            w: f(self.w),
+        }
+    }
+
+    /// Returns a reference to the component at an index of the calling `Vec4<T>`, or `None` if
+    /// the index is out of range, instead of panicking like indexing does.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gamemath::Vec4;
+    ///
+    /// let v = Vec4::new(1, 2, 3, 4);
+    ///
+    /// assert_eq!(v.get(1), Some(&2));
+    /// assert_eq!(v.get(5), None);
+    /// ```
+    pub fn get(&self, index: usize) -> Option<&T> {
+        match index {
+            0 => Some(&self.x),
+            1 => Some(&self.y),
+            2 => Some(&self.z),
+            3 => Some(&self.w),
+            _ => None,
+        }
+    }
+
+    /// Checks if a predicate holds for every component of the calling `Vec4<T>`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gamemath::Vec4;
+    ///
+    /// let v = Vec4::new(1, 2, 3, 4);
+    ///
+    /// assert!(v.all(|c| c > 0));
+    /// assert!(!v.all(|c| c > 3));
+    /// ```
+    pub fn all<F: Fn(T) -> bool>(&self, f: F) -> bool {
+        f(self.x) && f(self.y) && f(self.z) && f(self.w)
+    }
+
+    /// Checks if a predicate holds for at least one component of the calling `Vec4<T>`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gamemath::Vec4;
+    ///
+    /// let v = Vec4::new(1, 2, 3, 4);
+    ///
+    /// assert!(v.any(|c| c > 3));
+    /// assert!(!v.any(|c| c < 0));
+    /// ```
+    pub fn any<F: Fn(T) -> bool>(&self, f: F) -> bool {
+        f(self.x) || f(self.y) || f(self.z) || f(self.w)
+    }
+}
+
+impl<T> Vec4<T>
+where
+    T: Copy + Default + Add<Output = T> + Sub<Output = T> + Neg<Output = T> + PartialOrd,
+{
     /// Calculates and returns the manhattan distance between the two points pointed to by two
     /// `Vec4<T>` objects.
     ///
@@ -119,6 +289,8 @@ where
     /// let v2 = Vec4::new(2.0, 4.0, 6.0, 8.0);
     ///
     /// assert_eq!(v1.manhattan_distance(v2), 10.0);
+    /// assert_eq!(Vec4::new(1, 2, 3, 4).manhattan_distance(Vec4::new(4, 3, 2, 1)), 8);
+    /// ```
     pub fn manhattan_distance(&self, right: Vec4<T>) -> T {
         let mut a = self.x - right.x;
         let mut b = self.y - right.y;
@@ -143,6 +315,58 @@ where
 
         a + b + c + d
     }
+
+    /// Calculates and returns the chebyshev distance between the two points pointed to by two
+    /// `Vec4<T>` objects, which is the largest absolute difference along any axis.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gamemath::Vec4;
+    ///
+    /// let v1 = Vec4::new(1.0, 2.0, 3.0, 4.0);
+    /// let v2 = Vec4::new(2.0, 4.0, 6.0, 8.0);
+    ///
+    /// assert_eq!(v1.chebyshev_distance(v2), 4.0);
+    /// assert_eq!(Vec4::new(0, 0, 0, 0).chebyshev_distance(Vec4::new(3, 5, -4, 1)), 5);
+    /// ```
+    pub fn chebyshev_distance(&self, right: Vec4<T>) -> T {
+        let mut distance = T::default();
+
+        let x = self.x - right.x;
+
+        if x > distance {
+            distance = x;
+        } else if -x > distance {
+            distance = -x;
+        }
+
+        let y = self.y - right.y;
+
+        if y > distance {
+            distance = y;
+        } else if -y > distance {
+            distance = -y;
+        }
+
+        let z = self.z - right.z;
+
+        if z > distance {
+            distance = z;
+        } else if -z > distance {
+            distance = -z;
+        }
+
+        let w = self.w - right.w;
+
+        if w > distance {
+            distance = w;
+        } else if -w > distance {
+            distance = -w;
+        }
+
+        distance
+    }
 }
 
 impl Vec4<f32> {
@@ -204,6 +428,154 @@ impl Vec4<f32> {
     pub fn normalize(&mut self) {
         *self = self.normalized();
     }
+
+    /// Returns `true` if every component of the `Vec4<f32>` is neither infinite nor NaN.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gamemath::Vec4;
+    ///
+    /// assert!(Vec4::new(1.0_f32, 1.0_f32, 1.0_f32, 1.0_f32).is_finite());
+    /// assert!(!Vec4::new(1.0_f32, 1.0_f32, 1.0_f32, f32::NAN).is_finite());
+    /// assert!(!Vec4::new(1.0_f32, 1.0_f32, 1.0_f32, f32::INFINITY).is_finite());
+    /// ```
+    pub fn is_finite(&self) -> bool {
+        self.x.is_finite() && self.y.is_finite() && self.z.is_finite() && self.w.is_finite()
+    }
+
+    /// Returns `true` if any component of the `Vec4<f32>` is NaN.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gamemath::Vec4;
+    ///
+    /// assert!(Vec4::new(1.0_f32, 1.0_f32, 1.0_f32, f32::NAN).is_nan());
+    /// assert!(!Vec4::new(1.0_f32, 1.0_f32, 1.0_f32, 1.0_f32).is_nan());
+    /// ```
+    pub fn is_nan(&self) -> bool {
+        self.x.is_nan() || self.y.is_nan() || self.z.is_nan() || self.w.is_nan()
+    }
+
+    /// Linearly interpolates between the calling `Vec4<f32>` and a target `Vec4<f32>` by a
+    /// factor. The factor is not clamped, so factors outside of `[0, 1]` extrapolate beyond the
+    /// two vectors.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gamemath::Vec4;
+    ///
+    /// let v1 = Vec4::new(0.0_f32, 0.0_f32, 0.0_f32, 0.0_f32);
+    /// let v2 = Vec4::new(2.0_f32, 2.0_f32, 2.0_f32, 2.0_f32);
+    ///
+    /// assert_eq!(v1.lerp(v2, 0.5), Vec4::new(1.0_f32, 1.0_f32, 1.0_f32, 1.0_f32));
+    /// assert_eq!(v1.lerp(v2, 2.0), Vec4::new(4.0_f32, 4.0_f32, 4.0_f32, 4.0_f32));
+    /// ```
+    pub fn lerp(&self, target: Vec4<f32>, factor: f32) -> Vec4<f32> {
+        *self + (target - *self) * factor
+    }
+
+    /// Linearly interpolates between the calling `Vec4<f32>` and a target `Vec4<f32>` by a
+    /// factor clamped into the range `[0, 1]`, so the result never passes either vector.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gamemath::Vec4;
+    ///
+    /// let v1 = Vec4::new(0.0_f32, 0.0_f32, 0.0_f32, 0.0_f32);
+    /// let v2 = Vec4::new(2.0_f32, 2.0_f32, 2.0_f32, 2.0_f32);
+    ///
+    /// assert_eq!(v1.lerp_clamped(v2, 0.5), Vec4::new(1.0_f32, 1.0_f32, 1.0_f32, 1.0_f32));
+    /// assert_eq!(v1.lerp_clamped(v2, 2.0), v2);
+    /// assert_eq!(v1.lerp_clamped(v2, -1.0), v1);
+    /// ```
+    pub fn lerp_clamped(&self, target: Vec4<f32>, factor: f32) -> Vec4<f32> {
+        self.lerp(target, factor.clamp(0.0, 1.0))
+    }
+
+    /// Calculates and returns a `Vec4<f32>` with each component of the calling `Vec4<f32>`
+    /// clamped into the range `[0, 1]`, which is useful for colors.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gamemath::Vec4;
+    ///
+    /// assert_eq!(Vec4::new(-0.5_f32, 0.5_f32, 1.5_f32, 0.25_f32).saturate(), Vec4::new(0.0_f32, 0.5_f32, 1.0_f32, 0.25_f32));
+    /// ```
+    pub fn saturate(&self) -> Vec4<f32> {
+        Vec4 {
+            x: self.x.clamp(0.0, 1.0),
+            y: self.y.clamp(0.0, 1.0),
+            z: self.z.clamp(0.0, 1.0),
+            w: self.w.clamp(0.0, 1.0),
+        }
+    }
+
+    /// Constructs a `Vec4<f32>` in homogeneous coordinates from a point, setting the w component
+    /// to one so that the point is affected by translation.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gamemath::{Mat4, Vec3, Vec4};
+    ///
+    /// let m = Mat4::identity().translated(Vec3::new(1.0, 2.0, 3.0));
+    /// let p = Vec4::from_point(Vec3::new(1.0, 1.0, 1.0));
+    ///
+    /// assert_eq!(p, Vec4::new(1.0, 1.0, 1.0, 1.0));
+    ///
+    /// let moved = m[0] * p.x + m[1] * p.y + m[2] * p.z + m[3] * p.w;
+    ///
+    /// assert_eq!(moved, Vec4::new(2.0, 3.0, 4.0, 1.0));
+    /// ```
+    pub fn from_point(point: Vec3<f32>) -> Vec4<f32> {
+        point.extend(1.0)
+    }
+
+    /// Constructs a `Vec4<f32>` in homogeneous coordinates from a direction, setting the w
+    /// component to zero so that the direction is not affected by translation.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gamemath::{Mat4, Vec3, Vec4};
+    ///
+    /// let m = Mat4::identity().translated(Vec3::new(1.0, 2.0, 3.0));
+    /// let d = Vec4::from_direction(Vec3::new(1.0, 1.0, 1.0));
+    ///
+    /// assert_eq!(d, Vec4::new(1.0, 1.0, 1.0, 0.0));
+    ///
+    /// let moved = m[0] * d.x + m[1] * d.y + m[2] * d.z + m[3] * d.w;
+    ///
+    /// assert_eq!(moved, Vec4::new(1.0, 1.0, 1.0, 0.0));
+    /// ```
+    pub fn from_direction(direction: Vec3<f32>) -> Vec4<f32> {
+        direction.extend(0.0)
+    }
+
+    /// Converts the calling `Vec4<f32>` from homogeneous coordinates into a `Vec3<f32>`, by
+    /// dividing its x, y and z components by its w component.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gamemath::{Mat4, Vec3, Vec4};
+    ///
+    /// let m = Mat4::perspective(90.0, 1.0, 1.0, 100.0);
+    /// let p = Vec4::from_point(Vec3::new(1.0, -1.0, -2.0));
+    /// let clip = m[0] * p.x + m[1] * p.y + m[2] * p.z + m[3] * p.w;
+    ///
+    /// assert_eq!(clip.w, 2.0);
+    /// assert!((clip.homogenize() - Vec3::new(0.5, -0.5, 0.5 * clip.z)).length() < 0.000001);
+    /// assert_eq!(clip.homogenize(), m.transform_point(Vec3::new(1.0, -1.0, -2.0)));
+    /// ```
+    pub fn homogenize(&self) -> Vec3<f32> {
+        Vec3::new(self.x / self.w, self.y / self.w, self.z / self.w)
+    }
 }
 
 impl Vec4<f64> {
@@ -265,6 +637,106 @@ impl Vec4<f64> {
     pub fn normalize(&mut self) {
         *self = self.normalized();
     }
+
+    /// Returns `true` if every component of the `Vec4<f64>` is neither infinite nor NaN.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gamemath::Vec4;
+    ///
+    /// assert!(Vec4::new(1.0_f64, 1.0_f64, 1.0_f64, 1.0_f64).is_finite());
+    /// assert!(!Vec4::new(1.0_f64, 1.0_f64, 1.0_f64, f64::NAN).is_finite());
+    /// assert!(!Vec4::new(1.0_f64, 1.0_f64, 1.0_f64, f64::INFINITY).is_finite());
+    /// ```
+    pub fn is_finite(&self) -> bool {
+        self.x.is_finite() && self.y.is_finite() && self.z.is_finite() && self.w.is_finite()
+    }
+
+    /// Returns `true` if any component of the `Vec4<f64>` is NaN.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gamemath::Vec4;
+    ///
+    /// assert!(Vec4::new(1.0_f64, 1.0_f64, 1.0_f64, f64::NAN).is_nan());
+    /// assert!(!Vec4::new(1.0_f64, 1.0_f64, 1.0_f64, 1.0_f64).is_nan());
+    /// ```
+    pub fn is_nan(&self) -> bool {
+        self.x.is_nan() || self.y.is_nan() || self.z.is_nan() || self.w.is_nan()
+    }
+
+    /// Linearly interpolates between the calling `Vec4<f64>` and a target `Vec4<f64>` by a
+    /// factor. The factor is not clamped, so factors outside of `[0, 1]` extrapolate beyond the
+    /// two vectors.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gamemath::Vec4;
+    ///
+    /// let v1 = Vec4::new(0.0_f64, 0.0_f64, 0.0_f64, 0.0_f64);
+    /// let v2 = Vec4::new(2.0_f64, 2.0_f64, 2.0_f64, 2.0_f64);
+    ///
+    /// assert_eq!(v1.lerp(v2, 0.5), Vec4::new(1.0_f64, 1.0_f64, 1.0_f64, 1.0_f64));
+    /// assert_eq!(v1.lerp(v2, 2.0), Vec4::new(4.0_f64, 4.0_f64, 4.0_f64, 4.0_f64));
+    /// ```
+    pub fn lerp(&self, target: Vec4<f64>, factor: f64) -> Vec4<f64> {
+        *self + (target - *self) * factor
+    }
+
+    /// Linearly interpolates between the calling `Vec4<f64>` and a target `Vec4<f64>` by a
+    /// factor clamped into the range `[0, 1]`, so the result never passes either vector.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gamemath::Vec4;
+    ///
+    /// let v1 = Vec4::new(0.0_f64, 0.0_f64, 0.0_f64, 0.0_f64);
+    /// let v2 = Vec4::new(2.0_f64, 2.0_f64, 2.0_f64, 2.0_f64);
+    ///
+    /// assert_eq!(v1.lerp_clamped(v2, 0.5), Vec4::new(1.0_f64, 1.0_f64, 1.0_f64, 1.0_f64));
+    /// assert_eq!(v1.lerp_clamped(v2, 2.0), v2);
+    /// assert_eq!(v1.lerp_clamped(v2, -1.0), v1);
+    /// ```
+    pub fn lerp_clamped(&self, target: Vec4<f64>, factor: f64) -> Vec4<f64> {
+        self.lerp(target, factor.clamp(0.0, 1.0))
+    }
+
+    /// Calculates and returns a `Vec4<f64>` with each component of the calling `Vec4<f64>`
+    /// clamped into the range `[0, 1]`, which is useful for colors.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gamemath::Vec4;
+    ///
+    /// assert_eq!(Vec4::new(-0.5_f64, 0.5_f64, 1.5_f64, 0.25_f64).saturate(), Vec4::new(0.0_f64, 0.5_f64, 1.0_f64, 0.25_f64));
+    /// ```
+    pub fn saturate(&self) -> Vec4<f64> {
+        Vec4 {
+            x: self.x.clamp(0.0, 1.0),
+            y: self.y.clamp(0.0, 1.0),
+            z: self.z.clamp(0.0, 1.0),
+            w: self.w.clamp(0.0, 1.0),
+        }
+    }
+
+    /// Converts the calling `Vec4<f64>` from homogeneous coordinates into a `Vec3<f64>`, by
+    /// dividing its x, y and z components by its w component.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gamemath::{Vec3, Vec4};
+    ///
+    /// assert_eq!(Vec4::new(2.0_f64, 4.0, 6.0, 2.0).homogenize(), Vec3::new(1.0, 2.0, 3.0));
+    /// ```
+    pub fn homogenize(&self) -> Vec3<f64> {
+        Vec3::new(self.x / self.w, self.y / self.w, self.z / self.w)
+    }
 }
 
 impl<T: Default> Default for Vec4<T> {
@@ -300,6 +772,28 @@ impl<T: Copy> From<[T; 4]> for Vec4<T> {
     }
 }
 
+/// Converts a slice into a `Vec4<T>`, failing if the length of the slice is not exactly 4.
+///
+/// # Examples
+///
+/// ```
+/// use gamemath::Vec4;
+/// use std::convert::TryFrom;
+///
+/// let values = [1.0_f32, 2.0, 3.0, 4.0];
+///
+/// assert_eq!(Vec4::try_from(&values[..]).unwrap(), Vec4::new(1.0, 2.0, 3.0, 4.0));
+/// assert!(Vec4::<f32>::try_from(&values[1..]).is_err());
+/// assert!(Vec4::<f32>::try_from(&[0.0_f32; 5][..]).is_err());
+/// ```
+impl<'a, T: Copy> TryFrom<&'a [T]> for Vec4<T> {
+    type Error = TryFromSliceError;
+
+    fn try_from(slice: &'a [T]) -> Result<Vec4<T>, TryFromSliceError> {
+        <[T; 4]>::try_from(slice).map(Vec4::from)
+    }
+}
+
 impl<T: Default> From<Vec2<T>> for Vec4<T> {
     fn from(vec: Vec2<T>) -> Vec4<T> {
         Vec4 {
@@ -322,6 +816,19 @@ impl<T: Default> From<Vec3<T>> for Vec4<T> {
     }
 }
 
+/// Converts a `Quat` into a `Vec4<f32>` holding its x, y, z and w components.
+///
+/// # Examples
+///
+/// ```
+/// use gamemath::{Quat, Vec3, Vec4};
+///
+/// let q = Quat::rotation(1.0, Vec3::new(1.0, 2.0, 3.0));
+/// let v: Vec4<f32> = q.into();
+///
+/// assert_eq!(v, Vec4::new(q.x, q.y, q.z, q.w));
+/// assert_eq!(Quat::from(v), q);
+/// ```
 impl From<Quat> for Vec4<f32> {
     fn from(quat: Quat) -> Vec4<f32> {
         Vec4 {
@@ -333,6 +840,17 @@ impl From<Quat> for Vec4<f32> {
     }
 }
 
+impl From<Quatd> for Vec4<f64> {
+    fn from(quat: Quatd) -> Vec4<f64> {
+        Vec4 {
+            x: quat.x,
+            y: quat.y,
+            z: quat.z,
+            w: quat.w,
+        }
+    }
+}
+
 impl<T: Copy> From<T> for Vec4<T> {
     fn from(value: T) -> Vec4<T> {
         Vec4 {
@@ -427,6 +945,50 @@ impl<T: Copy + Mul<Output = T>> Mul<T> for Vec4<T> {
     }
 }
 
+/// Multiplies every component of a `Vec4<f32>` by a scalar value on the left.
+///
+/// # Examples
+///
+/// ```
+/// use gamemath::Vec4;
+///
+/// assert_eq!(2.0 * Vec4::new(1.0_f32, 2.0_f32, 3.0_f32, 4.0_f32), Vec4::new(2.0_f32, 4.0_f32, 6.0_f32, 8.0_f32));
+/// ```
+impl Mul<Vec4<f32>> for f32 {
+    type Output = Vec4<f32>;
+
+    fn mul(self, right: Vec4<f32>) -> Vec4<f32> {
+        Vec4 {
+            x: self * right.x,
+            y: self * right.y,
+            z: self * right.z,
+            w: self * right.w,
+        }
+    }
+}
+
+/// Multiplies every component of a `Vec4<f64>` by a scalar value on the left.
+///
+/// # Examples
+///
+/// ```
+/// use gamemath::Vec4;
+///
+/// assert_eq!(2.0 * Vec4::new(1.0_f64, 2.0_f64, 3.0_f64, 4.0_f64), Vec4::new(2.0_f64, 4.0_f64, 6.0_f64, 8.0_f64));
+/// ```
+impl Mul<Vec4<f64>> for f64 {
+    type Output = Vec4<f64>;
+
+    fn mul(self, right: Vec4<f64>) -> Vec4<f64> {
+        Vec4 {
+            x: self * right.x,
+            y: self * right.y,
+            z: self * right.z,
+            w: self * right.w,
+        }
+    }
+}
+
 impl<T: Copy + MulAssign> MulAssign<T> for Vec4<T> {
     fn mul_assign(&mut self, right: T) {
         self.x *= right;
@@ -436,6 +998,50 @@ impl<T: Copy + MulAssign> MulAssign<T> for Vec4<T> {
     }
 }
 
+/// Divides every component of a `Vec4<T>` by a scalar value.
+///
+/// # Examples
+///
+/// ```
+/// use gamemath::Vec4;
+///
+/// assert_eq!(Vec4::new(2.0, 4.0, 6.0, 8.0) / 2.0, Vec4::new(1.0, 2.0, 3.0, 4.0));
+/// ```
+impl<T: Copy + Div<Output = T>> Div<T> for Vec4<T> {
+    type Output = Vec4<T>;
+
+    fn div(self, right: T) -> Vec4<T> {
+        Vec4 {
+            x: self.x / right,
+            y: self.y / right,
+            z: self.z / right,
+            w: self.w / right,
+        }
+    }
+}
+
+/// Divides every component of a `Vec4<T>` by a scalar value in place.
+///
+/// # Examples
+///
+/// ```
+/// use gamemath::Vec4;
+///
+/// let mut v = Vec4::new(2.0, 4.0, 6.0, 8.0);
+///
+/// v /= 2.0;
+///
+/// assert_eq!(v, Vec4::new(1.0, 2.0, 3.0, 4.0));
+/// ```
+impl<T: Copy + DivAssign> DivAssign<T> for Vec4<T> {
+    fn div_assign(&mut self, right: T) {
+        self.x /= right;
+        self.y /= right;
+        self.z /= right;
+        self.w /= right;
+    }
+}
+
 impl<T: Neg<Output = T>> Neg for Vec4<T> {
     type Output = Vec4<T>;
 
@@ -448,3 +1054,39 @@ impl<T: Neg<Output = T>> Neg for Vec4<T> {
         }
     }
 }
+
+/// Orders `Vec4<T>`s of totally ordered components lexicographically, comparing the x, y, z
+/// and then the w component. Vectors of floating point components are left unordered.
+///
+/// # Examples
+///
+/// ```
+/// use gamemath::Vec4;
+///
+/// let mut v = vec![Vec4::new(1, 2, 0, 0),
+///                  Vec4::new(0, 5, 3, 1),
+///                  Vec4::new(1, 2, 0, -3),
+///                  Vec4::new(0, 5, 3, 0)];
+///
+/// v.sort();
+///
+/// assert_eq!(v, vec![Vec4::new(0, 5, 3, 0),
+///                    Vec4::new(0, 5, 3, 1),
+///                    Vec4::new(1, 2, 0, -3),
+///                    Vec4::new(1, 2, 0, 0)]);
+/// ```
+impl<T: Ord> Ord for Vec4<T> {
+    fn cmp(&self, other: &Vec4<T>) -> Ordering {
+        self.x
+            .cmp(&other.x)
+            .then_with(|| self.y.cmp(&other.y))
+            .then_with(|| self.z.cmp(&other.z))
+            .then_with(|| self.w.cmp(&other.w))
+    }
+}
+
+impl<T: Ord> PartialOrd for Vec4<T> {
+    fn partial_cmp(&self, other: &Vec4<T>) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
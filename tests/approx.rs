@@ -0,0 +1,63 @@
+//! Checks the `approx` trait implementations, enabled through the `approx` feature.
+
+#![cfg(feature = "approx")]
+
+#[macro_use]
+extern crate approx;
+extern crate gamemath;
+
+use gamemath::{Mat3, Mat4, Mat4d, Quat, Vec2, Vec3, Vec4};
+
+#[test]
+fn close_matrices_are_relatively_equal() {
+    let a = Mat4::rotation(1.0, Vec3::new(1.0, 2.0, 3.0));
+    let b = Mat4::rotation(0.5, Vec3::new(1.0, 2.0, 3.0)).rotated(0.5, Vec3::new(1.0, 2.0, 3.0));
+
+    assert_ne!(a, b);
+    assert_relative_eq!(a, b, epsilon = 1e-6);
+    assert_abs_diff_eq!(a, b, epsilon = 1e-6);
+    assert_ulps_eq!(a, b, max_ulps = 8);
+    assert_relative_ne!(a, Mat4::identity());
+}
+
+#[test]
+fn close_f64_matrices_are_relatively_equal() {
+    let a = Mat4d::rotation(1.0, Vec3::new(1.0, 2.0, 3.0));
+    let b = Mat4d::rotation(0.5, Vec3::new(1.0, 2.0, 3.0)).rotated(0.5, Vec3::new(1.0, 2.0, 3.0));
+
+    assert_relative_eq!(a, b, epsilon = 1e-12);
+    assert_relative_ne!(a, b * Mat4d::identity().scaled(Vec3::new(1.0, 1.0, 1.001)));
+}
+
+#[test]
+fn close_vectors_are_relatively_equal() {
+    assert_relative_eq!(Vec2::new(0.1 + 0.2, 1.0), Vec2::new(0.3, 1.0));
+    assert_relative_eq!(
+        Vec3::new(1.0_f32, 2.0, 3.0).normalized().length(),
+        1.0,
+        epsilon = 1e-6
+    );
+    assert_abs_diff_eq!(
+        Vec4::new(1.0, 2.0, 3.0, 4.0),
+        Vec4::new(1.05, 2.0, 3.0, 4.0),
+        epsilon = 0.1
+    );
+    assert_abs_diff_ne!(
+        Vec4::new(1.0, 2.0, 3.0, 4.0),
+        Vec4::new(1.5, 2.0, 3.0, 4.0),
+        epsilon = 0.1
+    );
+}
+
+#[test]
+fn close_quaternions_are_relatively_equal() {
+    let q = Quat::rotation(1.0, Vec3::new(0.0, 1.0, 0.0));
+    let r: Mat3 = Mat3::from((
+        Vec3::from(q.extract_matrix()[0]),
+        Vec3::from(q.extract_matrix()[1]),
+        Vec3::from(q.extract_matrix()[2]),
+    ));
+
+    assert_relative_eq!(Quat::from_mat3(r), q, epsilon = 1e-6);
+    assert_relative_eq!(q.pow(0.5) * q.pow(0.5), q, epsilon = 1e-6);
+}
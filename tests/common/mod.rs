@@ -0,0 +1,49 @@
+//! Helpers shared by the integration tests. Each test crate only uses some of them.
+
+#![allow(dead_code)]
+
+use gamemath::{Mat4, Vec3, Vec4};
+
+/// A small linear congruential generator, so the randomized tests are reproducible without
+/// depending on `rand`.
+pub struct Lcg(pub u32);
+
+impl Lcg {
+    /// Returns the next value in the range `[0, 1)`.
+    pub fn next(&mut self) -> f32 {
+        self.0 = self.0.wrapping_mul(1_664_525).wrapping_add(1_013_904_223);
+
+        (self.0 >> 8) as f32 / (1 << 24) as f32
+    }
+
+    pub fn range(&mut self, min: f32, max: f32) -> f32 {
+        min + self.next() * (max - min)
+    }
+
+    pub fn vec3(&mut self, min: f32, max: f32) -> Vec3<f32> {
+        Vec3::new(
+            self.range(min, max),
+            self.range(min, max),
+            self.range(min, max),
+        )
+    }
+
+    pub fn vec4(&mut self, min: f32, max: f32) -> Vec4<f32> {
+        Vec4::new(
+            self.range(min, max),
+            self.range(min, max),
+            self.range(min, max),
+            self.range(min, max),
+        )
+    }
+
+    pub fn mat4(&mut self, min: f32, max: f32) -> Mat4 {
+        (
+            self.vec4(min, max),
+            self.vec4(min, max),
+            self.vec4(min, max),
+            self.vec4(min, max),
+        )
+            .into()
+    }
+}
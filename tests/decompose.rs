@@ -0,0 +1,98 @@
+//! Checks that decomposing random affine matrices with `Mat4::decompose` and recomposing them
+//! with `Mat4::recompose` gives back the original matrices, which catches sign and ordering
+//! mistakes that single examples can miss.
+
+extern crate gamemath;
+
+mod common;
+
+use common::Lcg;
+use gamemath::{Mat4, Quat, Vec3};
+
+const SAMPLES: usize = 10_000;
+
+fn random_scale(rng: &mut Lcg) -> Vec3<f32> {
+    let mut scale = rng.vec3(0.1, 5.0);
+
+    for i in 0..3 {
+        if rng.next() < 0.25 {
+            scale[i] = -scale[i];
+        }
+    }
+
+    scale
+}
+
+fn random_rotation(rng: &mut Lcg) -> Quat {
+    let mut axis = rng.vec3(-1.0, 1.0);
+
+    while axis.length_squared() < 0.01 {
+        axis = rng.vec3(-1.0, 1.0);
+    }
+
+    Quat::rotation(rng.range(-10.0, 10.0), axis)
+}
+
+#[test]
+fn random_affine_matrices_round_trip() {
+    let mut rng = Lcg(0x1234_5678);
+
+    for _ in 0..SAMPLES {
+        let m = Mat4::from_trs(
+            rng.vec3(-100.0, 100.0),
+            random_rotation(&mut rng),
+            random_scale(&mut rng),
+        );
+        let (translation, rotation, scale) = m.decompose();
+        let recomposed = Mat4::recompose(translation, rotation, scale);
+
+        assert!(
+            recomposed.approx_eq(m, 0.0001),
+            "{:?} was recomposed as {:?}",
+            m,
+            recomposed
+        );
+    }
+}
+
+#[test]
+fn decomposed_parts_match_the_inputs() {
+    let mut rng = Lcg(0x9abc_def0);
+
+    for _ in 0..SAMPLES {
+        let translation = rng.vec3(-100.0, 100.0);
+        let rotation = random_rotation(&mut rng);
+        let scale = rng.vec3(0.1, 5.0);
+        let (t, r, s) = Mat4::from_trs(translation, rotation, scale).decompose();
+
+        assert_eq!(t, translation);
+        assert!((s - scale).length() < 0.0001, "{:?} != {:?}", s, scale);
+        assert!(
+            r.approx_eq(rotation, 0.0001) || r.approx_eq(rotation * -1.0, 0.0001),
+            "{:?} != {:?}",
+            r,
+            rotation
+        );
+    }
+}
+
+#[test]
+fn transformed_points_agree_after_round_trip() {
+    let mut rng = Lcg(0x0f0f_0f0f);
+
+    for _ in 0..SAMPLES {
+        let m = Mat4::from_trs(
+            rng.vec3(-100.0, 100.0),
+            random_rotation(&mut rng),
+            random_scale(&mut rng),
+        );
+        let (translation, rotation, scale) = m.decompose();
+        let recomposed = Mat4::recompose(translation, rotation, scale);
+        let point = rng.vec3(-10.0, 10.0);
+
+        let expected = m.transform_point(point);
+        let actual = recomposed.transform_point(point);
+
+        assert!((expected - actual).length() < 0.001);
+    }
+}
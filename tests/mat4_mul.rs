@@ -0,0 +1,72 @@
+//! Compares `Mat4` multiplication against a straightforward reference implementation, summing
+//! in the same order. Run with `--features simd` to check the SIMD path, which is expected to be
+//! bit-identical to the scalar path.
+
+extern crate gamemath;
+
+mod common;
+
+use common::Lcg;
+use gamemath::{Mat4, Vec4};
+
+fn reference_mul_vec4(left: &Mat4, right: Vec4<f32>) -> Vec4<f32> {
+    let mut result = Vec4::new(0.0, 0.0, 0.0, 0.0);
+
+    for i in 0..4 {
+        result[i] = left[i][0] * right[0];
+
+        for k in 1..4 {
+            result[i] += left[i][k] * right[k];
+        }
+    }
+
+    result
+}
+
+fn reference_combine(left: &Mat4, right: Vec4<f32>) -> Vec4<f32> {
+    let mut result = Vec4::new(0.0, 0.0, 0.0, 0.0);
+
+    for j in 0..4 {
+        result[j] = left[0][j] * right[0];
+
+        for k in 1..4 {
+            result[j] += left[k][j] * right[k];
+        }
+    }
+
+    result
+}
+
+fn reference_mul_mat4(left: &Mat4, right: &Mat4) -> Mat4 {
+    let mut result: Mat4 = 0.0.into();
+
+    for i in 0..4 {
+        result[i] = reference_combine(left, right[i]);
+    }
+
+    result
+}
+
+#[test]
+fn mat4_mul_mat4_matches_reference() {
+    let mut rng = Lcg(0x5eed);
+
+    for _ in 0..1000 {
+        let a = rng.mat4(-100.0, 100.0);
+        let b = rng.mat4(-100.0, 100.0);
+
+        assert_eq!(a * b, reference_mul_mat4(&a, &b));
+    }
+}
+
+#[test]
+fn mat4_mul_vec4_matches_reference() {
+    let mut rng = Lcg(0xcafe);
+
+    for _ in 0..1000 {
+        let m = rng.mat4(-100.0, 100.0);
+        let v = rng.vec4(-100.0, 100.0);
+
+        assert_eq!(m * v, reference_mul_vec4(&m, v));
+    }
+}
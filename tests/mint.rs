@@ -0,0 +1,64 @@
+//! Round-trip checks for the `mint` conversions, enabled through the `mint` feature.
+
+#![cfg(feature = "mint")]
+
+extern crate gamemath;
+extern crate mint;
+
+use gamemath::{Mat4, Mat4d, Quat, Quatd, Vec2, Vec3, Vec4};
+
+#[test]
+fn vectors_round_trip() {
+    let v2 = Vec2::new(1.0, 2.0);
+    let v3 = Vec3::new(1, 2, 3);
+    let v4 = Vec4::new(1.0, 2.0, 3.0, 4.0);
+
+    let m2: mint::Vector2<f32> = v2.into();
+    let m3: mint::Vector3<i32> = v3.into();
+    let m4: mint::Vector4<f64> = v4.into();
+
+    assert_eq!((m2.x, m2.y), (1.0, 2.0));
+    assert_eq!((m3.x, m3.y, m3.z), (1, 2, 3));
+    assert_eq!((m4.x, m4.y, m4.z, m4.w), (1.0, 2.0, 3.0, 4.0));
+
+    assert_eq!(Vec2::from(m2), v2);
+    assert_eq!(Vec3::from(m3), v3);
+    assert_eq!(Vec4::from(m4), v4);
+}
+
+#[test]
+fn matrices_convert_column_by_column() {
+    let m = Mat4::identity()
+        .translated(Vec3::new(1.0, 2.0, 3.0))
+        .rotated(0.5, Vec3::new(0.0, 1.0, 0.0));
+    let columns: mint::ColumnMatrix4<f32> = m.into();
+
+    assert_eq!(columns.w.x, 1.0);
+    assert_eq!(columns.w.y, 2.0);
+    assert_eq!(columns.w.z, 3.0);
+    assert_eq!(columns.w.w, 1.0);
+    assert_eq!(Vec4::from(columns.x), m[0]);
+    assert_eq!(Vec4::from(columns.z), m[2]);
+    assert_eq!(Mat4::from(columns), m);
+
+    let md = Mat4d::identity().translated(Vec3::new(4.0, 5.0, 6.0));
+    let columns: mint::ColumnMatrix4<f64> = md.into();
+
+    assert_eq!((columns.w.x, columns.w.y, columns.w.z), (4.0, 5.0, 6.0));
+    assert_eq!(Mat4d::from(columns), md);
+}
+
+#[test]
+fn quaternions_round_trip() {
+    let q = Quat::rotation(1.0, Vec3::new(1.0_f32, 2.0, 3.0).normalized());
+    let m: mint::Quaternion<f32> = q.into();
+
+    assert_eq!((m.v.x, m.v.y, m.v.z, m.s), (q.x, q.y, q.z, q.w));
+    assert_eq!(Quat::from(m), q);
+
+    let qd = Quatd::rotation(1.0, Vec3::new(0.0, 0.0, 1.0));
+    let md: mint::Quaternion<f64> = qd.into();
+
+    assert_eq!(md.s, qd.w);
+    assert_eq!(Quatd::from(md), qd);
+}
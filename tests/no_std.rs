@@ -0,0 +1,27 @@
+//! Smoke test making sure the public API is usable from a `no_std` crate. Run it against a build
+//! without the standard library using `cargo test --no-default-features --features libm`.
+#![no_std]
+
+extern crate gamemath;
+
+use gamemath::{Curve, Mat4, Quat, Vec3, Vec4};
+
+#[test]
+fn no_std_smoke_test() {
+    let v = Vec3::new(9.0_f32, 12.0_f32, 20.0_f32);
+
+    assert_eq!(v.length(), 25.0);
+    assert_eq!(v.normalized(), Vec3::new(0.36, 0.48, 0.8));
+
+    let m = Mat4::identity().translated(Vec3::new(1.0, 2.0, 3.0));
+
+    assert_eq!(m[3], Vec4::new(1.0, 2.0, 3.0, 1.0));
+
+    let q = Quat::rotation(0.0, Vec3::new(0.0, 0.0, 1.0));
+
+    assert_eq!(q, Quat::identity());
+
+    let c: Curve<f32> = Curve::new(&[0.0, 10.0, 5.0, 0.0]);
+
+    assert_eq!(c.lerp(0.75), 3.75);
+}
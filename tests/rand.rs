@@ -0,0 +1,94 @@
+//! Statistical smoke tests for the `rand` sampling support, enabled through the `rand` feature.
+
+#![cfg(feature = "rand")]
+
+extern crate gamemath;
+extern crate rand;
+
+use gamemath::{Quat, UnitQuaternion, UnitSphere, Vec3, Vec4};
+use rand::{Error, Rng, RngCore};
+
+const SAMPLES: usize = 10_000;
+
+struct XorShift(u64);
+
+impl RngCore for XorShift {
+    fn next_u32(&mut self) -> u32 {
+        (self.next_u64() >> 32) as u32
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        self.0
+    }
+
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        for chunk in dest.chunks_mut(8) {
+            let bytes = self.next_u64().to_le_bytes();
+            chunk.copy_from_slice(&bytes[..chunk.len()]);
+        }
+    }
+
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), Error> {
+        self.fill_bytes(dest);
+        Ok(())
+    }
+}
+
+#[test]
+fn standard_vectors_are_in_unit_range() {
+    let mut rng = XorShift(0x2545_f491_4f6c_dd1d);
+    let mut sum = Vec4::new(0.0, 0.0, 0.0, 0.0);
+
+    for _ in 0..SAMPLES {
+        let v: Vec4<f32> = rng.gen();
+
+        for i in 0..4 {
+            assert!(v[i] >= 0.0 && v[i] < 1.0);
+        }
+
+        sum += v;
+    }
+
+    let mean = sum / SAMPLES as f32;
+
+    for i in 0..4 {
+        assert!((mean[i] - 0.5).abs() < 0.02);
+    }
+}
+
+#[test]
+fn unit_sphere_samples_are_unit_length_and_unbiased() {
+    let mut rng = XorShift(0x9e37_79b9_7f4a_7c15);
+    let mut sum = Vec3::new(0.0, 0.0, 0.0);
+
+    for _ in 0..SAMPLES {
+        let v: Vec3<f32> = rng.sample(UnitSphere);
+
+        assert!((v.length() - 1.0).abs() < 0.00001);
+
+        sum += v;
+    }
+
+    assert!((sum / SAMPLES as f32).length() < 0.05);
+}
+
+#[test]
+fn unit_quaternion_samples_are_unit_length_and_unbiased() {
+    let mut rng = XorShift(0x853c_49e6_748f_ea9b);
+    let mut sum = Vec3::new(0.0, 0.0, 0.0);
+
+    for _ in 0..SAMPLES {
+        let q: Quat = rng.sample(UnitQuaternion);
+
+        assert!((q.length() - 1.0).abs() < 0.00001);
+
+        sum += q
+            .extract_matrix()
+            .transform_vector(Vec3::new(0.0, 0.0, 1.0));
+    }
+
+    assert!((sum / SAMPLES as f32).length() < 0.05);
+}